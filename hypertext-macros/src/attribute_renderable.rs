@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ext::IdentExt, Data, DeriveInput, Fields, LitStr};
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`AttributeRenderable` can only be derived for enums",
+        ));
+    };
+
+    let ident = &input.ident;
+    let mut arms = Vec::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &variant.fields,
+                "`AttributeRenderable` can only be derived for enums with unit variants",
+            ));
+        }
+
+        let mut value = LitStr::new(
+            &kebab_case(&variant.ident.unraw().to_string()),
+            variant.ident.span(),
+        );
+
+        for attr in &variant.attrs {
+            if attr.path().is_ident("attribute") {
+                value = attr.parse_args()?;
+            }
+        }
+
+        let escaped = LitStr::new(
+            &html_escape::encode_double_quoted_attribute(&value.value()),
+            value.span(),
+        );
+        let variant_ident = &variant.ident;
+
+        arms.push(quote!(#ident::#variant_ident => #escaped));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            extern crate alloc;
+
+            #[automatically_derived]
+            impl #impl_generics ::hypertext::Renderable for #ident #ty_generics #where_clause {
+                fn render_to(self, hypertext_output: &mut alloc::string::String) {
+                    hypertext_output.push_str(match self {
+                        #(#arms,)*
+                    });
+                }
+            }
+
+            #[automatically_derived]
+            impl #impl_generics ::hypertext::Renderable for &#ident #ty_generics #where_clause {
+                fn render_to(self, hypertext_output: &mut alloc::string::String) {
+                    hypertext_output.push_str(match self {
+                        #(#arms,)*
+                    });
+                }
+            }
+        };
+    })
+}
+
+/// Converts a `PascalCase` variant name to `kebab-case`.
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::new();
+
+    for (i, c) in name.char_indices() {
+        if c.is_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+
+        kebab.extend(c.to_lowercase());
+    }
+
+    kebab
+}
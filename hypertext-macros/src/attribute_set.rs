@@ -0,0 +1,194 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt, Data, DeriveInput, ExprPath, Field, Fields, GenericArgument, Ident, LitStr,
+    PathArguments, Type,
+};
+
+use crate::validate;
+
+pub fn derive(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let mut checked_elements = Vec::<Ident>::new();
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("attr") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("check") {
+                    checked_elements.push(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown option, expected `check`"))
+                }
+            })?;
+        }
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "`AttributeSet` can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "`AttributeSet` can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut renders = Vec::new();
+    let mut checks = Vec::new();
+
+    for field in &fields.named {
+        let field_ident = field.ident.as_ref().expect("fields are named");
+        let mut name = LitStr::new(
+            &field_ident.unraw().to_string().replace('_', "-"),
+            field_ident.span(),
+        );
+
+        let transform = field_options(field, &mut name)?;
+
+        let prefix = LitStr::new(&format!(" {}=\"", name.value()), name.span());
+        let render = |value: TokenStream| {
+            let value = transform.apply(value);
+
+            quote! {
+                hypertext_output.push_str(#prefix);
+                ::hypertext::Renderable::render_to(#value, hypertext_output);
+                hypertext_output.push('"');
+            }
+        };
+
+        renders.push(if is_option(&field.ty) {
+            let render = render(quote!(value));
+
+            quote! {
+                if let ::core::option::Option::Some(value) = self.#field_ident {
+                    #render
+                }
+            }
+        } else {
+            render(quote!(self.#field_ident))
+        });
+
+        let name = name.value();
+
+        if name.starts_with("data-") {
+            continue;
+        }
+
+        let attr_ident = validate::ident(
+            &validate::normalize_attribute_ident(&name),
+            field_ident.span(),
+        );
+        let kind = if name.contains(':') {
+            quote!(AttributeNamespace)
+        } else {
+            quote!(Attribute)
+        };
+
+        checks.extend(checked_elements.iter().map(|el| {
+            quote! {
+                let _: ::hypertext::#kind = html_elements::#el::#attr_ident;
+            }
+        }));
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            extern crate alloc;
+
+            #(#checks)*
+
+            #[automatically_derived]
+            impl #impl_generics ::hypertext::AttributeSet for #ident #ty_generics #where_clause {
+                fn render_attributes(self, hypertext_output: &mut alloc::string::String) {
+                    #(#renders)*
+                }
+            }
+        };
+    })
+}
+
+/// How a field's value is turned into something `Renderable` before it is
+/// rendered.
+enum Transform {
+    /// The value is rendered as-is.
+    None,
+    /// `#[attr(display)]`: the value is rendered via its `Display` impl.
+    Display,
+    /// `#[attr(debug)]`: the value is rendered via its `Debug` impl.
+    Debug,
+    /// `#[attr(with = path)]`: the value is passed to a function, and the
+    /// result is rendered.
+    With(ExprPath),
+}
+
+impl Transform {
+    fn apply(&self, value: TokenStream) -> TokenStream {
+        match self {
+            Self::None => value,
+            Self::Display => quote!(::hypertext::Displayed(#value)),
+            Self::Debug => quote!(::hypertext::Displayed(::core::format_args!("{:?}", #value))),
+            Self::With(path) => quote!(#path(#value)),
+        }
+    }
+}
+
+/// Parses the `#[attr(...)]` options of a field, setting `name` if it is
+/// renamed, and returning how its value is transformed.
+fn field_options(field: &Field, name: &mut LitStr) -> syn::Result<Transform> {
+    let mut transform = Transform::None;
+
+    for attr in &field.attrs {
+        if attr.path().is_ident("attr") {
+            attr.parse_nested_meta(|meta| {
+                let new_transform = if meta.path.is_ident("rename") {
+                    *name = meta.value()?.parse()?;
+                    return Ok(());
+                } else if meta.path.is_ident("display") {
+                    Transform::Display
+                } else if meta.path.is_ident("debug") {
+                    Transform::Debug
+                } else if meta.path.is_ident("with") {
+                    Transform::With(meta.value()?.parse()?)
+                } else {
+                    return Err(meta
+                        .error("unknown option, expected `rename`, `display`, `debug` or `with`"));
+                };
+
+                if !matches!(transform, Transform::None) {
+                    return Err(meta
+                        .error("only one of `display`, `debug` or `with` can be used on a field"));
+                }
+
+                transform = new_transform;
+                Ok(())
+            })?;
+        }
+    }
+
+    Ok(transform)
+}
+
+/// Whether the type is syntactically an `Option`.
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    path.qself.is_none()
+        && path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "Option"
+                && matches!(
+                    &segment.arguments,
+                    PathArguments::AngleBracketed(args)
+                        if args.args.len() == 1
+                            && matches!(args.args[0], GenericArgument::Type(_))
+                )
+        })
+}
@@ -0,0 +1,141 @@
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    token::Paren,
+    Expr, Ident, Lit, LitFloat, LitInt, LitStr, Token,
+};
+
+use crate::generate::{Generate, Generator};
+
+/// The declarations of a `css!` invocation, like `color: (color); margin:
+/// "0"`.
+pub struct Css {
+    declarations: Vec<Declaration>,
+}
+
+impl Parse for Css {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut declarations = Vec::new();
+
+        while !input.is_empty() {
+            declarations.push(input.parse()?);
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![;]>()?;
+        }
+
+        Ok(Self { declarations })
+    }
+}
+
+impl Generate for Css {
+    fn generate(&self, gen: &mut Generator) {
+        for (i, declaration) in self.declarations.iter().enumerate() {
+            if i > 0 {
+                gen.push_str("; ");
+            }
+
+            gen.push(declaration);
+        }
+    }
+}
+
+/// A single `property: value` declaration, where the value is one or more
+/// literals and `(expr)` splices.
+struct Declaration {
+    property: LitStr,
+    values: Vec<Value>,
+}
+
+impl Parse for Declaration {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let property = parse_property(input)?;
+        input.parse::<Token![:]>()?;
+
+        let mut values = Vec::new();
+
+        while !input.is_empty() && !input.peek(Token![;]) {
+            values.push(input.parse()?);
+        }
+
+        if values.is_empty() {
+            return Err(input.error(format!("expected a value for `{}`", property.value())));
+        }
+
+        Ok(Self { property, values })
+    }
+}
+
+impl Generate for Declaration {
+    fn generate(&self, gen: &mut Generator) {
+        gen.push_escaped_lit(self.property.clone());
+        gen.push_str(": ");
+
+        for value in &self.values {
+            match value {
+                Value::Literal(lit) => gen.push_escaped_lit(lit.clone()),
+                Value::Splice(expr) => gen.push_rendered_expr(expr),
+            }
+        }
+    }
+}
+
+/// Parses a property name like `margin-top` or `--accent-color`.
+fn parse_property(input: ParseStream) -> syn::Result<LitStr> {
+    let span = input.span();
+    let mut property = String::new();
+
+    if input.peek(Token![-]) && input.peek2(Token![-]) {
+        input.parse::<Token![-]>()?;
+        input.parse::<Token![-]>()?;
+        property.push_str("--");
+    }
+
+    loop {
+        property.push_str(&input.call(Ident::parse_any)?.unraw().to_string());
+
+        if input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+            property.push('-');
+        } else {
+            break;
+        }
+    }
+
+    Ok(LitStr::new(&property, span))
+}
+
+#[allow(clippy::large_enum_variant)]
+enum Value {
+    Literal(LitStr),
+    Splice(Expr),
+}
+
+impl Parse for Value {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+
+            content.parse().map(Self::Splice)
+        } else if lookahead.peek(LitStr) || lookahead.peek(LitInt) || lookahead.peek(LitFloat) {
+            let lit = input.parse::<Lit>()?;
+            let value = match &lit {
+                Lit::Str(lit) => lit.value(),
+                // keep suffixes, so units can be written like `10px`
+                Lit::Int(lit) => lit.to_string(),
+                Lit::Float(lit) => lit.to_string(),
+                _ => unreachable!(),
+            };
+
+            Ok(Self::Literal(LitStr::new(&value, lit.span())))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Reading the files named by macro arguments, like `@include("...")` and
+//! `file = "..."`.
+//!
+//! Paths are relative to the `CARGO_MANIFEST_DIR` of the crate being
+//! compiled, and errors always name the absolute path which was tried.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Component, Path, PathBuf},
+    str::FromStr,
+};
+
+use proc_macro2::{LexError, TokenStream};
+use quote::ToTokens;
+
+/// An error from reading or parsing a file named by a macro argument.
+#[derive(Debug)]
+pub enum FileError {
+    /// The file doesn't exist.
+    NotFound(PathBuf),
+    /// The file exists, but couldn't be read.
+    Io(PathBuf, io::Error),
+    /// The file isn't valid UTF-8.
+    InvalidUtf8(PathBuf),
+    /// The file isn't valid Rust tokens.
+    Tokenize(PathBuf, LexError),
+    /// The file's tokens couldn't be parsed, with the path as it was written.
+    Parse(String, syn::Error),
+    /// The file includes itself, directly or through the other files.
+    Cycle(Vec<PathBuf>),
+}
+
+impl FileError {
+    /// Converts this into a [`syn::Error`] spanning `tokens`, which should be
+    /// the path as written in the invocation.
+    ///
+    /// Tokens read from a file have no spans of their own, so even errors in
+    /// the file's contents are reported on its path.
+    pub fn spanned(self, tokens: impl ToTokens) -> syn::Error {
+        syn::Error::new_spanned(tokens, self)
+    }
+}
+
+impl fmt::Display for FileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(path) => write!(f, "file not found: `{}`", path.display()),
+            Self::Io(path, err) => write!(f, "failed to read `{}`: {err}", path.display()),
+            Self::InvalidUtf8(path) => write!(f, "`{}` is not valid UTF-8", path.display()),
+            Self::Tokenize(path, err) => {
+                write!(f, "failed to tokenize `{}`: {err}", path.display())
+            }
+            Self::Parse(path, err) => write!(f, "in `{path}`: {err}"),
+            Self::Cycle(paths) => {
+                f.write_str("include cycle: ")?;
+
+                for (i, path) in paths.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" -> ")?;
+                    }
+                    write!(f, "`{}`", path.display())?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Resolves `path` relative to the crate's `CARGO_MANIFEST_DIR`, removing any
+/// `.` and `..` components so errors show a readable path.
+///
+/// A leading `/` is ignored, so paths written like URLs (as in the `src` of
+/// `script_sri!`) still resolve relative to the crate.
+pub fn resolve(path: &str) -> PathBuf {
+    let joined = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(path.trim_start_matches('/'));
+
+    let mut resolved = PathBuf::new();
+
+    for component in joined.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if resolved.file_name().is_some() => {
+                resolved.pop();
+            }
+            component => resolved.push(component),
+        }
+    }
+
+    resolved
+}
+
+/// Reads the file at `path`.
+pub fn read(path: &Path) -> Result<Vec<u8>, FileError> {
+    fs::read(path).map_err(|err| match err.kind() {
+        io::ErrorKind::NotFound => FileError::NotFound(path.to_owned()),
+        _ => FileError::Io(path.to_owned(), err),
+    })
+}
+
+/// Reads the file at `path` as a string.
+pub fn read_to_string(path: &Path) -> Result<String, FileError> {
+    String::from_utf8(read(path)?).map_err(|_| FileError::InvalidUtf8(path.to_owned()))
+}
+
+/// Reads the file at `path` as tokens.
+pub fn tokenize(path: &Path) -> Result<TokenStream, FileError> {
+    TokenStream::from_str(&read_to_string(path)?)
+        .map_err(|err| FileError::Tokenize(path.to_owned(), err))
+}
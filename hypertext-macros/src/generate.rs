@@ -1,19 +1,45 @@
-use std::iter;
+use std::{cell::RefCell, collections::HashSet, iter, rc::Rc};
 
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned};
 use syn::{
     parse_quote, parse_quote_spanned, spanned::Spanned, token::Brace, Block, Expr, ExprBlock,
-    ExprIf, LitStr, Stmt, Token,
+    ExprIf, Label, Lifetime, LitStr, Stmt, Token,
 };
 
-pub fn normal(value: impl Generate, len_estimate: usize, r#move: bool) -> TokenStream {
+use crate::{
+    options::Options,
+    stats::{self, Stats},
+};
+
+/// Whether to generate a minimal expansion for IDEs, enabled with `--cfg
+/// hypertext_ide`.
+///
+/// This keeps every user expression (with its span), but skips static parts
+/// and all checks, so the output renders incorrectly but is much cheaper to
+/// analyze.
+const IDE_MODE: bool = cfg!(hypertext_ide);
+
+/// The number of bytes reserved for each dynamic part of a template when
+/// estimating its rendered length.
+const DYNAMIC_LEN_ESTIMATE: usize = 16;
+
+pub fn normal(
+    macro_name: &str,
+    value: impl Generate,
+    options: Options,
+    r#move: bool,
+) -> TokenStream {
     let output_ident = Ident::new("hypertext_output", Span::mixed_site());
+    let depth_ident = Ident::new("hypertext_depth", Span::mixed_site());
 
-    let mut gen = Generator::new(output_ident.clone());
+    let mut gen = Generator::new(output_ident.clone(), options);
 
     gen.push(value);
 
+    let items = gen.items();
+    let len_estimate = gen.len_estimate();
+    let stats = gen.stats.clone();
     let block = gen.finish();
 
     let move_kw = if r#move {
@@ -22,49 +48,252 @@ pub fn normal(value: impl Generate, len_estimate: usize, r#move: bool) -> TokenS
         None
     };
 
-    quote! {
+    let output = quote! {
         {
             extern crate alloc;
 
+            #items
+
             #move_kw |#output_ident: &mut alloc::string::String| {
+                // catch component cycles before they overflow the stack
+                #[cfg(debug_assertions)]
+                let #depth_ident = ::hypertext::RenderDepthGuard::enter();
+
                 #output_ident.reserve(#len_estimate);
                 #block
             }
         }
-    }
+    };
+
+    stats::record(macro_name, *stats.borrow(), &output);
+
+    output
 }
 
-pub fn r#static(output_ident: Ident, value: impl Generate) -> TokenStream {
-    let mut gen = Generator::new(output_ident);
+pub fn r#static(
+    macro_name: &str,
+    output_ident: Ident,
+    options: Options,
+    value: impl Generate,
+) -> TokenStream {
+    let mut gen = Generator::new(output_ident, options);
 
     gen.push(value);
 
+    let items = gen.items();
+    let stats = gen.stats.clone();
     let block = gen.finish_static();
 
-    quote!(::hypertext::Rendered(#block))
+    let output = quote! {
+        {
+            #items
+
+            ::hypertext::Rendered(#block)
+        }
+    };
+
+    stats::record(macro_name, *stats.borrow(), &output);
+
+    output
+}
+
+pub fn r#const(
+    macro_name: &str,
+    output_ident: Ident,
+    options: Options,
+    value: impl Generate,
+) -> TokenStream {
+    let mut gen = Generator::new(output_ident, options);
+
+    gen.push(value);
+
+    let items = gen.items();
+    let stats = gen.stats.clone();
+    let block = gen.finish_const();
+
+    let output = quote! {
+        {
+            #items
+
+            const HYPERTEXT_HTML: &str = #block;
+
+            ::hypertext::Raw(HYPERTEXT_HTML)
+        }
+    };
+
+    stats::record(macro_name, *stats.borrow(), &output);
+
+    output
 }
 
 pub struct Generator {
     output_ident: Ident,
+    options: Options,
     parts: Vec<Part>,
     elements: Vec<Ident>,
     attributes: Vec<(Ident, Ident)>,
     namespaces: Vec<(Ident, Ident)>,
     void_elements: Vec<Ident>,
+    ids: Rc<RefCell<Ids>>,
+    warnings: Rc<RefCell<Vec<Warning>>>,
+    /// The paths of the files included with `@include`.
+    includes: Rc<RefCell<Vec<String>>>,
+    /// The checks already emitted, so each is only emitted once.
+    checked: Rc<RefCell<HashSet<Check>>>,
+    stats: Rc<RefCell<Stats>>,
+    /// The loops this block is in, innermost last.
+    loops: Vec<Loop>,
+}
+
+/// A loop a block is in.
+#[derive(Clone)]
+struct Loop {
+    label: Option<Lifetime>,
+    /// The variable tracking the loop's `@separated_by`, if it has one.
+    separator: Option<Ident>,
+}
+
+/// The literal `id`s defined in an invocation, and the literal references to
+/// them, shared between all nested generators.
+#[derive(Default)]
+struct Ids {
+    defined: HashSet<String>,
+    references: Vec<(String, String, Span)>,
+}
+
+/// A check of the `html_elements` module, identified by the names it checks.
+///
+/// Repeated elements and attributes would otherwise emit identical checks,
+/// which only cost compile time. Only the first occurrence is checked, so
+/// errors point at it.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Check {
+    Element(String),
+    Attribute(String, String),
+    Namespace(String, String),
+    VoidElement(String),
+}
+
+/// A warning, reported as a use of the deprecated item `name`, shared between
+/// all nested generators.
+#[derive(Clone)]
+struct Warning {
+    name: &'static str,
+    note: String,
+    span: Span,
 }
 
 impl Generator {
-    const fn new(output_ident: Ident) -> Self {
+    fn new(output_ident: Ident, options: Options) -> Self {
         Self {
             output_ident,
+            options,
             parts: Vec::new(),
             elements: Vec::new(),
             attributes: Vec::new(),
             namespaces: Vec::new(),
             void_elements: Vec::new(),
+            ids: Rc::new(RefCell::new(Ids::default())),
+            warnings: Rc::new(RefCell::new(Vec::new())),
+            includes: Rc::new(RefCell::new(Vec::new())),
+            checked: Rc::new(RefCell::new(HashSet::new())),
+            stats: Rc::new(RefCell::new(Stats::default())),
+            loops: Vec::new(),
+        }
+    }
+
+    fn nested(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            warnings: self.warnings.clone(),
+            includes: self.includes.clone(),
+            checked: self.checked.clone(),
+            stats: self.stats.clone(),
+            loops: self.loops.clone(),
+            ..Self::new(self.output_ident.clone(), self.options)
         }
     }
 
+    /// Emits the items checked alongside the output: the recorded warnings,
+    /// warnings about literal id references which don't point to any literal
+    /// `id` in the invocation, and the included files, so the crate is
+    /// recompiled when they change.
+    ///
+    /// Warnings can't be emitted on stable, so this uses deprecated items
+    /// instead.
+    fn items(&self) -> Option<Stmt> {
+        if IDE_MODE {
+            return None;
+        }
+
+        let ids = self.ids.borrow();
+
+        let id_references = ids
+            .references
+            .iter()
+            .filter(|(id, _, _)| !ids.defined.contains(id))
+            .map(|(id, attr, span)| Warning {
+                name: "UnresolvedIdReference",
+                note: format!(
+                    "`{attr}` references the id `{id}`, which is not defined in this template"
+                ),
+                span: *span,
+            });
+
+        let warnings = self.warnings.borrow();
+        let warnings =
+            warnings
+                .iter()
+                .cloned()
+                .chain(id_references)
+                .map(|Warning { name, note, span }| {
+                    let name = Ident::new(name, span);
+
+                    quote_spanned! {span=>
+                        {
+                            #[deprecated(note = #note)]
+                            struct #name;
+
+                            let _ = #name;
+                        }
+                    }
+                });
+
+        let includes = self.includes.borrow();
+        let includes = includes.iter().map(|path| {
+            quote!(
+                const _: &str = ::core::include_str!(#path);
+            )
+        });
+
+        let items = warnings.chain(includes).collect::<Vec<_>>();
+
+        (!items.is_empty()).then(|| {
+            parse_quote! {
+                const _: () = {
+                    #(#items)*
+                };
+            }
+        })
+    }
+
+    /// Estimates the rendered length as the length of all top-level static
+    /// parts, plus [`DYNAMIC_LEN_ESTIMATE`] bytes for each dynamic part.
+    ///
+    /// Nested blocks (conditionals, loops, etc.) are counted as a single
+    /// dynamic part, so this is a lower bound for most templates. It is emitted
+    /// as a `reserve` call, so nested templates rendering into the same output
+    /// only reserve what they will add themselves.
+    fn len_estimate(&self) -> usize {
+        self.parts
+            .iter()
+            .map(|part| match part {
+                Part::Static(lit) => lit.value().len(),
+                Part::Dynamic(..) => DYNAMIC_LEN_ESTIMATE,
+            })
+            .sum()
+    }
+
     fn checks(&self) -> Stmt {
         let elements = self.elements.iter().map(|el| quote!(html_elements::#el;));
         let attributes = self
@@ -92,8 +321,17 @@ impl Generator {
         }
     }
 
+    /// Finishes this block, emitting a single `push_str` of a `concat!` for
+    /// each run of static parts.
+    ///
+    /// Elements, attributes and blocks without `@let` all push their static
+    /// parts directly into this generator, so runs only end at dynamic parts
+    /// (splices, control flow, and blocks which need their own scope).
     fn finish(self) -> Block {
-        let mut stmts = vec![self.checks()];
+        let mut stmts = (!IDE_MODE)
+            .then(|| self.checks())
+            .into_iter()
+            .collect::<Vec<_>>();
 
         let output_ident = self.output_ident;
         let mut parts = self.parts.into_iter();
@@ -105,7 +343,7 @@ impl Generator {
                     let static_parts =
                         iter::once(lit).chain(parts.by_ref().map_while(|part| match part {
                             Part::Static(lit) => Some(lit),
-                            Part::Dynamic(stmt, _) => {
+                            Part::Dynamic(stmt, ..) => {
                                 dynamic_stmt = Some(stmt);
                                 None
                             }
@@ -116,10 +354,79 @@ impl Generator {
                     });
                     stmts.extend(dynamic_stmt);
                 }
-                Part::Dynamic(stmt, _) => stmts.push(stmt),
+                Part::Dynamic(stmt, ..) => stmts.push(stmt),
+            }
+        }
+
+        Block {
+            brace_token: Brace::default(),
+            stmts,
+        }
+    }
+
+    /// Finishes this block as a `const` evaluation, where each splice is
+    /// converted to a `ConstPart` and everything is concatenated at compile
+    /// time.
+    fn finish_const(self) -> Block {
+        let mut stmts = (!IDE_MODE)
+            .then(|| self.checks())
+            .into_iter()
+            .collect::<Vec<_>>();
+        let mut const_parts = Vec::<Expr>::new();
+        let mut static_parts = Vec::new();
+
+        for part in self.parts {
+            match part {
+                Part::Static(lit) => static_parts.push(lit),
+                Part::Dynamic(_, span, expr) => {
+                    if !static_parts.is_empty() {
+                        const_parts.push(parse_quote! {
+                            ::hypertext::ConstPart::Static(::core::concat!(#(#static_parts),*))
+                        });
+                        static_parts.clear();
+                    }
+
+                    if let Some(expr) = expr {
+                        const_parts.push(parse_quote_spanned! {expr.span()=>
+                            ::hypertext::ConstSplice(#expr).into_part()
+                        });
+                    } else {
+                        stmts.push(
+                            syn::parse2(
+                                syn::Error::new_spanned(
+                                    Ident::new("_", span.unwrap_or_else(Span::call_site)),
+                                    "const evaluation can only contain splices, not control flow",
+                                )
+                                .into_compile_error(),
+                            )
+                            .unwrap(),
+                        );
+                    }
+                }
             }
         }
 
+        if !static_parts.is_empty() {
+            const_parts.push(parse_quote! {
+                ::hypertext::ConstPart::Static(::core::concat!(#(#static_parts),*))
+            });
+        }
+
+        stmts.push(parse_quote! {
+            const HYPERTEXT_PARTS: &[::hypertext::ConstPart] = &[#(#const_parts),*];
+        });
+        stmts.push(parse_quote! {
+            const HYPERTEXT_LEN: usize = ::hypertext::ConstPart::len(HYPERTEXT_PARTS);
+        });
+        stmts.push(parse_quote! {
+            const HYPERTEXT_BYTES: [u8; HYPERTEXT_LEN] =
+                ::hypertext::ConstPart::concat(HYPERTEXT_PARTS);
+        });
+        stmts.push(Stmt::Expr(
+            parse_quote!(::hypertext::ConstPart::as_str(&HYPERTEXT_BYTES)),
+            None,
+        ));
+
         Block {
             brace_token: Brace::default(),
             stmts,
@@ -127,13 +434,16 @@ impl Generator {
     }
 
     fn finish_static(self) -> Block {
-        let mut stmts = vec![self.checks()];
+        let mut stmts = (!IDE_MODE)
+            .then(|| self.checks())
+            .into_iter()
+            .collect::<Vec<_>>();
         let mut static_parts = Vec::new();
 
         for part in self.parts {
             match part {
                 Part::Static(lit) => static_parts.push(lit),
-                Part::Dynamic(_, span) => stmts.push(
+                Part::Dynamic(_, span, _) => stmts.push(
                     syn::parse2(
                         syn::Error::new_spanned(
                             Ident::new("_", span.unwrap_or_else(Span::call_site)),
@@ -158,7 +468,7 @@ impl Generator {
     }
 
     pub fn block_with(&self, f: impl FnOnce(&mut Self)) -> Block {
-        let mut gen = Self::new(self.output_ident.clone());
+        let mut gen = self.nested();
 
         f(&mut gen);
 
@@ -169,8 +479,84 @@ impl Generator {
         self.block_with(|gen| value.generate(gen))
     }
 
+    /// Generates the body of a loop, in which `@break` and `@continue` can be
+    /// used.
+    ///
+    /// If the loop is `separated`, its separator is tracked by the variable
+    /// named by [`separator_ident`](Self::separator_ident).
+    pub fn loop_block(
+        &self,
+        label: Option<&Label>,
+        separated: bool,
+        value: impl Generate,
+    ) -> Block {
+        let mut gen = self.nested();
+        gen.loops.push(Loop {
+            label: label.map(|label| label.name.clone()),
+            separator: separated.then(|| self.separator_ident()),
+        });
+
+        value.generate(&mut gen);
+
+        gen.finish()
+    }
+
+    /// The name of the output the generated code renders to.
+    pub const fn output_ident(&self) -> &Ident {
+        &self.output_ident
+    }
+
+    /// The name of the variable tracking the separator of a loop directly in
+    /// this block.
+    pub fn separator_ident(&self) -> Ident {
+        Ident::new(
+            &format!("hypertext_separator_{}", self.loops.len()),
+            Span::mixed_site(),
+        )
+    }
+
+    /// The variables tracking the separators of the loops whose iterations
+    /// `@break` or `@continue` with the given label (or none) would cut short,
+    /// innermost first, or [`None`] if it doesn't apply to a loop in this
+    /// template.
+    pub fn cut_separators(&self, label: Option<&Lifetime>) -> Option<Vec<Ident>> {
+        let target = match label {
+            Some(label) => self
+                .loops
+                .iter()
+                .rposition(|l| l.label.as_ref().is_some_and(|l| l.ident == label.ident))?,
+            None => self.loops.len().checked_sub(1)?,
+        };
+
+        Some(
+            self.loops[target..]
+                .iter()
+                .rev()
+                .filter_map(|l| l.separator.clone())
+                .collect(),
+        )
+    }
+
+    /// Generates a closure which renders to its own output argument, like the
+    /// one [`normal`] generates, but borrowing from the enclosing template.
+    ///
+    /// Loops outside the closure cannot be broken out of from inside it.
+    pub fn closure(&self, value: impl Generate) -> Expr {
+        let output_ident = &self.output_ident;
+        let block = {
+            let mut gen = self.nested();
+            gen.loops.clear();
+            value.generate(&mut gen);
+            gen.finish()
+        };
+
+        parse_quote! {
+            |#output_ident: &mut alloc::string::String| #block
+        }
+    }
+
     pub fn in_block(&mut self, f: impl FnOnce(&mut Self)) {
-        let mut gen = Self::new(self.output_ident.clone());
+        let mut gen = self.nested();
 
         f(&mut gen);
 
@@ -186,20 +572,33 @@ impl Generator {
     }
 
     pub fn push_spanned_str(&mut self, s: &'static str, span: Span) {
-        self.parts.push(Part::Static(LitStr::new(s, span)));
+        self.push_lit(LitStr::new(s, span));
     }
 
+    pub fn push_lit(&mut self, lit: LitStr) {
+        if !IDE_MODE {
+            self.parts.push(Part::Static(lit));
+        }
+    }
+
+    /// Pushes a literal, escaped exactly as `Renderable for &str` escapes at
+    /// runtime, so that literals and dynamic values render identically in
+    /// every context.
     #[allow(clippy::needless_pass_by_value)]
     pub fn push_escaped_lit(&mut self, lit: LitStr) {
+        if IDE_MODE {
+            return;
+        }
+
         let value = lit.value();
         let escaped_value = html_escape::encode_double_quoted_attribute(&value);
 
-        self.parts
-            .push(Part::Static(LitStr::new(&escaped_value, lit.span())));
+        self.push_lit(LitStr::new(&escaped_value, lit.span()));
     }
 
     pub fn push_dynamic(&mut self, stmt: Stmt, span: Option<Span>) {
-        self.parts.push(Part::Dynamic(stmt, span));
+        self.stats.borrow_mut().dynamic_parts += 1;
+        self.parts.push(Part::Dynamic(stmt, span, None));
     }
 
     pub fn push_conditional(&mut self, cond: &Expr, f: impl FnOnce(&mut Self)) {
@@ -212,6 +611,50 @@ impl Generator {
         });
     }
 
+    /// Pushes an `if` expression which renders `then` if `cond` holds, and
+    /// `otherwise` if not.
+    pub fn push_conditional_else(
+        &mut self,
+        cond: &Expr,
+        then: impl FnOnce(&mut Self),
+        otherwise: impl FnOnce(&mut Self),
+    ) {
+        let else_branch = Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block: self.block_with(otherwise),
+        });
+
+        self.push_unspanned_expr(ExprIf {
+            attrs: Vec::new(),
+            if_token: <Token![if]>::default(),
+            cond: Box::new(cond.clone()),
+            then_branch: self.block_with(then),
+            else_branch: Some((<Token![else]>::default(), Box::new(else_branch))),
+        });
+    }
+
+    /// Pushes a block which is only compiled if the `cfg` predicate holds.
+    ///
+    /// As the block is a dynamic part, static parts are never merged across
+    /// it.
+    pub fn push_cfg(&mut self, predicate: &TokenStream, f: impl FnOnce(&mut Self)) {
+        // checks in the block may be compiled out, so they mustn't stand in
+        // for the same checks after it
+        let mut gen = Self {
+            checked: Rc::new(RefCell::new(self.checked.borrow().clone())),
+            ..self.nested()
+        };
+
+        f(&mut gen);
+
+        self.push_unspanned_expr(ExprBlock {
+            attrs: vec![parse_quote!(#[cfg(#predicate)])],
+            label: None,
+            block: gen.finish(),
+        });
+    }
+
     pub fn push_expr(&mut self, expr: impl Into<Expr> + Spanned) {
         let span = expr.span();
         let expr = expr.into();
@@ -224,9 +667,19 @@ impl Generator {
 
     pub fn push_rendered_expr(&mut self, expr: &Expr) {
         let output_ident = &self.output_ident;
-        self.push_dynamic(
+        self.stats.borrow_mut().dynamic_parts += 1;
+        self.parts.push(Part::Dynamic(
             parse_quote_spanned!(expr.span()=> ::hypertext::Renderable::render_to(#expr, #output_ident);),
             Some(expr.span()),
+            Some(expr.clone()),
+        ));
+    }
+
+    pub fn push_attribute_set_expr(&mut self, expr: &Expr) {
+        let output_ident = &self.output_ident;
+        self.push_dynamic(
+            parse_quote_spanned!(expr.span()=> ::hypertext::AttributeSet::render_attributes(#expr, #output_ident);),
+            Some(expr.span()),
         );
     }
 
@@ -237,29 +690,112 @@ impl Generator {
     }
 
     pub fn push(&mut self, value: impl Generate) {
+        self.stats.borrow_mut().nodes += 1;
         value.generate(self);
     }
 
+    /// Pushes the top-level nodes of the invocation, separating them with
+    /// newlines if requested.
+    pub fn push_top_level(&mut self, values: impl IntoIterator<Item = impl Generate>) {
+        for (i, value) in values.into_iter().enumerate() {
+            if i > 0 {
+                self.push_top_level_separator();
+            }
+
+            self.push(value);
+        }
+    }
+
+    /// Pushes the separator between two top-level nodes, if any.
+    pub fn push_top_level_separator(&mut self) {
+        if self.options.newlines {
+            self.push_str("\n");
+        }
+    }
+
+    /// Pushes the end of an element's start tag.
+    pub fn push_start_tag_end(&mut self, void: bool) {
+        if void && self.options.xhtml {
+            self.push_str(" />");
+        } else {
+            self.push_str(">");
+        }
+    }
+
+    /// Returns whether `check` hasn't been emitted yet, marking it as emitted.
+    fn first_check(&self, check: Check) -> bool {
+        self.checked.borrow_mut().insert(check)
+    }
+
     pub fn record_void_element(&mut self, el_name: &Ident) {
-        self.void_elements.push(el_name.clone());
+        if self.first_check(Check::VoidElement(el_name.to_string())) {
+            self.void_elements.push(el_name.clone());
+        }
     }
 
     pub fn record_element(&mut self, el_name: &Ident) {
-        self.elements.push(el_name.clone());
+        self.stats.borrow_mut().elements += 1;
+
+        if self.first_check(Check::Element(el_name.to_string())) {
+            self.elements.push(el_name.clone());
+        }
     }
 
     pub fn record_attribute(&mut self, el_name: &Ident, attr_name: &Ident) {
-        self.attributes.push((el_name.clone(), attr_name.clone()));
+        self.stats.borrow_mut().attributes += 1;
+
+        if self.first_check(Check::Attribute(el_name.to_string(), attr_name.to_string())) {
+            self.attributes.push((el_name.clone(), attr_name.clone()));
+        }
     }
 
     pub fn record_namespace(&mut self, el_name: &Ident, namespace: &Ident) {
-        self.namespaces.push((el_name.clone(), namespace.clone()));
+        self.stats.borrow_mut().attributes += 1;
+
+        if self.first_check(Check::Namespace(el_name.to_string(), namespace.to_string())) {
+            self.namespaces.push((el_name.clone(), namespace.clone()));
+        }
+    }
+
+    /// Records a warning, reported as a use of the deprecated item `name`.
+    pub fn record_warning(&self, name: &'static str, note: String, span: Span) {
+        self.warnings
+            .borrow_mut()
+            .push(Warning { name, note, span });
+    }
+
+    /// Records a file included with `@include`, so the crate is recompiled
+    /// when it changes.
+    pub fn record_include(&self, path: String) {
+        self.includes.borrow_mut().push(path);
+    }
+
+    /// Records an attribute with a literal value, so that `id`s and references
+    /// to them can be checked.
+    pub fn record_literal_attribute(&self, name: &str, value: &LitStr) {
+        let mut ids = self.ids.borrow_mut();
+
+        match name {
+            "id" => {
+                ids.defined.insert(value.value());
+            }
+            "for" | "headers" | "aria-labelledby" => {
+                for id in value.value().split_ascii_whitespace() {
+                    ids.references
+                        .push((id.to_owned(), name.to_owned(), value.span()));
+                }
+            }
+            _ => {}
+        }
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 enum Part {
     Static(LitStr),
-    Dynamic(Stmt, Option<Span>),
+    /// A dynamic statement, with the span to report errors at, and the
+    /// expression if it renders a splice, for [`r#const`] evaluation.
+    Dynamic(Stmt, Option<Span>, Option<Expr>),
 }
 
 pub trait Generate {
@@ -0,0 +1,181 @@
+use std::{cell::RefCell, fmt::Write, path::PathBuf, str::FromStr};
+
+use proc_macro2::TokenStream;
+use syn::LitStr;
+
+use crate::file::{self, FileError};
+
+syn::custom_keyword!(include);
+
+thread_local! {
+    /// The files currently being parsed, outermost first.
+    static STACK: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Reads the file included by `@include("...")`, relative to the crate's
+/// `CARGO_MANIFEST_DIR`, and parses its contents as tokens with `parse`,
+/// returning its absolute path and the parsed contents.
+///
+/// Files included while `parse` runs are checked against the ones already
+/// being parsed, so an include cycle is an error instead of recursing
+/// forever.
+pub fn read<T>(path: &LitStr, parse: impl FnOnce(TokenStream) -> T) -> syn::Result<(String, T)> {
+    let full_path = enter(path)?;
+    let tokens = file::tokenize(&full_path).map_err(|err| err.spanned(path))?;
+
+    Ok(parse_nested(full_path, tokens, parse))
+}
+
+/// Like [`read`], but for files of rsx, which may be hand-written HTML
+/// containing `<!-- comments -->` with unquoted text.
+///
+/// The text of each such comment is quoted before tokenizing, so it can
+/// contain anything (like apostrophes) and is kept verbatim.
+pub fn read_rsx<T>(
+    path: &LitStr,
+    parse: impl FnOnce(TokenStream) -> T,
+) -> syn::Result<(String, T)> {
+    let full_path = enter(path)?;
+    let source = file::read_to_string(&full_path).map_err(|err| err.spanned(path))?;
+    let tokens = TokenStream::from_str(&quote_comments(&source))
+        .map_err(|err| FileError::Tokenize(full_path.clone(), err).spanned(path))?;
+
+    Ok(parse_nested(full_path, tokens, parse))
+}
+
+/// Resolves `path`, failing if the file is already being parsed.
+fn enter(path: &LitStr) -> syn::Result<PathBuf> {
+    let full_path = file::resolve(&path.value());
+
+    STACK.with_borrow(|stack| {
+        stack
+            .iter()
+            .position(|included| *included == full_path)
+            .map_or(Ok(()), |start| {
+                let mut cycle = stack[start..].to_vec();
+                cycle.push(full_path.clone());
+
+                Err(FileError::Cycle(cycle).spanned(path))
+            })
+    })?;
+
+    Ok(full_path)
+}
+
+/// Runs `parse` on the tokens of `full_path` with it pushed onto the stack of
+/// files being parsed.
+fn parse_nested<T>(
+    full_path: PathBuf,
+    tokens: TokenStream,
+    parse: impl FnOnce(TokenStream) -> T,
+) -> (String, T) {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            STACK.with_borrow_mut(Vec::pop);
+        }
+    }
+
+    let display_path = full_path.to_string_lossy().into_owned();
+
+    STACK.with_borrow_mut(|stack| stack.push(full_path));
+    let _guard = Guard;
+
+    (display_path, parse(tokens))
+}
+
+/// Rewrites each `<!-- text -->` in `source` to `<!-- "text" -->`, skipping
+/// string literals (including raw ones) and comments whose text is already
+/// quoted.
+fn quote_comments(source: &str) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(i) = rest.find(['"', '<']) {
+        let (before, after) = rest.split_at(i);
+        output.push_str(before);
+
+        if before.ends_with('\'') && after.starts_with("\"'") {
+            // a `'"'` char literal
+            output.push('"');
+            rest = &after[1..];
+        } else if after.starts_with('"') {
+            let len = raw_string_hashes(before).map_or_else(
+                || string_literal_len(after),
+                |hashes| raw_string_literal_len(after, hashes),
+            );
+            output.push_str(&after[..len]);
+            rest = &after[len..];
+        } else if let Some(comment) = after.strip_prefix("<!--") {
+            let Some(end) = comment.find("-->") else {
+                output.push_str(after);
+                return output;
+            };
+            let text = &comment[..end];
+            let trimmed = text.trim();
+
+            if trimmed.len() >= 2 && trimmed.starts_with('"') && trimmed.ends_with('"') {
+                output.push_str("<!--");
+                output.push_str(text);
+            } else {
+                let _ = write!(output, "<!-- {text:?} ");
+            }
+            output.push_str("-->");
+            rest = &comment[end + 3..];
+        } else {
+            output.push('<');
+            rest = &after[1..];
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// The length of the string literal at the start of `s`, including its
+/// quotes, or all of `s` if it's unterminated.
+fn string_literal_len(s: &str) -> usize {
+    let mut chars = s.char_indices().skip(1);
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '"' => return i + 1,
+            _ => {}
+        }
+    }
+
+    s.len()
+}
+
+/// The number of `#`s opening a raw string literal, if `before` ends with the
+/// start of one (like `r#`) whose opening quote comes next.
+fn raw_string_hashes(before: &str) -> Option<usize> {
+    let prefix = before.trim_end_matches('#');
+    let hashes = before.len() - prefix.len();
+    let prefix = prefix.strip_suffix('r')?;
+    let prefix = prefix.strip_suffix(['b', 'c']).unwrap_or(prefix);
+
+    // otherwise, the `r` ends an identifier
+    (!prefix.ends_with(|c: char| c.is_alphanumeric() || c == '_')).then_some(hashes)
+}
+
+/// The length of the raw string literal at the start of `s`, opened with
+/// `hashes` `#`s, including its closing quote and `#`s, or all of `s` if it's
+/// unterminated.
+fn raw_string_literal_len(s: &str, hashes: usize) -> usize {
+    let closing = format!("\"{}", "#".repeat(hashes));
+
+    s[1..]
+        .find(&closing)
+        .map_or(s.len(), |i| 1 + i + closing.len())
+}
+
+/// Moves an error from parsing an included file, whose tokens have no useful
+/// spans, onto the path of the file.
+pub fn error(path: &LitStr, err: syn::Error) -> syn::Error {
+    FileError::Parse(path.value(), err).spanned(path)
+}
@@ -1,34 +1,44 @@
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream};
 use proc_macro2_diagnostics::Diagnostic;
 use quote::quote;
 
+mod attribute_renderable;
+mod attribute_set;
+mod css;
+mod file;
 mod generate;
+mod include;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod maud;
+mod options;
 mod rstml;
+#[cfg(feature = "sri")]
+mod sri;
+mod stats;
+mod validate;
 
 #[proc_macro]
 pub fn maud(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let len_estimate = tokens.to_string().len();
-
-    maud::parse(tokens.into())
+    options::parse(tokens.into())
+        .and_then(|(options, tokens)| Ok((options, maud::parse(tokens)?)))
         .map_or_else(
             |err| err.to_compile_error(),
-            |markup| generate::normal(markup, len_estimate, false),
+            |(options, markup)| generate::normal("maud", markup, options, false),
         )
         .into()
 }
 
 #[proc_macro]
 pub fn maud_move(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let len_estimate = tokens.to_string().len();
-
-    maud::parse(tokens.into())
+    options::parse(tokens.into())
+        .and_then(|(options, tokens)| Ok((options, maud::parse(tokens)?)))
         .map_or_else(
             |err| err.to_compile_error(),
-            |markup| generate::normal(markup, len_estimate, true),
+            |(options, markup)| generate::normal("maud_move", markup, options, true),
         )
         .into()
 }
@@ -37,45 +47,41 @@ pub fn maud_move(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn maud_static(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let output_ident = Ident::new("hypertext_output", Span::mixed_site());
 
-    maud::parse(tokens.into())
+    options::parse(tokens.into())
+        .and_then(|(options, tokens)| Ok((options, maud::parse(tokens)?)))
         .map_or_else(
             |err| err.to_compile_error(),
-            |markup| generate::r#static(output_ident, markup),
+            |(options, markup)| generate::r#static("maud_static", output_ident, options, markup),
         )
         .into()
 }
 
 #[proc_macro]
-pub fn rsx(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let len_estimate = tokens.to_string().len();
+pub fn maud_const(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let output_ident = Ident::new("hypertext_output", Span::mixed_site());
 
-    let (nodes, diagnostics) = rstml::parse(tokens.into());
-    let output = generate::normal(nodes, len_estimate, false);
-    let diagnostics = diagnostics.into_iter().map(Diagnostic::emit_as_expr_tokens);
+    options::parse(tokens.into())
+        .and_then(|(options, tokens)| Ok((options, maud::parse(tokens)?)))
+        .map_or_else(
+            |err| err.to_compile_error(),
+            |(options, markup)| generate::r#const("maud_const", output_ident, options, markup),
+        )
+        .into()
+}
 
-    quote! {
-        {
-            #(#diagnostics;)*
-            #output
-        }
-    }
+#[proc_macro]
+pub fn rsx(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    rsx_with(tokens.into(), |nodes, options| {
+        generate::normal("rsx", nodes, options, false)
+    })
     .into()
 }
 
 #[proc_macro]
 pub fn rsx_move(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    let len_estimate = tokens.to_string().len();
-
-    let (nodes, diagnostics) = rstml::parse(tokens.into());
-    let output = generate::normal(nodes, len_estimate, true);
-    let diagnostics = diagnostics.into_iter().map(Diagnostic::emit_as_expr_tokens);
-
-    quote! {
-        {
-            #(#diagnostics;)*
-            #output
-        }
-    }
+    rsx_with(tokens.into(), |nodes, options| {
+        generate::normal("rsx_move", nodes, options, true)
+    })
     .into()
 }
 
@@ -83,8 +89,67 @@ pub fn rsx_move(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
 pub fn rsx_static(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let output_ident = Ident::new("hypertext_output", Span::mixed_site());
 
-    let (nodes, diagnostics) = rstml::parse(tokens.into());
-    let output = generate::r#static(output_ident, nodes);
+    rsx_with(tokens.into(), |nodes, options| {
+        generate::r#static("rsx_static", output_ident, options, nodes)
+    })
+    .into()
+}
+
+#[proc_macro]
+pub fn css(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse::<css::Css>(tokens)
+        .map_or_else(
+            |err| err.to_compile_error(),
+            |css| generate::normal("css", css, options::Options::default(), false),
+        )
+        .into()
+}
+
+#[cfg(feature = "sri")]
+#[proc_macro]
+pub fn script_sri(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse(tokens)
+        .and_then(|args| sri::generate(&args))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[cfg(feature = "markdown")]
+#[proc_macro]
+pub fn markdown_static(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse(tokens)
+        .and_then(|args| markdown::generate(&args))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(AttributeRenderable, attributes(attribute))]
+pub fn derive_attribute_renderable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse(input)
+        .and_then(|input| attribute_renderable::derive(&input))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(AttributeSet, attributes(attr))]
+pub fn derive_attribute_set(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    syn::parse(input)
+        .and_then(|input| attribute_set::derive(&input))
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn rsx_with(
+    tokens: TokenStream,
+    f: impl FnOnce(Vec<::rstml::node::Node<rstml::Keyword>>, options::Options) -> TokenStream,
+) -> TokenStream {
+    let (options, tokens) = match options::parse(tokens) {
+        Ok(parsed) => parsed,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let (nodes, diagnostics) = rstml::parse(tokens);
+    let output = f(nodes, options);
     let diagnostics = diagnostics.into_iter().map(Diagnostic::emit_as_expr_tokens);
 
     quote! {
@@ -93,5 +158,4 @@ pub fn rsx_static(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
             #output
         }
     }
-    .into()
 }
@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use pulldown_cmark::{html, Options, Parser};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    LitStr, Token,
+};
+
+use crate::file::{read_to_string, resolve};
+
+syn::custom_keyword!(file);
+
+/// The argument of `markdown_static!`, either a string literal of Markdown or
+/// `file = "docs/intro.md"`.
+pub enum MarkdownStatic {
+    Source(LitStr),
+    File(LitStr),
+}
+
+impl Parse for MarkdownStatic {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let args = if input.peek(file) {
+            input.parse::<file>()?;
+            input.parse::<Token![=]>()?;
+            Self::File(input.parse()?)
+        } else {
+            Self::Source(input.parse()?)
+        };
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(args)
+    }
+}
+
+pub fn generate(args: &MarkdownStatic) -> syn::Result<TokenStream> {
+    match args {
+        MarkdownStatic::Source(source) => {
+            let html = to_html(&source.value());
+
+            Ok(quote!(::hypertext::Raw(#html)))
+        }
+        MarkdownStatic::File(file) => {
+            let path = resolve(&file.value());
+            let source = read_to_string(&path).map_err(|err| err.spanned(file))?;
+
+            let html = to_html(&source);
+            let path = path.to_string_lossy();
+
+            Ok(quote! {
+                {
+                    // recompile when the file changes
+                    const _: &str = ::core::include_str!(#path);
+
+                    ::hypertext::Raw(#html)
+                }
+            })
+        }
+    }
+}
+
+/// Converts Markdown to HTML with the same options as `hypertext::Markdown`.
+fn to_html(source: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES;
+
+    let mut html = String::new();
+    html::push_html(&mut html, Parser::new_ext(source, options));
+    html
+}
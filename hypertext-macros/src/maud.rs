@@ -1,23 +1,26 @@
-#![allow(clippy::struct_field_names)]
+#![allow(clippy::struct_field_names, clippy::large_enum_variant)]
 
-use std::ops::ControlFlow;
-
-use proc_macro2::TokenStream;
-use quote::ToTokens;
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
 use syn::{
     braced, bracketed,
     ext::IdentExt,
     parenthesized,
-    parse::{Parse, ParseStream},
-    parse_quote,
+    parse::{Parse, ParseStream, Parser},
+    parse_quote, parse_quote_spanned,
     punctuated::{Pair, Punctuated},
     spanned::Spanned,
     token::{At, Brace, Bracket, Comma, Else, FatArrow, For, If, In, Match, Paren, While},
-    Arm, Expr, ExprBlock, ExprForLoop, ExprIf, ExprMatch, ExprParen, ExprWhile, Ident, LitBool,
-    LitInt, LitStr, Local, Pat, Stmt, Token,
+    Arm, Expr, ExprBlock, ExprForLoop, ExprIf, ExprMatch, ExprParen, ExprWhile, Ident, Label,
+    Lifetime, LitBool, LitInt, LitStr, Local, Pat, Stmt, Token,
 };
 
-use crate::generate::{Generate, Generator};
+use crate::{
+    generate::{Generate, Generator},
+    include,
+    rstml::RsxBlock,
+    validate,
+};
 
 pub fn parse(tokens: TokenStream) -> syn::Result<Markup> {
     syn::parse2(tokens)
@@ -60,9 +63,13 @@ impl Generate for Markup {
     fn generate(&self, gen: &mut Generator) {
         if let Some(doctype) = &self.doctype {
             gen.push(doctype);
+
+            if !self.nodes.is_empty() {
+                gen.push_top_level_separator();
+            }
         }
 
-        gen.push_all(&self.nodes);
+        gen.push_top_level(&self.nodes);
     }
 }
 
@@ -72,6 +79,7 @@ syn::custom_keyword!(DOCTYPE);
 struct Doctype {
     bang_token: Token![!],
     name: DOCTYPE,
+    legacy: Option<LitStr>,
 }
 
 impl Parse for Doctype {
@@ -79,6 +87,20 @@ impl Parse for Doctype {
         Ok(Self {
             bang_token: input.parse()?,
             name: input.parse()?,
+            legacy: if input.peek(LitStr) {
+                let lit = input.parse::<LitStr>()?;
+
+                if lit.value().contains(['<', '>']) {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "doctype strings cannot contain `<` or `>`",
+                    ));
+                }
+
+                Some(lit)
+            } else {
+                None
+            },
         })
     }
 }
@@ -87,23 +109,45 @@ impl ToTokens for Doctype {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.bang_token.to_tokens(tokens);
         self.name.to_tokens(tokens);
+        if let Some(legacy) = &self.legacy {
+            legacy.to_tokens(tokens);
+        }
     }
 }
 
 impl Generate for Doctype {
     fn generate(&self, gen: &mut Generator) {
-        gen.push_spanned_str("<!DOCTYPE html>", self.span());
+        if let Some(legacy) = &self.legacy {
+            gen.push_spanned_str("<!DOCTYPE ", self.name.span());
+            gen.push_lit(legacy.clone());
+            gen.push_str(">");
+        } else {
+            gen.push_spanned_str("<!DOCTYPE html>", self.span());
+        }
     }
 }
 
 trait Node: Parse + ToTokens + Generate {
     fn is_let(&self) -> bool;
+
+    /// Adds a `data-key` attribute with the key of a keyed `@for` to this
+    /// node, which must be an element.
+    fn set_key(&mut self, key: &Key) -> syn::Result<()> {
+        Err(syn::Error::new_spanned(
+            key,
+            "keyed `@for` loops can only be used in element content",
+        ))
+    }
 }
 
 #[derive(Debug, Clone)]
 enum ElementNode {
     Block(Block<Self>),
     Element(Element),
+    Component(Component),
+    Rsx(RsxBlock),
+    Include(Include),
+    Raw(RawBlock),
     Splice(Splice),
     Literal(Lit),
     Keyword(Keyword<Self>),
@@ -119,6 +163,39 @@ impl Node for ElementNode {
             })
         )
     }
+
+    fn set_key(&mut self, key: &Key) -> syn::Result<()> {
+        let Self::Element(element) = self else {
+            return Err(syn::Error::new_spanned(
+                self,
+                "the body of a keyed `@for` must be a single element",
+            ));
+        };
+
+        if element
+            .attrs
+            .iter()
+            .any(|attr| !attr.name.is_quoted() && attr.name.lit().value() == "data-key")
+        {
+            return Err(syn::Error::new_spanned(
+                &element.name,
+                "the root element of a keyed `@for` already has a `data-key` attribute",
+            ));
+        }
+
+        let span = key.keyed_token.span;
+
+        element.attrs.push(Attribute {
+            name: parse_quote_spanned!(span=> data-key),
+            kind: AttributeKind::Normal {
+                eq_token: Token![=](span),
+                value: AttributeValueNode::Splice(key.splice.clone()),
+                toggle: None,
+            },
+        });
+
+        Ok(())
+    }
 }
 
 impl Parse for ElementNode {
@@ -127,12 +204,32 @@ impl Parse for ElementNode {
 
         if lookahead.peek(Brace) {
             input.parse().map(Self::Block)
+        } else if peek_quoted_element(input) {
+            input.parse().map(Self::Element)
         } else if lookahead.peek(LitStr) || lookahead.peek(LitInt) || lookahead.peek(LitBool) {
             input.parse().map(Self::Literal)
         } else if lookahead.peek(Paren) {
             input.parse().map(Self::Splice)
         } else if lookahead.peek(Ident::peek_any) {
+            if peek_component(input) {
+                input.parse().map(Self::Component)
+            } else {
+                input.parse().map(Self::Element)
+            }
+        } else if RsxBlock::peek(input) {
+            input.parse().map(Self::Rsx)
+        } else if Include::peek(input) {
+            input.parse().map(Self::Include)
+        } else if RawBlock::peek(input) {
+            input.parse().map(Self::Raw)
+        } else if input.peek(Token![@]) && input.peek2(element) {
+            input.parse::<Token![@]>()?;
+            input.parse::<element>()?;
             input.parse().map(Self::Element)
+        } else if input.peek(Token![@]) && input.peek2(component) {
+            input.parse::<Token![@]>()?;
+            input.parse::<component>()?;
+            input.parse().map(Self::Component)
         } else if lookahead.peek(Token![@]) {
             input.parse().map(Self::Keyword)
         } else {
@@ -141,11 +238,18 @@ impl Parse for ElementNode {
     }
 }
 
+syn::custom_keyword!(element);
+syn::custom_keyword!(component);
+
 impl ToTokens for ElementNode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             Self::Block(block) => block.to_tokens(tokens),
             Self::Element(element) => element.to_tokens(tokens),
+            Self::Component(component) => component.to_tokens(tokens),
+            Self::Rsx(rsx) => rsx.to_tokens(tokens),
+            Self::Include(include) => include.to_tokens(tokens),
+            Self::Raw(raw) => raw.to_tokens(tokens),
             Self::Splice(splice) => splice.to_tokens(tokens),
             Self::Literal(lit) => lit.to_tokens(tokens),
             Self::Keyword(kw) => kw.to_tokens(tokens),
@@ -158,6 +262,10 @@ impl Generate for ElementNode {
         match self {
             Self::Block(block) => gen.push(block),
             Self::Element(element) => gen.push(element),
+            Self::Component(component) => gen.push(component),
+            Self::Rsx(rsx) => gen.push(rsx),
+            Self::Include(include) => gen.push(include),
+            Self::Raw(raw) => gen.push(raw),
             Self::Splice(splice) => gen.push(splice),
             Self::Literal(lit) => gen.push(lit),
             Self::Keyword(kw) => gen.push(kw),
@@ -207,6 +315,188 @@ impl<N: Node> Generate for Block<N> {
     }
 }
 
+syn::custom_keyword!(maud);
+
+/// An `@maud { ... }` block nested in `rsx!`, whose contents are parsed as maud
+/// and rendered inline.
+#[derive(Debug, Clone)]
+pub struct MaudBlock {
+    at_token: At,
+    maud_token: maud,
+    block: Block<ElementNode>,
+}
+
+impl MaudBlock {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![@]) && input.peek2(maud)
+    }
+}
+
+impl Parse for MaudBlock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            at_token: input.parse()?,
+            maud_token: input.parse()?,
+            block: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for MaudBlock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.maud_token.to_tokens(tokens);
+        self.block.to_tokens(tokens);
+    }
+}
+
+impl Generate for MaudBlock {
+    fn generate(&self, gen: &mut Generator) {
+        gen.push(&self.block);
+    }
+}
+
+/// An `@include("...")` of a file of maud, which is read at compile time and
+/// rendered inline.
+#[derive(Debug, Clone)]
+struct Include {
+    at_token: At,
+    include_token: include::include,
+    paren_token: Paren,
+    path: LitStr,
+    full_path: String,
+    nodes: Vec<ElementNode>,
+}
+
+impl Include {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(Token![@]) && input.peek2(include::include)
+    }
+}
+
+impl Parse for Include {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let at_token = input.parse()?;
+        let include_token = input.parse()?;
+        let paren_token = parenthesized!(content in input);
+        let path = content.parse()?;
+        let (full_path, nodes) = include::read(&path, |tokens| {
+            (|input: ParseStream| {
+                let mut nodes = Vec::new();
+                while !input.is_empty() {
+                    nodes.push(input.parse()?);
+                }
+                Ok(nodes)
+            })
+            .parse2(tokens)
+        })?;
+        let nodes = nodes.map_err(|err| include::error(&path, err))?;
+
+        Ok(Self {
+            at_token,
+            include_token,
+            paren_token,
+            path,
+            full_path,
+            nodes,
+        })
+    }
+}
+
+impl ToTokens for Include {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.include_token.to_tokens(tokens);
+        self.paren_token.surround(tokens, |tokens| {
+            self.path.to_tokens(tokens);
+        });
+    }
+}
+
+impl Generate for Include {
+    fn generate(&self, gen: &mut Generator) {
+        gen.record_include(self.full_path.clone());
+
+        // keep `@let` bindings from leaking out of the included file
+        if self.nodes.iter().any(Node::is_let) {
+            gen.in_block(|gen| gen.push_all(&self.nodes));
+        } else {
+            gen.push_all(&self.nodes);
+        }
+    }
+}
+
+syn::custom_keyword!(raw);
+
+/// A `@raw { "..." }` block of string literals, which are rendered verbatim
+/// as HTML.
+#[derive(Debug, Clone)]
+struct RawBlock {
+    at_token: At,
+    raw_token: raw,
+    brace_token: Brace,
+    lits: Vec<LitStr>,
+}
+
+impl RawBlock {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(Token![@]) && input.peek2(raw)
+    }
+}
+
+impl Parse for RawBlock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let at_token = input.parse()?;
+        let raw_token: raw = input.parse()?;
+        let brace_token = braced!(content in input);
+
+        let mut lits = Vec::new();
+        while !content.is_empty() {
+            if !content.peek(LitStr) {
+                return Err(syn::Error::new_spanned(
+                    content.parse::<TokenTree>()?,
+                    "`@raw` blocks can only contain string literals",
+                ));
+            }
+            lits.push(content.parse::<LitStr>()?);
+        }
+
+        let html = lits.iter().map(LitStr::value).collect::<String>();
+        if let Some(message) = validate::raw_html_error(&html) {
+            return Err(syn::Error::new(raw_token.span, message));
+        }
+
+        Ok(Self {
+            at_token,
+            raw_token,
+            brace_token,
+            lits,
+        })
+    }
+}
+
+impl ToTokens for RawBlock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.raw_token.to_tokens(tokens);
+        self.brace_token.surround(tokens, |tokens| {
+            for lit in &self.lits {
+                lit.to_tokens(tokens);
+            }
+        });
+    }
+}
+
+impl Generate for RawBlock {
+    fn generate(&self, gen: &mut Generator) {
+        for lit in &self.lits {
+            gen.push_lit(lit.clone());
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Splice {
     paren_token: Paren,
@@ -216,13 +506,21 @@ struct Splice {
 impl Parse for Splice {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
-        Ok(Self {
-            paren_token: parenthesized!(content in input),
-            expr: content.parse()?,
-        })
+        let paren_token = parenthesized!(content in input);
+        let expr = content.parse().and_then(reject_await)?;
+
+        Ok(Self { paren_token, expr })
     }
 }
 
+/// Fails if `tokens` contain an `.await`, which would otherwise be reported
+/// as an error about the closure the macro generates.
+fn reject_await<T: ToTokens>(tokens: T) -> syn::Result<T> {
+    validate::await_span(tokens.to_token_stream()).map_or(Ok(tokens), |span| {
+        Err(syn::Error::new(span, validate::AWAIT_ERROR))
+    })
+}
+
 impl ToTokens for Splice {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.paren_token.surround(tokens, |tokens| {
@@ -243,32 +541,101 @@ struct Element {
     id: Option<IdAttribute>,
     classes: Option<Classes>,
     attrs: Vec<Attribute>,
+    spreads: Vec<Spread>,
     body: ElementBody,
 }
 
 impl Parse for Element {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let id = if input.peek(Token![#]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let mut classes = if peek_class(input) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
         Ok(Self {
-            name: input.parse()?,
-            id: if input.peek(Token![#]) {
-                Some(input.parse()?)
-            } else {
-                None
-            },
-            classes: if input.peek(Token![.]) {
-                Some(input.parse()?)
-            } else {
-                None
-            },
+            name,
+            id,
             attrs: {
-                let mut attrs = Vec::new();
+                let mut attrs = Vec::<Attribute>::new();
+                let mut class_lists = Vec::<ClassList>::new();
 
                 while input.peek(Ident::peek_any) || input.peek(LitStr) || input.peek(LitInt) {
-                    attrs.push(input.parse()?);
+                    if ClassList::peek(input) {
+                        class_lists.push(input.parse()?);
+                    } else {
+                        attrs.push(input.parse()?);
+                    }
+
+                    if input.peek(Token![,]) {
+                        return Err(syn::Error::new(
+                            input.span(),
+                            "attributes are separated by spaces, not commas",
+                        ));
+                    }
+                }
+
+                if !class_lists.is_empty() {
+                    if let Some(attr) = attrs
+                        .iter()
+                        .find(|attr| !attr.name.is_quoted() && attr.name.lit().value() == "class")
+                    {
+                        return Err(syn::Error::new(
+                            attr.name.span(),
+                            "`class` can't be set alongside `class:[...]`, use `.` classes \
+                             instead",
+                        ));
+                    }
+                }
+
+                if !class_lists.is_empty() {
+                    let classes = classes.get_or_insert_with(|| Classes {
+                        classes: Vec::new(),
+                        toggled_classes: Vec::new(),
+                    });
+
+                    classes.toggled_classes.extend(
+                        class_lists
+                            .into_iter()
+                            .flat_map(ClassList::into_toggled_classes),
+                    );
+                }
+
+                let names = attrs
+                    .iter()
+                    .filter(|attr| !attr.name.is_data() && !attr.name.is_quoted())
+                    .map(|attr| (attr.name.lit().value(), attr.name.span()))
+                    .collect::<Vec<_>>();
+
+                if let Some(error) = validate::attribute_collisions(&names)
+                    .into_iter()
+                    .map(|(span, message)| syn::Error::new(span, message))
+                    .reduce(|mut errors, error| {
+                        errors.combine(error);
+                        errors
+                    })
+                {
+                    return Err(error);
                 }
 
                 attrs
             },
+            classes,
+            spreads: {
+                let mut spreads = Vec::new();
+
+                while input.peek(Token![..]) {
+                    spreads.push(input.parse()?);
+                }
+
+                spreads
+            },
             body: input.parse()?,
         })
     }
@@ -286,26 +653,46 @@ impl ToTokens for Element {
         for attr in &self.attrs {
             attr.to_tokens(tokens);
         }
+        for spread in &self.spreads {
+            spread.to_tokens(tokens);
+        }
         self.body.to_tokens(tokens);
     }
 }
 
 impl Generate for Element {
     fn generate(&self, gen: &mut Generator) {
-        gen.record_element(&self.name.ident());
+        // quoted element names opt out of all validation
+        let el = (!self.name.is_quoted()).then(|| self.name.ident());
+
+        if let Some(el) = &el {
+            gen.record_element(el);
+        }
 
         gen.push_str("<");
         gen.push_escaped_lit(self.name.lit());
 
         if let Some(id) = &self.id {
-            gen.record_attribute(&self.name.ident(), &id.attr_name_ident());
+            if let Some(el) = &el {
+                gen.record_attribute(el, &id.attr_name_ident());
+            }
+
+            match &id.value {
+                IdOrClassNode::Value(AttributeValueNode::Literal(lit)) => {
+                    gen.record_literal_attribute("id", &lit.lit_str());
+                }
+                IdOrClassNode::Name(name) => gen.record_literal_attribute("id", &name.lit()),
+                IdOrClassNode::Value(_) => {}
+            }
 
             gen.push_str(" ");
             gen.push(id);
         }
 
         if let Some(classes) = &self.classes {
-            gen.record_attribute(&self.name.ident(), &classes.attr_name_ident());
+            if let Some(el) = &el {
+                gen.record_attribute(el, &classes.attr_name_ident());
+            }
 
             gen.push_str(" ");
             gen.push(classes);
@@ -314,31 +701,66 @@ impl Generate for Element {
         for attr in &self.attrs {
             gen.push(attr);
 
-            let mut name_pairs = attr.name.name.pairs();
-            if name_pairs.next().is_some_and(|pair| {
-                if let Pair::Punctuated(NameFragment::Ident(ident), NamePunct::Hyphen(_)) = pair {
-                    ident == "data"
-                } else {
-                    false
-                }
-            }) && name_pairs.next().is_some()
+            if let AttributeKind::Normal {
+                value: AttributeValueNode::Literal(Lit::Str(lit)),
+                ..
+            } = &attr.kind
             {
+                gen.record_literal_attribute(&attr.name.lit().value(), lit);
+            }
+
+            let Some(el) = &el else {
+                continue;
+            };
+
+            if attr.name.is_data() || attr.name.is_quoted() {
                 continue;
             }
 
             let (attr_ident, is_namespace) = attr.name.ident_or_namespace();
 
             if is_namespace {
-                gen.record_namespace(&self.name.ident(), &attr_ident);
+                gen.record_namespace(el, &attr_ident);
             } else {
-                gen.record_attribute(&self.name.ident(), &attr_ident);
+                gen.record_attribute(el, &attr_ident);
             }
         }
 
-        gen.push_str(">");
+        gen.push_all(&self.spreads);
+
+        // spreads may add a `rel`, so they skip the check
+        if el.is_some() && self.spreads.is_empty() {
+            let attrs = self
+                .attrs
+                .iter()
+                .map(|attr| {
+                    let value = match &attr.kind {
+                        AttributeKind::Normal {
+                            value: AttributeValueNode::Literal(lit),
+                            toggle: None,
+                            ..
+                        } => Some(lit.lit_str().value()),
+                        AttributeKind::Empty(_, None) => Some(String::new()),
+                        _ => None,
+                    };
+
+                    (attr.name.lit().value(), value)
+                })
+                .collect::<Vec<_>>();
+
+            if let Some(note) = validate::blank_target_warning(&self.name.lit().value(), &attrs) {
+                gen.record_warning("UnsafeBlankTarget", note.to_owned(), self.name.span());
+            }
+        }
+
+        gen.push_start_tag_end(matches!(self.body, ElementBody::Void(_)));
 
         match &self.body {
-            ElementBody::Void(_) => gen.record_void_element(&self.name.ident()),
+            ElementBody::Void(_) => {
+                if let Some(el) = &el {
+                    gen.record_void_element(el);
+                }
+            }
             ElementBody::Block(block) => {
                 gen.push(block);
                 gen.push_str("</");
@@ -378,6 +800,506 @@ impl ToTokens for ElementBody {
     }
 }
 
+/// A set of attributes spread onto an element, like `..(attrs)`.
+#[derive(Debug, Clone)]
+struct Spread {
+    dot2_token: Token![..],
+    splice: Splice,
+}
+
+impl Parse for Spread {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            dot2_token: input.parse()?,
+            splice: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Spread {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.dot2_token.to_tokens(tokens);
+        self.splice.to_tokens(tokens);
+    }
+}
+
+impl Generate for Spread {
+    fn generate(&self, gen: &mut Generator) {
+        gen.push_attribute_set_expr(&self.splice.expr);
+    }
+}
+
+/// Whether the next node is an element with a quoted name, like
+/// `"my-élément" { ... }`.
+///
+/// A string literal is only an element name if it is directly followed by
+/// something which could not otherwise follow text: its body, its `id` or
+/// `class` shorthands, or an attribute with a value.
+fn peek_quoted_element(input: ParseStream) -> bool {
+    if !input.peek(LitStr) {
+        return false;
+    }
+
+    if input.peek2(Brace)
+        || input.peek2(Token![;])
+        || input.peek2(Token![#])
+        || (input.peek2(Token![.]) && !input.peek2(Token![..]))
+    {
+        return true;
+    }
+
+    let fork = input.fork();
+
+    fork.parse::<LitStr>().is_ok() && fork.parse::<Name>().is_ok() && fork.peek(Token![=])
+}
+
+/// Whether the next node is a component invocation, which is any name starting
+/// with an uppercase letter.
+///
+/// This can be overridden with `@element Name` or `@component name`.
+fn peek_component(input: ParseStream) -> bool {
+    input.fork().call(Ident::parse_any).is_ok_and(|ident| {
+        ident
+            .to_string()
+            .starts_with(|c: char| c.is_ascii_uppercase())
+    })
+}
+
+syn::custom_keyword!(slot);
+
+/// A component invocation, like `Card title=("Hello") { ... }`.
+///
+/// This renders a struct expression of the component, with each attribute as
+/// a field. The `#id` and `.class` shorthands are passed as the `id` and
+/// `class` fields. The default children are passed as the `children` field and
+/// each `@slot name { ... }` as the field `name`, all as [`Renderable`]
+/// closures.
+///
+/// [`Renderable`]: https://docs.rs/hypertext/latest/hypertext/trait.Renderable.html
+#[derive(Debug, Clone)]
+struct Component {
+    name: Ident,
+    id: Option<IdAttribute>,
+    classes: Option<Classes>,
+    attrs: Vec<ComponentAttribute>,
+    body: ComponentBody,
+}
+
+impl Component {
+    /// The value of the `id` field, if given with the `#id` shorthand.
+    fn id_value(&self) -> Option<TokenStream> {
+        let id = self.id.as_ref()?;
+        let name = id.attr_name_ident();
+        let value = shorthand_value(&id.value);
+
+        Some(quote!(#name: #value,))
+    }
+
+    /// The value of the `class` field, if given with the `.class` shorthand.
+    ///
+    /// Literal classes are joined at expansion time, otherwise they are joined
+    /// at runtime with `hypertext::join_classes`.
+    fn class_value(&self) -> Option<TokenStream> {
+        let classes = self.classes.as_ref()?;
+        let name = classes.attr_name_ident();
+
+        let literals = classes
+            .classes
+            .iter()
+            .map(|class| match &class.value {
+                IdOrClassNode::Value(AttributeValueNode::Literal(lit)) => {
+                    Some(lit.lit_str().value())
+                }
+                IdOrClassNode::Name(name) => Some(name.lit().value()),
+                IdOrClassNode::Value(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|_| classes.toggled_classes.is_empty());
+
+        let value = literals.map_or_else(
+            || {
+                let classes = classes
+                    .classes
+                    .iter()
+                    .map(|class| {
+                        let value = shorthand_value(&class.value);
+
+                        quote!(::core::option::Option::Some(&#value as &dyn ::core::fmt::Display))
+                    })
+                    .chain(classes.toggled_classes.iter().map(|class| {
+                        let value = shorthand_value(&class.value);
+                        let cond = class.toggle.parenthesized_cond();
+
+                        quote!(#cond.then_some(&#value as &dyn ::core::fmt::Display))
+                    }));
+
+                quote!(::hypertext::join_classes(&[#(#classes),*]))
+            },
+            |literals| LitStr::new(&literals.join(" "), classes.span()).into_token_stream(),
+        );
+
+        Some(quote!(#name: #value,))
+    }
+}
+
+/// The field value of a component's `#id` or `.class` shorthand.
+fn shorthand_value(node: &IdOrClassNode) -> TokenStream {
+    match node {
+        IdOrClassNode::Value(AttributeValueNode::Literal(lit)) => lit.lit_str().to_token_stream(),
+        IdOrClassNode::Name(name) => name.lit().to_token_stream(),
+        IdOrClassNode::Value(AttributeValueNode::Splice(splice)) => splice.expr.to_token_stream(),
+        IdOrClassNode::Value(AttributeValueNode::Block(_) | AttributeValueNode::Keyword(_)) => {
+            unreachable!("component shorthands are checked while parsing")
+        }
+    }
+}
+
+/// Checks that a component's `#id` or `.class` shorthand can be passed as a
+/// field value.
+fn check_shorthand(node: &IdOrClassNode) -> syn::Result<()> {
+    match node {
+        IdOrClassNode::Name(_)
+        | IdOrClassNode::Value(AttributeValueNode::Literal(_) | AttributeValueNode::Splice(_)) => {
+            Ok(())
+        }
+        IdOrClassNode::Value(AttributeValueNode::Block(_) | AttributeValueNode::Keyword(_)) => {
+            Err(syn::Error::new_spanned(
+                node,
+                "component shorthands must be names, literals or splices",
+            ))
+        }
+    }
+}
+
+impl Parse for Component {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.parse()?,
+            id: if input.peek(Token![#]) {
+                let id = input.parse::<IdAttribute>()?;
+                check_shorthand(&id.value)?;
+
+                Some(id)
+            } else {
+                None
+            },
+            classes: if peek_class(input) {
+                let classes = input.parse::<Classes>()?;
+                for value in classes
+                    .classes
+                    .iter()
+                    .map(|class| &class.value)
+                    .chain(classes.toggled_classes.iter().map(|class| &class.value))
+                {
+                    check_shorthand(value)?;
+                }
+
+                Some(classes)
+            } else {
+                None
+            },
+            attrs: {
+                let mut attrs = Vec::new();
+
+                while input.peek(Ident::peek_any) {
+                    attrs.push(input.parse()?);
+                }
+
+                attrs
+            },
+            body: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Component {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        self.id.to_tokens(tokens);
+        self.classes.to_tokens(tokens);
+        for attr in &self.attrs {
+            attr.to_tokens(tokens);
+        }
+        self.body.to_tokens(tokens);
+    }
+}
+
+impl Generate for Component {
+    fn generate(&self, gen: &mut Generator) {
+        let name = &self.name;
+        let id = self.id_value();
+        let class = self.class_value();
+
+        let attrs = self.attrs.iter().map(|attr| {
+            let name = &attr.name;
+            let value = &attr.value;
+
+            quote!(#name: #value)
+        });
+
+        let slots = self.body.slots().map(|slot| {
+            let name = &slot.name;
+            let closure = gen.closure(&slot.block);
+
+            quote!(#name: #closure)
+        });
+
+        let children = self.body.children().map(|children| {
+            let name = Ident::new("children", self.body.span());
+            let closure = gen.closure(children);
+
+            quote!(#name: #closure,)
+        });
+
+        let expr: Expr = parse_quote_spanned! {name.span()=>
+            {
+                let hypertext_component = #name {
+                    #id
+                    #class
+                    #(#attrs,)*
+                    #(#slots,)*
+                    #children
+                };
+
+                {
+                    #[allow(unused_imports)]
+                    use ::hypertext::NotAnElement as _;
+
+                    ::hypertext::ComponentCheck::of(&hypertext_component).check();
+                }
+
+                hypertext_component
+            }
+        };
+
+        gen.push_rendered_expr(&expr);
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ComponentAttribute {
+    name: Ident,
+    eq_token: Token![=],
+    value: ComponentAttributeValue,
+}
+
+impl Parse for ComponentAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: input.call(Ident::parse_any)?,
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for ComponentAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.name.to_tokens(tokens);
+        self.eq_token.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ComponentAttributeValue {
+    Literal(Lit),
+    Splice(Splice),
+}
+
+impl Parse for ComponentAttributeValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(LitStr) || lookahead.peek(LitInt) || lookahead.peek(LitBool) {
+            input.parse().map(Self::Literal)
+        } else if lookahead.peek(Paren) {
+            input.parse().map(Self::Splice)
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for ComponentAttributeValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Literal(lit) => lit.to_tokens(tokens),
+            Self::Splice(splice) => splice.expr.to_tokens(tokens),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ComponentBody {
+    Void(Token![;]),
+    Block {
+        brace_token: Brace,
+        children: Vec<ComponentChild>,
+    },
+}
+
+impl ComponentBody {
+    fn slots(&self) -> impl Iterator<Item = &Slot> {
+        let children = match self {
+            Self::Void(_) => &[][..],
+            Self::Block { children, .. } => children,
+        };
+
+        children.iter().filter_map(|child| match child {
+            ComponentChild::Slot(slot) => Some(slot),
+            ComponentChild::Node(_) => None,
+        })
+    }
+
+    /// The default children, which are all nodes outside of a named slot.
+    fn children(&self) -> Option<Block<ElementNode>> {
+        let Self::Block {
+            brace_token,
+            children,
+        } = self
+        else {
+            return None;
+        };
+
+        let nodes = children
+            .iter()
+            .filter_map(|child| match child {
+                ComponentChild::Node(node) => Some(node.clone()),
+                ComponentChild::Slot(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        (!nodes.is_empty()).then_some(Block {
+            brace_token: *brace_token,
+            nodes,
+        })
+    }
+}
+
+impl Parse for ComponentBody {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+
+        if lookahead.peek(Token![;]) {
+            input.parse().map(Self::Void)
+        } else if lookahead.peek(Brace) {
+            let content;
+            let brace_token = braced!(content in input);
+
+            let mut children = Vec::new();
+            while !content.is_empty() {
+                children.push(content.parse()?);
+            }
+
+            let mut names = Vec::<&Ident>::new();
+            let mut has_children = false;
+
+            for child in &children {
+                match child {
+                    ComponentChild::Slot(slot) => {
+                        if names.iter().any(|name| **name == slot.name) {
+                            return Err(syn::Error::new_spanned(
+                                &slot.name,
+                                format!("slot `{}` is filled more than once", slot.name),
+                            ));
+                        }
+
+                        names.push(&slot.name);
+                    }
+                    ComponentChild::Node(_) => has_children = true,
+                }
+            }
+
+            if has_children {
+                if let Some(name) = names.iter().find(|name| **name == "children") {
+                    return Err(syn::Error::new_spanned(
+                        name,
+                        "slot `children` is already filled by the default children",
+                    ));
+                }
+            }
+
+            Ok(Self::Block {
+                brace_token,
+                children,
+            })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for ComponentBody {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Void(semi) => semi.to_tokens(tokens),
+            Self::Block {
+                brace_token,
+                children,
+            } => brace_token.surround(tokens, |tokens| {
+                for child in children {
+                    child.to_tokens(tokens);
+                }
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ComponentChild {
+    Slot(Slot),
+    Node(ElementNode),
+}
+
+impl Parse for ComponentChild {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![@]) && input.peek2(slot) {
+            input.parse().map(Self::Slot)
+        } else {
+            input.parse().map(Self::Node)
+        }
+    }
+}
+
+impl ToTokens for ComponentChild {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Slot(slot) => slot.to_tokens(tokens),
+            Self::Node(node) => node.to_tokens(tokens),
+        }
+    }
+}
+
+/// A named slot of a component, like `@slot header { ... }`.
+#[derive(Debug, Clone)]
+struct Slot {
+    at_token: At,
+    slot_token: slot,
+    name: Ident,
+    block: Block<ElementNode>,
+}
+
+impl Parse for Slot {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            at_token: input.parse()?,
+            slot_token: input.parse()?,
+            name: input.call(Ident::parse_any)?,
+            block: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Slot {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.slot_token.to_tokens(tokens);
+        self.name.to_tokens(tokens);
+        self.block.to_tokens(tokens);
+    }
+}
+
 #[derive(Debug, Clone)]
 struct IdAttribute {
     pound_token: Token![#],
@@ -435,13 +1357,19 @@ impl Classes {
     }
 }
 
+/// Whether the next token starts a class, rather than being the `..` of a
+/// spread.
+fn peek_class(input: ParseStream) -> bool {
+    input.peek(Token![.]) && !input.peek(Token![..])
+}
+
 impl Parse for Classes {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut classes = Vec::new();
         let mut toggled_classes = Vec::new();
 
         loop {
-            if !input.peek(Token![.]) {
+            if !peek_class(input) {
                 break;
             }
 
@@ -456,7 +1384,7 @@ impl Parse for Classes {
         }
 
         loop {
-            if !input.peek(Token![.]) {
+            if !peek_class(input) {
                 break;
             }
 
@@ -561,51 +1489,101 @@ impl Parse for ToggledClass {
     }
 }
 
-impl ToTokens for ToggledClass {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        self.dot_token.to_tokens(tokens);
-        self.value.to_tokens(tokens);
-        self.toggle.to_tokens(tokens);
+impl ToTokens for ToggledClass {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.dot_token.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+        self.toggle.to_tokens(tokens);
+    }
+}
+
+/// A list of conditional classes, like `class:[("active", is_active),
+/// ("disabled", !enabled)]`, which is sugar for the toggled classes
+/// `."active"[is_active] ."disabled"[!enabled]`.
+struct ClassList {
+    items: Punctuated<ClassListItem, Comma>,
+}
+
+impl ClassList {
+    fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+
+        fork.call(Ident::parse_any)
+            .is_ok_and(|ident| ident == "class")
+            && fork.peek(Token![:])
+            && fork.peek2(Bracket)
+    }
+
+    fn into_toggled_classes(self) -> impl Iterator<Item = ToggledClass> {
+        self.items.into_iter().map(|item| ToggledClass {
+            dot_token: Token![.](item.class.span()),
+            value: IdOrClassNode::Value(AttributeValueNode::Literal(Lit::Str(item.class))),
+            toggle: Toggle {
+                bracket_token: Bracket(item.paren_token.span),
+                cond: item.cond,
+            },
+        })
+    }
+}
+
+impl Parse for ClassList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.call(Ident::parse_any)?;
+        input.parse::<Token![:]>()?;
+
+        let content;
+        bracketed!(content in input);
+
+        Ok(Self {
+            items: content.parse_terminated(ClassListItem::parse, Token![,])?,
+        })
+    }
+}
+
+/// A `("class", condition)` pair in a [`ClassList`].
+struct ClassListItem {
+    paren_token: Paren,
+    class: LitStr,
+    cond: Expr,
+}
+
+impl Parse for ClassListItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let paren_token = parenthesized!(content in input);
+        let class = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let cond = content.parse()?;
+
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        }
+
+        Ok(Self {
+            paren_token,
+            class,
+            cond,
+        })
     }
 }
 
+/// The value of an `#id` or `.class` shorthand.
+///
+/// Apart from bare names like `#main`, this is parsed exactly like the value
+/// of `id=...` or `class=...`, so both accept the same literals, splices,
+/// groups and control flow.
 #[derive(Debug, Clone)]
 enum IdOrClassNode {
-    Block(Block<Self>),
-    Splice(Splice),
-    Literal(LitStr),
-    Keyword(Keyword<Self>),
     Name(Name),
-}
-
-impl Node for IdOrClassNode {
-    fn is_let(&self) -> bool {
-        matches!(
-            self,
-            Self::Keyword(Keyword {
-                kind: KeywordKind::Let(_),
-                ..
-            })
-        )
-    }
+    Value(AttributeValueNode),
 }
 
 impl Parse for IdOrClassNode {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let lookahead = input.lookahead1();
-
-        if lookahead.peek(Brace) {
-            input.parse().map(Self::Block)
-        } else if lookahead.peek(Paren) {
-            input.parse().map(Self::Splice)
-        } else if lookahead.peek(LitStr) {
-            input.parse().map(Self::Literal)
-        } else if lookahead.peek(Token![@]) {
-            input.parse().map(Self::Keyword)
-        } else if lookahead.peek(Ident::peek_any) || lookahead.peek(LitInt) {
+        if input.peek(Ident::peek_any) || input.peek(LitInt) {
             input.parse().map(Self::Name)
         } else {
-            Err(lookahead.error())
+            input.parse().map(Self::Value)
         }
     }
 }
@@ -613,11 +1591,8 @@ impl Parse for IdOrClassNode {
 impl ToTokens for IdOrClassNode {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
-            Self::Block(block) => block.to_tokens(tokens),
-            Self::Splice(splice) => splice.to_tokens(tokens),
-            Self::Literal(lit) => lit.to_tokens(tokens),
-            Self::Keyword(kw) => kw.to_tokens(tokens),
             Self::Name(name) => name.to_tokens(tokens),
+            Self::Value(value) => value.to_tokens(tokens),
         }
     }
 }
@@ -625,11 +1600,8 @@ impl ToTokens for IdOrClassNode {
 impl Generate for IdOrClassNode {
     fn generate(&self, gen: &mut Generator) {
         match self {
-            Self::Block(block) => gen.push(block),
-            Self::Splice(splice) => gen.push(splice),
-            Self::Literal(lit) => gen.push_escaped_lit(lit.clone()),
-            Self::Keyword(kw) => gen.push(kw),
             Self::Name(name) => gen.push_escaped_lit(name.lit()),
+            Self::Value(value) => gen.push(value),
         }
     }
 }
@@ -642,10 +1614,57 @@ struct Attribute {
 
 impl Parse for Attribute {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
-            name: input.parse()?,
-            kind: input.parse()?,
-        })
+        let name = input.parse::<Name>()?;
+
+        #[cfg(feature = "strict-data-attributes")]
+        if name.is_data() {
+            let rest = name
+                .name
+                .pairs()
+                .skip(1)
+                .map(ToTokens::into_token_stream)
+                .collect::<TokenStream>();
+
+            if let Some(message) = crate::validate::data_attribute_error(&name.lit().value()) {
+                return Err(syn::Error::new_spanned(rest, message));
+            }
+        }
+
+        let kind = input.parse()?;
+
+        if !name.is_quoted() {
+            let values = match &kind {
+                AttributeKind::Normal {
+                    value: AttributeValueNode::Literal(Lit::Str(lit)),
+                    ..
+                } => vec![Some(lit)],
+                AttributeKind::Either {
+                    value, else_value, ..
+                } => [value, else_value]
+                    .into_iter()
+                    .filter_map(|value| match value {
+                        AttributeValueNode::Literal(Lit::Str(lit)) => Some(Some(lit)),
+                        _ => None,
+                    })
+                    .collect(),
+                AttributeKind::Empty(..) => vec![None],
+                _ => Vec::new(),
+            };
+
+            for value in values {
+                if let Some(message) = crate::validate::attribute_value_error(
+                    &name.lit().value(),
+                    value.map(LitStr::value).as_deref(),
+                ) {
+                    return Err(value.map_or_else(
+                        || syn::Error::new_spanned(&name, &message),
+                        |lit| syn::Error::new_spanned(lit, &message),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { name, kind })
     }
 }
 
@@ -694,13 +1713,29 @@ impl Generate for Attribute {
                     gen.push_str("\"");
                 },
             ),
-            AttributeKind::Empty(Some(toggle)) => {
+            AttributeKind::Either {
+                value,
+                toggle,
+                else_value,
+                ..
+            } => {
+                gen.push_str(" ");
+                gen.push_escaped_lit(self.name.lit());
+                gen.push_str("=\"");
+                gen.push_conditional_else(
+                    &toggle.parenthesized_cond(),
+                    |gen| gen.push(value),
+                    |gen| gen.push(else_value),
+                );
+                gen.push_str("\"");
+            }
+            AttributeKind::Empty(_, Some(toggle)) => {
                 gen.push_conditional(&toggle.parenthesized_cond(), |gen| {
                     gen.push_str(" ");
                     gen.push_escaped_lit(self.name.lit());
                 });
             }
-            AttributeKind::Empty(None) => {
+            AttributeKind::Empty(_, None) => {
                 gen.push_str(" ");
                 gen.push_escaped_lit(self.name.lit());
             }
@@ -719,7 +1754,16 @@ enum AttributeKind {
         eq_token: Token![=],
         toggle: Toggle,
     },
-    Empty(Option<Toggle>),
+    Either {
+        eq_token: Token![=],
+        value: AttributeValueNode,
+        toggle: Toggle,
+        else_token: Token![else],
+        else_value: AttributeValueNode,
+    },
+    /// An attribute without a value, optionally marked with a `?` like in
+    /// older versions of maud (`checked?`).
+    Empty(Option<Token![?]>, Option<Toggle>),
 }
 
 impl Parse for AttributeKind {
@@ -735,20 +1779,56 @@ impl Parse for AttributeKind {
                     toggle: input.parse()?,
                 })
             } else {
-                Ok(Self::Normal {
-                    eq_token,
-                    value: input.parse()?,
-                    toggle: if input.peek(Bracket) {
-                        Some(input.parse()?)
-                    } else {
-                        None
-                    },
-                })
+                let value = input.parse()?;
+
+                if !input.peek(Bracket) {
+                    return Ok(Self::Normal {
+                        eq_token,
+                        value,
+                        toggle: None,
+                    });
+                }
+
+                let toggle = input.parse()?;
+
+                if input.peek(Token![else]) {
+                    Ok(Self::Either {
+                        eq_token,
+                        value,
+                        toggle,
+                        else_token: input.parse()?,
+                        else_value: input.parse()?,
+                    })
+                } else {
+                    Ok(Self::Normal {
+                        eq_token,
+                        value,
+                        toggle: Some(toggle),
+                    })
+                }
             }
+        } else if lookahead.peek(Token![?]) {
+            let question_token = input.parse::<Token![?]>()?;
+
+            if input.peek(Token![=]) {
+                return Err(syn::Error::new(
+                    question_token.span,
+                    "`?` marks an attribute without a value, remove it to give this attribute a \
+                     value",
+                ));
+            }
+
+            let toggle = if input.peek(Bracket) {
+                Some(input.parse()?)
+            } else {
+                None
+            };
+
+            Ok(Self::Empty(Some(question_token), toggle))
         } else if lookahead.peek(Bracket) {
-            Ok(Self::Empty(Some(input.parse()?)))
+            Ok(Self::Empty(None, Some(input.parse()?)))
         } else {
-            Ok(Self::Empty(None))
+            Ok(Self::Empty(None, None))
         }
     }
 }
@@ -771,7 +1851,21 @@ impl ToTokens for AttributeKind {
                 eq_token.to_tokens(tokens);
                 toggle.to_tokens(tokens);
             }
-            Self::Empty(toggle) => {
+            Self::Either {
+                eq_token,
+                value,
+                toggle,
+                else_token,
+                else_value,
+            } => {
+                eq_token.to_tokens(tokens);
+                value.to_tokens(tokens);
+                toggle.to_tokens(tokens);
+                else_token.to_tokens(tokens);
+                else_value.to_tokens(tokens);
+            }
+            Self::Empty(question_token, toggle) => {
+                question_token.to_tokens(tokens);
                 if let Some(toggle) = toggle {
                     toggle.to_tokens(tokens);
                 }
@@ -846,42 +1940,32 @@ struct Name {
 }
 
 impl Name {
-    /// If the ident should be a namespace, the boolean is true.
-    fn ident_or_namespace(&self) -> (Ident, bool) {
-        let string = self.name.pairs().map(Pair::into_tuple).try_fold(
-            String::new(),
-            |mut acc, (fragment, punct)| {
-                acc.push_str(&fragment.to_fragment_string());
+    /// Whether this is a `data-*` attribute name, which skips validation.
+    fn is_data(&self) -> bool {
+        let mut name_pairs = self.name.pairs();
 
-                match punct {
-                    Some(NamePunct::Colon(_)) => {
-                        return ControlFlow::Break(acc);
-                    }
-                    Some(NamePunct::Hyphen(_)) => {
-                        acc.push('_');
-                    }
-                    None => {}
-                }
+        name_pairs.next().is_some_and(|pair| {
+            if let Pair::Punctuated(NameFragment::Ident(ident), NamePunct::Hyphen(_)) = pair {
+                ident == "data"
+            } else {
+                false
+            }
+        }) && name_pairs.next().is_some()
+    }
 
-                ControlFlow::Continue(acc)
-            },
-        );
+    /// Whether this is a quoted name, like `"my-élément"`, which skips
+    /// validation.
+    fn is_quoted(&self) -> bool {
+        matches!(self.name.first(), Some(NameFragment::Quoted(_)))
+    }
 
-        let (string, is_namespace) = match string {
-            ControlFlow::Break(string) => (string, true),
-            ControlFlow::Continue(string) => (string, false),
-        };
+    /// If the ident should be a namespace, the boolean is true.
+    fn ident_or_namespace(&self) -> (Ident, bool) {
+        let name = self.lit().value();
 
         (
-            // results in better editor hover-doc support than unconditional `new_raw` usage
-            syn::parse_str::<Ident>(&string).map_or_else(
-                |_| Ident::new_raw(&string, self.span()),
-                |mut ident| {
-                    ident.set_span(self.span());
-                    ident
-                },
-            ),
-            is_namespace,
+            validate::ident(&validate::normalize_attribute_ident(&name), self.span()),
+            name.contains(':'),
         )
     }
 
@@ -899,14 +1983,7 @@ impl Name {
             },
         );
 
-        // results in better editor hover-doc support than unconditional `new_raw` usage
-        syn::parse_str::<Ident>(&string).map_or_else(
-            |_| Ident::new_raw(&string, self.span()),
-            |mut ident| {
-                ident.set_span(self.span());
-                ident
-            },
-        )
+        validate::ident(&validate::normalize_ident(&string), self.span())
     }
 
     fn lit(&self) -> LitStr {
@@ -929,6 +2006,28 @@ impl Name {
 
 impl Parse for Name {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let lit = input.parse::<LitStr>()?;
+            let value = lit.value();
+
+            if value.is_empty()
+                || value.contains(|c: char| {
+                    c.is_ascii_whitespace() || matches!(c, '<' | '>' | '/' | '=' | '"' | '\'')
+                })
+            {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "quoted names cannot be empty, or contain whitespace, quotes, `<`, `>`, `/` \
+                     or `=`",
+                ));
+            }
+
+            let mut punctuated = Punctuated::new();
+            punctuated.push_value(NameFragment::Quoted(lit));
+
+            return Ok(Self { name: punctuated });
+        }
+
         Ok(Self {
             name: {
                 let mut punctuated = Punctuated::new();
@@ -960,6 +2059,7 @@ impl ToTokens for Name {
 enum NameFragment {
     Ident(Ident),
     Number(LitInt),
+    Quoted(LitStr),
     Empty,
 }
 
@@ -968,6 +2068,7 @@ impl NameFragment {
         match self {
             Self::Ident(ident) => ident.to_string(),
             Self::Number(num) => num.to_string(),
+            Self::Quoted(lit) => lit.value(),
             Self::Empty => String::new(),
         }
     }
@@ -994,6 +2095,7 @@ impl ToTokens for NameFragment {
         match self {
             Self::Ident(ident) => ident.to_tokens(tokens),
             Self::Number(lit) => lit.to_tokens(tokens),
+            Self::Quoted(lit) => lit.to_tokens(tokens),
             Self::Empty => {}
         }
     }
@@ -1048,7 +2150,7 @@ impl Lit {
     fn lit_str(&self) -> LitStr {
         match self {
             Self::Str(lit) => lit.clone(),
-            Self::Int(lit) => LitStr::new(&lit.to_string(), lit.span()),
+            Self::Int(lit) => LitStr::new(lit.base10_digits(), lit.span()),
             Self::Bool(lit) => LitStr::new(&lit.value.to_string(), lit.span()),
         }
     }
@@ -1133,7 +2235,26 @@ impl<N: Node> Parse for Keyword<N> {
             kind: {
                 let lookahead = input.lookahead1();
 
-                if lookahead.peek(Token![if]) {
+                if lookahead.peek(Lifetime) {
+                    let label = input.parse::<Label>()?;
+                    let lookahead = input.lookahead1();
+
+                    if lookahead.peek(Token![for]) {
+                        KeywordKind::For(ForNode {
+                            label: Some(label),
+                            ..input.parse()?
+                        })
+                    } else if lookahead.peek(Token![while]) {
+                        KeywordKind::While(WhileNode {
+                            label: Some(label),
+                            ..input.parse()?
+                        })
+                    } else {
+                        return Err(lookahead.error());
+                    }
+                } else if lookahead.peek(Token![break]) || lookahead.peek(Token![continue]) {
+                    KeywordKind::LoopControl(input.parse()?)
+                } else if lookahead.peek(Token![if]) {
                     KeywordKind::If(input.parse()?)
                 } else if lookahead.peek(Token![for]) {
                     KeywordKind::For(input.parse()?)
@@ -1141,12 +2262,14 @@ impl<N: Node> Parse for Keyword<N> {
                     KeywordKind::While(input.parse()?)
                 } else if lookahead.peek(Token![match]) {
                     KeywordKind::Match(input.parse()?)
+                } else if lookahead.peek(cfg) {
+                    KeywordKind::Cfg(input.parse()?)
                 } else if lookahead.peek(Token![let]) {
                     let Stmt::Local(local) = input.parse()? else {
                         unreachable!()
                     };
 
-                    KeywordKind::Let(local)
+                    KeywordKind::Let(reject_await(local)?)
                 } else {
                     return Err(lookahead.error());
                 }
@@ -1164,6 +2287,8 @@ impl<N: Node> ToTokens for Keyword<N> {
             KeywordKind::For(for_) => for_.to_tokens(tokens),
             KeywordKind::While(while_) => while_.to_tokens(tokens),
             KeywordKind::Match(match_) => match_.to_tokens(tokens),
+            KeywordKind::Cfg(cfg) => cfg.to_tokens(tokens),
+            KeywordKind::LoopControl(control) => control.to_tokens(tokens),
         }
     }
 }
@@ -1178,6 +2303,8 @@ impl<N: Node> Generate for Keyword<N> {
             KeywordKind::For(for_) => gen.push(for_),
             KeywordKind::While(while_) => gen.push(while_),
             KeywordKind::Match(match_) => gen.push(match_),
+            KeywordKind::Cfg(cfg) => gen.push(cfg),
+            KeywordKind::LoopControl(control) => gen.push(control),
         }
     }
 }
@@ -1189,6 +2316,126 @@ enum KeywordKind<N> {
     For(ForNode<N>),
     While(WhileNode<N>),
     Match(MatchNode<N>),
+    Cfg(CfgNode<N>),
+    LoopControl(LoopControl),
+}
+
+/// A `@break` or `@continue` in the body of a `@for` or `@while`, optionally
+/// with the label of the loop, like `@break 'rows;`.
+#[derive(Debug, Clone)]
+struct LoopControl {
+    kind: LoopControlKind,
+    label: Option<Lifetime>,
+    semi_token: Token![;],
+}
+
+#[derive(Debug, Clone)]
+enum LoopControlKind {
+    Break(Token![break]),
+    Continue(Token![continue]),
+}
+
+impl Parse for LoopControl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            kind: if input.peek(Token![break]) {
+                LoopControlKind::Break(input.parse()?)
+            } else {
+                LoopControlKind::Continue(input.parse()?)
+            },
+            label: input.parse()?,
+            semi_token: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for LoopControl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.kind {
+            LoopControlKind::Break(break_token) => break_token.to_tokens(tokens),
+            LoopControlKind::Continue(continue_token) => continue_token.to_tokens(tokens),
+        }
+        self.label.to_tokens(tokens);
+        self.semi_token.to_tokens(tokens);
+    }
+}
+
+impl Generate for LoopControl {
+    fn generate(&self, gen: &mut Generator) {
+        let label = &self.label;
+        let (keyword, stmt): (_, Stmt) = match &self.kind {
+            LoopControlKind::Break(break_token) => ("break", parse_quote!(#break_token #label;)),
+            LoopControlKind::Continue(continue_token) => {
+                ("continue", parse_quote!(#continue_token #label;))
+            }
+        };
+
+        if let Some(separators) = gen.cut_separators(self.label.as_ref()) {
+            let output_ident = gen.output_ident();
+
+            gen.push_dynamic(
+                parse_quote! {
+                    {
+                        #(#separators.cut_iteration(#output_ident);)*
+                        #stmt
+                    }
+                },
+                Some(self.span()),
+            );
+        } else {
+            let message = self.label.as_ref().map_or_else(
+                || format!("`@{keyword}` can only be used in the body of a `@for` or `@while`"),
+                |label| {
+                    format!("`@{keyword} {label}` is not in the body of a loop labeled `{label}`")
+                },
+            );
+
+            gen.push_dynamic(
+                syn::parse2(syn::Error::new_spanned(self, message).into_compile_error()).unwrap(),
+                Some(self.span()),
+            );
+        }
+    }
+}
+
+syn::custom_keyword!(cfg);
+
+/// A block which is only compiled if its predicate holds, like
+/// `@cfg(debug_assertions) { ... }`.
+#[derive(Debug, Clone)]
+struct CfgNode<N> {
+    cfg_token: cfg,
+    paren_token: Paren,
+    predicate: TokenStream,
+    body: Block<N>,
+}
+
+impl<N: Node> Parse for CfgNode<N> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            cfg_token: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            predicate: content.parse()?,
+            body: input.parse()?,
+        })
+    }
+}
+
+impl<N: Node> ToTokens for CfgNode<N> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.cfg_token.to_tokens(tokens);
+        self.paren_token.surround(tokens, |tokens| {
+            self.predicate.to_tokens(tokens);
+        });
+        self.body.to_tokens(tokens);
+    }
+}
+
+impl<N: Node> Generate for CfgNode<N> {
+    fn generate(&self, gen: &mut Generator) {
+        gen.push_cfg(&self.predicate, |gen| gen.push(&self.body));
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1203,7 +2450,9 @@ impl<N: Node> Parse for IfNode<N> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
             if_token: input.parse()?,
-            cond: input.call(Expr::parse_without_eager_brace)?,
+            cond: input
+                .call(Expr::parse_without_eager_brace)
+                .and_then(reject_await)?,
             then_branch: input.parse()?,
             else_branch: {
                 if input.peek(Token![@]) && input.peek2(Token![else]) {
@@ -1302,85 +2551,270 @@ impl<N: Node> Generate for IfOrBlock<N> {
 
 #[derive(Debug, Clone)]
 struct ForNode<N> {
+    label: Option<Label>,
     for_token: For,
     pat: Pat,
     in_token: In,
     expr: Expr,
+    key: Option<Key>,
     body: Block<N>,
+    separator: Option<Separator<N>>,
 }
 
 impl<N: Node> Parse for ForNode<N> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let for_token = input.parse()?;
+        let pat = input.call(Pat::parse_multi_with_leading_vert)?;
+        let in_token = input.parse()?;
+        let expr = input
+            .call(Expr::parse_without_eager_brace)
+            .and_then(reject_await)?;
+        let key = if input.peek(keyed) {
+            Some(input.parse::<Key>()?)
+        } else {
+            None
+        };
+        let mut body = input.parse::<Block<N>>()?;
+
+        if let Some(key) = &key {
+            match body.nodes.as_mut_slice() {
+                [node] => node.set_key(key)?,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &body,
+                        "the body of a keyed `@for` must be a single element",
+                    ))
+                }
+            }
+        }
+
         Ok(Self {
-            for_token: input.parse()?,
-            pat: input.call(Pat::parse_multi_with_leading_vert)?,
-            in_token: input.parse()?,
-            expr: input.call(Expr::parse_without_eager_brace)?,
-            body: input.parse()?,
+            label: None,
+            for_token,
+            pat,
+            in_token,
+            expr,
+            key,
+            body,
+            separator: Separator::parse_optional(input)?,
         })
     }
 }
 
 impl<N: Node> ToTokens for ForNode<N> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.label.to_tokens(tokens);
         self.for_token.to_tokens(tokens);
         self.pat.to_tokens(tokens);
         self.in_token.to_tokens(tokens);
         self.expr.to_tokens(tokens);
+        self.key.to_tokens(tokens);
         self.body.to_tokens(tokens);
+        self.separator.to_tokens(tokens);
     }
 }
 
 impl<N: Node> Generate for ForNode<N> {
     fn generate(&self, gen: &mut Generator) {
-        let body = gen.block(&self.body);
-        gen.push_expr(ExprForLoop {
-            attrs: Vec::new(),
-            label: None,
-            for_token: self.for_token,
-            pat: Box::new(self.pat.clone()),
-            in_token: self.in_token,
-            expr: Box::new(self.expr.clone()),
-            body,
-        });
+        let mut body = gen.loop_block(self.label.as_ref(), self.separator.is_some(), &self.body);
+        let separator = self
+            .separator
+            .as_ref()
+            .map(|separator| separator.generate(gen, &mut body));
+
+        push_loop(
+            gen,
+            separator,
+            ExprForLoop {
+                attrs: Vec::new(),
+                label: self.label.clone(),
+                for_token: self.for_token,
+                pat: Box::new(self.pat.clone()),
+                in_token: self.in_token,
+                expr: Box::new(self.expr.clone()),
+                body,
+            },
+        );
+    }
+}
+
+syn::custom_keyword!(keyed);
+syn::custom_keyword!(by);
+
+/// The key of a keyed `@for`, like `keyed by (item.id)`, which is rendered as
+/// a `data-key` attribute on the root element of each iteration.
+#[derive(Debug, Clone)]
+struct Key {
+    keyed_token: keyed,
+    by_token: by,
+    splice: Splice,
+}
+
+impl Parse for Key {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            keyed_token: input.parse()?,
+            by_token: input.parse()?,
+            splice: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Key {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.keyed_token.to_tokens(tokens);
+        self.by_token.to_tokens(tokens);
+        self.splice.to_tokens(tokens);
     }
 }
 
 #[derive(Debug, Clone)]
 struct WhileNode<N> {
+    label: Option<Label>,
     while_token: While,
     cond: Expr,
     body: Block<N>,
+    separator: Option<Separator<N>>,
 }
 
 impl<N: Node> Parse for WhileNode<N> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Ok(Self {
+            label: None,
             while_token: input.parse()?,
-            cond: input.call(Expr::parse_without_eager_brace)?,
+            cond: input
+                .call(Expr::parse_without_eager_brace)
+                .and_then(reject_await)?,
             body: input.parse()?,
+            separator: Separator::parse_optional(input)?,
         })
     }
 }
 
 impl<N: Node> ToTokens for WhileNode<N> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.label.to_tokens(tokens);
         self.while_token.to_tokens(tokens);
         self.cond.to_tokens(tokens);
         self.body.to_tokens(tokens);
+        self.separator.to_tokens(tokens);
     }
 }
 
 impl<N: Node> Generate for WhileNode<N> {
     fn generate(&self, gen: &mut Generator) {
-        let body = gen.block(&self.body);
-        gen.push_expr(ExprWhile {
-            attrs: Vec::new(),
-            label: None,
-            while_token: self.while_token,
-            cond: Box::new(self.cond.clone()),
-            body,
-        });
+        let mut body = gen.loop_block(self.label.as_ref(), self.separator.is_some(), &self.body);
+        let separator = self
+            .separator
+            .as_ref()
+            .map(|separator| separator.generate(gen, &mut body));
+
+        push_loop(
+            gen,
+            separator,
+            ExprWhile {
+                attrs: Vec::new(),
+                label: self.label.clone(),
+                while_token: self.while_token,
+                cond: Box::new(self.cond.clone()),
+                body,
+            },
+        );
+    }
+}
+
+/// Pushes a loop, surrounded by the statements tracking its separator if it
+/// has one.
+fn push_loop(
+    gen: &mut Generator,
+    separator: Option<(Stmt, Stmt)>,
+    loop_: impl Into<Expr> + Spanned,
+) {
+    if let Some((init, finish)) = separator {
+        let span = loop_.span();
+        let loop_ = loop_.into();
+
+        gen.push_dynamic(
+            Stmt::Expr(
+                Expr::Block(parse_quote! {
+                    {
+                        #init
+                        #loop_
+                        #finish
+                    }
+                }),
+                None,
+            ),
+            Some(span),
+        );
+    } else {
+        gen.push_expr(loop_);
+    }
+}
+
+syn::custom_keyword!(separated_by);
+
+/// A block rendered between the iterations of a loop.
+#[derive(Debug, Clone)]
+struct Separator<N> {
+    at_token: At,
+    separated_by_token: separated_by,
+    block: Block<N>,
+}
+
+impl<N: Node> Separator<N> {
+    fn parse_optional(input: ParseStream) -> syn::Result<Option<Self>> {
+        if input.peek(Token![@]) && input.peek2(separated_by) {
+            input.parse().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Makes `body` render the separator before every iteration but the
+    /// first, returning the statements to put before and after the loop.
+    ///
+    /// The separator is rendered at the start of every iteration (after the
+    /// first which wasn't skipped), and removed again if `@continue` or
+    /// `@break` cuts the iteration short before it renders anything, so
+    /// skipped iterations don't leave stray separators.
+    fn generate(&self, gen: &Generator, body: &mut syn::Block) -> (Stmt, Stmt) {
+        let separator = gen.separator_ident();
+        let output_ident = gen.output_ident();
+        let block = gen.block(&self.block);
+
+        body.stmts.splice(
+            0..0,
+            [
+                parse_quote! {
+                    if #separator.start_iteration(#output_ident) #block
+                },
+                parse_quote!(#separator.start_body(#output_ident);),
+            ],
+        );
+
+        (
+            parse_quote!(let mut #separator = ::hypertext::Separated::new();),
+            parse_quote!(#separator.finish_iteration();),
+        )
+    }
+}
+
+impl<N: Node> Parse for Separator<N> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            at_token: input.parse()?,
+            separated_by_token: input.parse()?,
+            block: input.parse()?,
+        })
+    }
+}
+
+impl<N: Node> ToTokens for Separator<N> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.separated_by_token.to_tokens(tokens);
+        self.block.to_tokens(tokens);
     }
 }
 
@@ -1395,7 +2829,9 @@ struct MatchNode<N> {
 impl<N: Node> Parse for MatchNode<N> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let match_token = input.parse()?;
-        let expr = input.call(Expr::parse_without_eager_brace)?;
+        let expr = input
+            .call(Expr::parse_without_eager_brace)
+            .and_then(reject_await)?;
 
         let content;
         let brace_token = braced!(content in input);
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Ident, Token,
+};
+
+pub fn parse(tokens: TokenStream) -> syn::Result<(Options, TokenStream)> {
+    syn::parse2::<WithOptions>(tokens).map(|with_options| (with_options.options, with_options.rest))
+}
+
+/// Options for a macro invocation, given as a leading inner attribute such as
+/// `#![newlines]`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Options {
+    /// Insert a newline between each top-level node.
+    pub newlines: bool,
+    /// Close void elements XML-style, with ` />`.
+    pub xhtml: bool,
+}
+
+impl Parse for Options {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Self::default();
+
+        while input.peek(Token![#]) && input.peek2(Token![!]) {
+            input.parse::<Token![#]>()?;
+            input.parse::<Token![!]>()?;
+
+            let content;
+            bracketed!(content in input);
+
+            for option in Punctuated::<Ident, Token![,]>::parse_terminated(&content)? {
+                if option == "newlines" {
+                    options.newlines = true;
+                } else if option == "xhtml" {
+                    options.xhtml = true;
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        option,
+                        "unknown option, expected `newlines` or `xhtml`",
+                    ));
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+struct WithOptions {
+    options: Options,
+    rest: TokenStream,
+}
+
+impl Parse for WithOptions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            options: input.parse()?,
+            rest: input.parse()?,
+        })
+    }
+}
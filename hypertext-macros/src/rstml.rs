@@ -1,24 +1,37 @@
 use std::collections::HashSet;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{Punct, TokenStream};
 use proc_macro2_diagnostics::{Diagnostic, SpanDiagnosticExt};
 use quote::ToTokens;
 use rstml::{
     node::{
-        AttributeValueExpr, KVAttributeValue, KeyedAttribute, KeyedAttributeValue, Node,
-        NodeAttribute, NodeBlock, NodeComment, NodeDoctype, NodeElement, NodeFragment, NodeName,
-        NodeNameFragment, NodeText, RawText,
+        AttributeValueExpr, CustomNode, KVAttributeValue, KeyedAttribute, KeyedAttributeValue,
+        Node, NodeAttribute, NodeBlock, NodeComment, NodeDoctype, NodeElement, NodeFragment,
+        NodeName, NodeNameFragment, NodeText, RawText,
     },
-    Infallible, Parser, ParserConfig,
+    recoverable::{ParseRecoverable, RecoverableContext},
+    Parser, ParserConfig,
 };
 use syn::{
-    parse_quote, punctuated::Pair, spanned::Spanned, Expr, ExprBlock, ExprLit, ExprPath, Ident,
-    Lit, LitStr,
+    braced,
+    ext::IdentExt,
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::{Pair, Punctuated},
+    spanned::Spanned,
+    token::{At, Brace, Paren},
+    Expr, ExprBlock, ExprLit, ExprPath, Ident, Lit, LitStr, Token,
 };
 
-use crate::generate::{Generate, Generator};
+use crate::{
+    generate::{Generate, Generator},
+    include,
+    maud::{cfg, MaudBlock},
+    validate,
+};
 
-pub fn parse(tokens: TokenStream) -> (Vec<Node>, Vec<Diagnostic>) {
+pub fn parse(tokens: TokenStream) -> (Vec<Node<Keyword>>, Vec<Diagnostic>) {
     let void_elements = [
         "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source",
         "track", "wbr",
@@ -28,10 +41,12 @@ pub fn parse(tokens: TokenStream) -> (Vec<Node>, Vec<Diagnostic>) {
 
     let config = ParserConfig::new()
         .recover_block(true)
-        .always_self_closed_elements(void_elements);
+        .always_self_closed_elements(void_elements)
+        .custom_node::<Keyword>();
 
     let parser = Parser::new(config);
     let (parsed_nodes, mut diagnostics) = parser.parse_recoverable(tokens).split_vec();
+    diagnostics.extend(await_diagnostics(&parsed_nodes));
     for el in parsed_nodes
         .clone()
         .into_iter()
@@ -44,16 +59,20 @@ pub fn parse(tokens: TokenStream) -> (Vec<Node>, Vec<Diagnostic>) {
             }
         })
     {
-        if let NodeName::Block(block) = el.open_tag.name {
+        if let NodeName::Block(block) = &el.open_tag.name {
             diagnostics.push(block.span().error("block names are unsupported"));
         }
 
+        diagnostics.extend(attribute_collision_diagnostics(&el));
+
         for attr in el.open_tag.attributes {
             match attr {
                 NodeAttribute::Block(block) => {
                     diagnostics.push(block.span().error("block attributes are unsupported"));
                 }
                 NodeAttribute::Attribute(keyed) => {
+                    diagnostics.extend(attribute_value_diagnostic(&keyed));
+
                     match keyed.key {
                         NodeName::Block(block) => {
                             diagnostics
@@ -88,6 +107,9 @@ pub fn parse(tokens: TokenStream) -> (Vec<Node>, Vec<Diagnostic>) {
                             }
                         }
                         NodeName::Punctuated(punctuated) => {
+                            #[cfg(feature = "strict-data-attributes")]
+                            diagnostics.extend(data_attribute_diagnostic(&punctuated));
+
                             if !punctuated.pairs().all(|pair| {
                                 pair.punct().is_none_or(|punct| {
                                     punct.as_char() == '-' || punct.as_char() == ':'
@@ -113,13 +135,253 @@ pub fn parse(tokens: TokenStream) -> (Vec<Node>, Vec<Diagnostic>) {
     (parsed_nodes, diagnostics)
 }
 
-impl Generate for Vec<Node> {
+syn::custom_keyword!(rsx);
+
+/// An `@rsx { ... }` block nested in `maud!`, whose contents are parsed as rsx
+/// and rendered inline.
+#[derive(Debug, Clone)]
+pub struct RsxBlock {
+    at_token: At,
+    rsx_token: rsx,
+    brace_token: Brace,
+    nodes: Vec<Node<Keyword>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl RsxBlock {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![@]) && input.peek2(rsx)
+    }
+}
+
+impl Parse for RsxBlock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let at_token = input.parse()?;
+        let rsx_token = input.parse()?;
+        let brace_token = braced!(content in input);
+        let (nodes, diagnostics) = parse(content.parse()?);
+
+        Ok(Self {
+            at_token,
+            rsx_token,
+            brace_token,
+            nodes,
+            diagnostics,
+        })
+    }
+}
+
+impl ToTokens for RsxBlock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.rsx_token.to_tokens(tokens);
+        self.brace_token.surround(tokens, |tokens| {
+            for node in &self.nodes {
+                node.to_tokens(tokens);
+            }
+        });
+    }
+}
+
+impl Generate for RsxBlock {
     fn generate(&self, gen: &mut Generator) {
-        gen.push_all(self);
+        push_diagnostics(gen, &self.diagnostics);
+        gen.push_all(&self.nodes);
     }
 }
 
-impl Generate for Node {
+/// Pushes diagnostics from parsing nested rsx, which can't be returned from
+/// [`Parse`] as they may only be warnings.
+fn push_diagnostics(gen: &mut Generator, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        let diagnostic = diagnostic.clone().emit_as_expr_tokens();
+
+        gen.push_dynamic(parse_quote!(#diagnostic;), None);
+    }
+}
+
+/// A node in `rsx!` starting with `@`.
+#[derive(Debug, Clone)]
+pub enum Keyword {
+    Maud(MaudBlock),
+    Cfg(CfgBlock),
+    Include(IncludeBlock),
+}
+
+impl CustomNode for Keyword {
+    fn peek_element(input: ParseStream) -> bool {
+        MaudBlock::peek(input) || CfgBlock::peek(input) || IncludeBlock::peek(input)
+    }
+}
+
+impl ParseRecoverable for Keyword {
+    fn parse_recoverable(parser: &mut RecoverableContext, input: ParseStream) -> Option<Self> {
+        if MaudBlock::peek(input) {
+            parser.parse_simple(input).map(Self::Maud)
+        } else if IncludeBlock::peek(input) {
+            parser.parse_simple(input).map(Self::Include)
+        } else {
+            parser.parse_simple(input).map(Self::Cfg)
+        }
+    }
+}
+
+impl ToTokens for Keyword {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Maud(maud) => maud.to_tokens(tokens),
+            Self::Cfg(cfg) => cfg.to_tokens(tokens),
+            Self::Include(include) => include.to_tokens(tokens),
+        }
+    }
+}
+
+impl Generate for Keyword {
+    fn generate(&self, gen: &mut Generator) {
+        match self {
+            Self::Maud(maud) => gen.push(maud),
+            Self::Cfg(cfg) => gen.push(cfg),
+            Self::Include(include) => gen.push(include),
+        }
+    }
+}
+
+/// A block of rsx which is only compiled if its predicate holds, like
+/// `@cfg(debug_assertions) { ... }`.
+#[derive(Debug, Clone)]
+pub struct CfgBlock {
+    at_token: At,
+    cfg_token: cfg,
+    paren_token: Paren,
+    predicate: TokenStream,
+    brace_token: Brace,
+    nodes: Vec<Node<Keyword>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl CfgBlock {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(Token![@]) && input.peek2(cfg)
+    }
+}
+
+impl Parse for CfgBlock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let predicate;
+        let content;
+        let at_token = input.parse()?;
+        let cfg_token = input.parse()?;
+        let paren_token = parenthesized!(predicate in input);
+        let predicate = predicate.parse()?;
+        let brace_token = braced!(content in input);
+        let (nodes, diagnostics) = parse(content.parse()?);
+
+        Ok(Self {
+            at_token,
+            cfg_token,
+            paren_token,
+            predicate,
+            brace_token,
+            nodes,
+            diagnostics,
+        })
+    }
+}
+
+impl ToTokens for CfgBlock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.cfg_token.to_tokens(tokens);
+        self.paren_token.surround(tokens, |tokens| {
+            self.predicate.to_tokens(tokens);
+        });
+        self.brace_token.surround(tokens, |tokens| {
+            for node in &self.nodes {
+                node.to_tokens(tokens);
+            }
+        });
+    }
+}
+
+impl Generate for CfgBlock {
+    fn generate(&self, gen: &mut Generator) {
+        push_diagnostics(gen, &self.diagnostics);
+        gen.push_cfg(&self.predicate, |gen| gen.push_all(&self.nodes));
+    }
+}
+
+/// An `@include("...")` of a file of rsx, which is read at compile time and
+/// rendered inline.
+#[derive(Debug, Clone)]
+pub struct IncludeBlock {
+    at_token: At,
+    include_token: include::include,
+    paren_token: Paren,
+    path: LitStr,
+    full_path: String,
+    nodes: Vec<Node<Keyword>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl IncludeBlock {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(Token![@]) && input.peek2(include::include)
+    }
+}
+
+impl Parse for IncludeBlock {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let at_token = input.parse()?;
+        let include_token = input.parse()?;
+        let paren_token = parenthesized!(content in input);
+        let path = content.parse()?;
+        let (full_path, (nodes, diagnostics)) = include::read_rsx(&path, parse)?;
+
+        // the included tokens have no useful spans, so point at the path too
+        let diagnostics = diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.span_note(path.span(), format!("in `{}`", path.value())))
+            .collect();
+
+        Ok(Self {
+            at_token,
+            include_token,
+            paren_token,
+            path,
+            full_path,
+            nodes,
+            diagnostics,
+        })
+    }
+}
+
+impl ToTokens for IncludeBlock {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.at_token.to_tokens(tokens);
+        self.include_token.to_tokens(tokens);
+        self.paren_token.surround(tokens, |tokens| {
+            self.path.to_tokens(tokens);
+        });
+    }
+}
+
+impl Generate for IncludeBlock {
+    fn generate(&self, gen: &mut Generator) {
+        gen.record_include(self.full_path.clone());
+        push_diagnostics(gen, &self.diagnostics);
+        gen.push_all(&self.nodes);
+    }
+}
+
+impl Generate for Vec<Node<Keyword>> {
+    fn generate(&self, gen: &mut Generator) {
+        gen.push_top_level(self);
+    }
+}
+
+impl Generate for Node<Keyword> {
     fn generate(&self, gen: &mut Generator) {
         match self {
             Self::Comment(comment) => gen.push(comment),
@@ -129,7 +391,7 @@ impl Generate for Node {
             Self::Block(block) => gen.push(block),
             Self::Text(text) => gen.push(text),
             Self::RawText(raw_text) => gen.push(raw_text),
-            Self::Custom(_) => {}
+            Self::Custom(keyword) => gen.push(keyword),
         }
     }
 }
@@ -137,28 +399,59 @@ impl Generate for Node {
 impl Generate for NodeComment {
     fn generate(&self, gen: &mut Generator) {
         gen.push_str("<!--");
-        gen.push_escaped_lit(self.value.clone());
+        gen.push_lit(LitStr::new(
+            &comment_text(&self.value.value()),
+            self.value.span(),
+        ));
         gen.push_str("-->");
     }
 }
 
+/// Makes `text` safe to use verbatim as the text of an HTML comment, by
+/// escaping any `>` which would end the comment early.
+fn comment_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for (i, c) in text.char_indices() {
+        let before = &text[..i];
+        let ends_comment =
+            before.is_empty() || before == "-" || before.ends_with("--") || before.ends_with("--!");
+
+        if c == '>' && ends_comment {
+            escaped.push_str("&gt;");
+        } else {
+            escaped.push(c);
+        }
+    }
+
+    escaped
+}
+
 impl Generate for NodeDoctype {
     fn generate(&self, gen: &mut Generator) {
         gen.push_str("<!");
         gen.push_spanned_str("DOCTYPE", self.token_doctype.span());
         gen.push_str(" ");
-        gen.push(&self.value);
+        gen.push_lit(LitStr::new(
+            &self
+                .value
+                .to_string_best()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            self.value.span(),
+        ));
         gen.push_str(">");
     }
 }
 
-impl Generate for NodeFragment<Infallible> {
+impl Generate for NodeFragment<Keyword> {
     fn generate(&self, gen: &mut Generator) {
         gen.push_all(&self.children);
     }
 }
 
-impl Generate for NodeElement<Infallible> {
+impl Generate for NodeElement<Keyword> {
     fn generate(&self, gen: &mut Generator) {
         gen.record_element(&node_name_ident(&self.open_tag.name));
 
@@ -171,30 +464,45 @@ impl Generate for NodeElement<Infallible> {
 
             gen.push(attr);
 
+            if let KeyedAttributeValue::Value(AttributeValueExpr {
+                value:
+                    KVAttributeValue::Expr(Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    })),
+                ..
+            }) = &attr.possible_value
+            {
+                gen.record_literal_attribute(&node_name_lit(&attr.key).value(), lit);
+            }
+
             if let KeyedAttribute {
                 key: NodeName::Punctuated(punct),
                 ..
             } = attr
             {
-                let mut name_pairs = punct.pairs();
-                if name_pairs.next().is_some_and(|pair| {
-                    if let Pair::Punctuated(NodeNameFragment::Ident(ident), punct) = pair {
-                        ident == "data" && punct.as_char() == '-'
-                    } else {
-                        false
-                    }
-                }) && name_pairs.next().is_some()
-                {
+                if is_data(punct) {
                     continue;
                 }
             }
 
-            gen.record_attribute(
-                &node_name_ident(&self.open_tag.name),
-                &node_name_ident(&attr.key),
+            let (attr_ident, is_namespace) = attribute_ident_or_namespace(&attr.key);
+
+            if is_namespace {
+                gen.record_namespace(&node_name_ident(&self.open_tag.name), &attr_ident);
+            } else {
+                gen.record_attribute(&node_name_ident(&self.open_tag.name), &attr_ident);
+            }
+        }
+
+        if let Some(note) = blank_target_warning(self) {
+            gen.record_warning(
+                "UnsafeBlankTarget",
+                note.to_owned(),
+                self.open_tag.name.span(),
             );
         }
-        gen.push_str(">");
+
+        gen.push_start_tag_end(self.close_tag.is_none());
 
         if let Some(tag) = &self.close_tag {
             gen.record_element(&node_name_ident(&tag.name));
@@ -211,6 +519,12 @@ impl Generate for NodeElement<Infallible> {
 
 impl Generate for KeyedAttribute {
     fn generate(&self, gen: &mut Generator) {
+        // reported by `await_diagnostics`, and generating it would only add
+        // rustc's error about the closure
+        if validate::await_span(self.possible_value.to_token_stream()).is_some() {
+            return;
+        }
+
         gen.push_str(" ");
 
         gen.push_escaped_lit(node_name_lit(&self.key));
@@ -227,7 +541,7 @@ impl Generate for KeyedAttribute {
                         gen.push_escaped_lit(lit_str.clone());
                     }
                     Lit::Int(lit_int) => {
-                        gen.push_escaped_lit(LitStr::new(&lit_int.to_string(), lit_int.span()));
+                        gen.push_escaped_lit(LitStr::new(lit_int.base10_digits(), lit_int.span()));
                     }
                     Lit::Bool(lit_bool) => {
                         gen.push_escaped_lit(LitStr::new(
@@ -239,6 +553,17 @@ impl Generate for KeyedAttribute {
                         gen.push_rendered_expr(value);
                     }
                 },
+                // the parentheses or braces only delimit the value, so they
+                // mustn't trigger `unused_parens` or `unused_braces`
+                Expr::Paren(paren) => {
+                    gen.push_rendered_expr(&paren.expr);
+                }
+                Expr::Block(block) => {
+                    gen.push_rendered_expr(&Expr::Block(ExprBlock {
+                        attrs: vec![parse_quote!(#[allow(unused_braces)])],
+                        ..block.clone()
+                    }));
+                }
                 _ => {
                     gen.push_rendered_expr(value);
                 }
@@ -248,17 +573,152 @@ impl Generate for KeyedAttribute {
     }
 }
 
+#[cfg(feature = "strict-data-attributes")]
+fn data_attribute_diagnostic(punct: &Punctuated<NodeNameFragment, Punct>) -> Option<Diagnostic> {
+    if !is_data(punct) {
+        return None;
+    }
+
+    validate::data_attribute_error(&node_name_lit(&NodeName::Punctuated(punct.clone())).value())
+        .map(|message| {
+            punct
+                .pairs()
+                .skip(1)
+                .map(quote::ToTokens::into_token_stream)
+                .collect::<TokenStream>()
+                .span()
+                .error(message)
+        })
+}
+
+/// Checks a literal or empty attribute value against the values the attribute
+/// accepts.
+fn attribute_value_diagnostic(keyed: &KeyedAttribute) -> Option<Diagnostic> {
+    let value = match &keyed.possible_value {
+        KeyedAttributeValue::Value(AttributeValueExpr {
+            value:
+                KVAttributeValue::Expr(Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                })),
+            ..
+        }) => Some(lit),
+        KeyedAttributeValue::None => None,
+        _ => return None,
+    };
+
+    validate::attribute_value_error(
+        &node_name_lit(&keyed.key).value(),
+        value.map(LitStr::value).as_deref(),
+    )
+    .map(|message| {
+        value
+            .map_or_else(|| keyed.key.span(), Spanned::span)
+            .error(message)
+    })
+}
+
+/// Whether this is a `data-*` attribute name, which skips validation.
+fn is_data(punct: &Punctuated<NodeNameFragment, Punct>) -> bool {
+    let mut name_pairs = punct.pairs();
+
+    name_pairs.next().is_some_and(|pair| {
+        if let Pair::Punctuated(NodeNameFragment::Ident(ident), punct) = pair {
+            ident == "data" && punct.as_char() == '-'
+        } else {
+            false
+        }
+    }) && name_pairs.next().is_some()
+}
+
+/// Reports each `.await` in a block or attribute value, which would otherwise
+/// be reported as an error about the generated closure.
+fn await_diagnostics(nodes: &[Node<Keyword>]) -> Vec<Diagnostic> {
+    nodes
+        .iter()
+        .cloned()
+        .flat_map(Node::flatten)
+        .filter_map(|node| {
+            let tokens = match node {
+                Node::Block(block) => block.into_token_stream(),
+                Node::Element(el) => el
+                    .open_tag
+                    .attributes
+                    .iter()
+                    .map(ToTokens::to_token_stream)
+                    .collect(),
+                _ => return None,
+            };
+
+            validate::await_span(tokens).map(|span| span.error(validate::AWAIT_ERROR))
+        })
+        .collect()
+}
+
+fn attribute_collision_diagnostics(el: &NodeElement<Keyword>) -> Vec<Diagnostic> {
+    let names = el
+        .open_tag
+        .attributes
+        .iter()
+        .filter_map(|attr| match attr {
+            NodeAttribute::Attribute(KeyedAttribute {
+                key: key @ (NodeName::Path(_) | NodeName::Punctuated(_)),
+                ..
+            }) => {
+                if let NodeName::Punctuated(punctuated) = key {
+                    if is_data(punctuated) {
+                        return None;
+                    }
+                }
+
+                Some((node_name_lit(key).value(), key.span()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    validate::attribute_collisions(&names)
+        .into_iter()
+        .map(|(span, message)| span.error(message))
+        .collect()
+}
+
+fn blank_target_warning(el: &NodeElement<Keyword>) -> Option<&'static str> {
+    let attrs = el
+        .open_tag
+        .attributes
+        .iter()
+        .map(|attr| match attr {
+            NodeAttribute::Attribute(attr) => {
+                let value = match &attr.possible_value {
+                    KeyedAttributeValue::None => Some(String::new()),
+                    KeyedAttributeValue::Value(AttributeValueExpr {
+                        value:
+                            KVAttributeValue::Expr(Expr::Lit(ExprLit {
+                                lit: Lit::Str(lit), ..
+                            })),
+                        ..
+                    }) => Some(lit.value()),
+                    _ => None,
+                };
+
+                Some((node_name_lit(&attr.key).value(), value))
+            }
+            // blocks may add a `rel`, so they skip the check
+            NodeAttribute::Block(_) => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    validate::blank_target_warning(&node_name_lit(&el.open_tag.name).value(), &attrs)
+}
+
 fn node_name_ident(node_name: &NodeName) -> Ident {
     match node_name {
         NodeName::Path(ExprPath { path, .. }) => path.segments.last().map_or_else(
             || Ident::new("_", path.span()),
             |segment| {
-                syn::parse2::<Ident>(segment.ident.to_token_stream()).map_or_else(
-                    |_| Ident::new_raw(&segment.ident.to_string(), segment.ident.span()),
-                    |mut ident| {
-                        ident.set_span(segment.ident.span());
-                        ident
-                    },
+                validate::ident(
+                    &validate::normalize_ident(&segment.ident.unraw().to_string()),
+                    segment.ident.span(),
                 )
             },
         ),
@@ -274,23 +734,27 @@ fn node_name_ident(node_name: &NodeName) -> Ident {
                 },
             );
 
-            syn::parse_str::<Ident>(&string).map_or_else(
-                |_| Ident::new_raw(&string, punctuated.span()),
-                |mut ident| {
-                    ident.set_span(punctuated.span());
-                    ident
-                },
-            )
+            validate::ident(&validate::normalize_ident(&string), punctuated.span())
         }
         NodeName::Block(_) => Ident::new("_", node_name.span()),
     }
 }
 
+/// If the ident should be a namespace, the boolean is true.
+fn attribute_ident_or_namespace(key: &NodeName) -> (Ident, bool) {
+    let name = node_name_lit(key).value();
+
+    (
+        validate::ident(&validate::normalize_attribute_ident(&name), key.span()),
+        name.contains(':'),
+    )
+}
+
 fn node_name_lit(node_name: &NodeName) -> LitStr {
     match node_name {
         NodeName::Path(ExprPath { path, .. }) => path.segments.last().map_or_else(
             || LitStr::new("", path.span()),
-            |segment| LitStr::new(&segment.ident.to_string(), segment.ident.span()),
+            |segment| LitStr::new(&segment.ident.unraw().to_string(), segment.ident.span()),
         ),
         NodeName::Punctuated(punctuated) => {
             let string = punctuated.pairs().map(Pair::into_tuple).fold(
@@ -313,6 +777,11 @@ fn node_name_lit(node_name: &NodeName) -> LitStr {
 impl Generate for NodeBlock {
     fn generate(&self, gen: &mut Generator) {
         if let Self::ValidBlock(block) = self {
+            // as for attributes, skip a block with an `.await`
+            if validate::await_span(block.to_token_stream()).is_some() {
+                return;
+            }
+
             gen.push_rendered_expr(&Expr::Block(ExprBlock {
                 attrs: vec![parse_quote!(#[allow(unused_braces)])],
                 label: None,
@@ -328,7 +797,7 @@ impl Generate for NodeText {
     }
 }
 
-impl Generate for RawText {
+impl Generate for RawText<Keyword> {
     fn generate(&self, gen: &mut Generator) {
         gen.push_escaped_lit(LitStr::new(&self.to_string_best(), self.span()));
     }
@@ -0,0 +1,68 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use proc_macro2::TokenStream;
+use quote::quote;
+use sha2::{Digest, Sha384};
+use syn::{
+    parse::{Parse, ParseStream},
+    LitStr, Token,
+};
+
+use crate::file::{read, resolve};
+
+syn::custom_keyword!(src);
+syn::custom_keyword!(file);
+
+/// The arguments of `script_sri!`, like `src = "/app.js", file =
+/// "static/app.js"`.
+pub struct ScriptSri {
+    src: LitStr,
+    file: Option<LitStr>,
+}
+
+impl Parse for ScriptSri {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        input.parse::<src>()?;
+        input.parse::<Token![=]>()?;
+        let src = input.parse()?;
+
+        let file = if input.peek(Token![,]) && input.peek2(file) {
+            input.parse::<Token![,]>()?;
+            input.parse::<file>()?;
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { src, file })
+    }
+}
+
+pub fn generate(args: &ScriptSri) -> syn::Result<TokenStream> {
+    let file = args.file.as_ref().unwrap_or(&args.src);
+
+    let path = resolve(&file.value());
+    let contents = read(&path).map_err(|err| err.spanned(file))?;
+
+    let integrity = format!("sha384-{}", STANDARD.encode(Sha384::digest(&contents)));
+
+    let html = format!(
+        r#"<script src="{}" integrity="{integrity}" crossorigin="anonymous"></script>"#,
+        html_escape::encode_double_quoted_attribute(&args.src.value()),
+    );
+
+    let path = path.to_string_lossy();
+
+    Ok(quote! {
+        {
+            // recompile when the file changes
+            const _: &[u8] = ::core::include_bytes!(#path);
+
+            ::hypertext::Raw(#html)
+        }
+    })
+}
@@ -0,0 +1,83 @@
+//! Opt-in statistics about each invocation, for finding the templates which
+//! are the most expensive to compile.
+//!
+//! Setting `HYPERTEXT_STATS` to a path makes every invocation append a JSON
+//! record to it, with one record per line. When it is unset, nothing is
+//! written.
+
+use std::{env, fmt::Write as _, fs::OpenOptions, io::Write as _};
+
+use proc_macro2::{TokenStream, TokenTree};
+
+/// The environment variable naming the file to append records to.
+const STATS_VAR: &str = "HYPERTEXT_STATS";
+
+/// Counts of what was generated by an invocation, shared between all nested
+/// generators.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Stats {
+    pub nodes: usize,
+    pub elements: usize,
+    pub attributes: usize,
+    pub dynamic_parts: usize,
+}
+
+/// Appends a record of an invocation of `macro_name` to the stats file, if
+/// `HYPERTEXT_STATS` is set.
+///
+/// Each record is written with a single append, so records from parallel
+/// invocations are never interleaved. Errors are ignored, as statistics must
+/// never break a build.
+pub fn record(macro_name: &str, stats: Stats, output: &TokenStream) {
+    let Some(path) = env::var_os(STATS_VAR) else {
+        return;
+    };
+
+    let call_site = proc_macro::Span::call_site();
+    let file = call_site.file();
+
+    let mut line = String::new();
+    let _ = writeln!(
+        line,
+        r#"{{"macro":"{macro_name}","file":"{}","line":{},"nodes":{},"elements":{},"attributes":{},"dynamic_parts":{},"tokens":{}}}"#,
+        escape_json(&file),
+        call_site.line(),
+        stats.nodes,
+        stats.elements,
+        stats.attributes,
+        stats.dynamic_parts,
+        count_tokens(output.clone()),
+    );
+
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = file.write_all(line.as_bytes());
+    }
+}
+
+/// Counts every token in `tokens`, including those inside groups.
+fn count_tokens(tokens: TokenStream) -> usize {
+    tokens
+        .into_iter()
+        .map(|tree| match tree {
+            TokenTree::Group(group) => 1 + count_tokens(group.stream()),
+            _ => 1,
+        })
+        .sum()
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", u32::from(c));
+            }
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
@@ -0,0 +1,361 @@
+use std::fmt::Write;
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+
+/// Keywords which must be checked as raw identifiers.
+const RAW_KEYWORDS: [&str; 50] = [
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "become",
+    "box",
+    "break",
+    "const",
+    "continue",
+    "do",
+    "dyn",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "final",
+    "fn",
+    "for",
+    "gen",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "macro",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "override",
+    "priv",
+    "pub",
+    "ref",
+    "return",
+    "static",
+    "struct",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "unsafe",
+    "unsized",
+    "use",
+    "virtual",
+    "where",
+    "while",
+    "yield",
+    "union",
+    "macro_rules",
+];
+
+/// Keywords which cannot be raw identifiers.
+const NON_RAW_KEYWORDS: [&str; 4] = ["crate", "self", "Self", "super"];
+
+/// The values accepted by an attribute whose literal values are checked.
+#[derive(Debug, Clone, Copy)]
+enum AttributeValues {
+    /// One of the keywords.
+    Enumerated(&'static [&'static str]),
+    /// Either present with no value (or an empty value), or one of the
+    /// keywords.
+    BooleanOrEnumerated(&'static [&'static str]),
+}
+
+/// Attributes whose literal values are checked.
+const ATTRIBUTE_VALUES: [(&str, AttributeValues); 3] = [
+    (
+        "autocapitalize",
+        AttributeValues::Enumerated(&["off", "none", "on", "sentences", "words", "characters"]),
+    ),
+    (
+        "contenteditable",
+        AttributeValues::BooleanOrEnumerated(&["true", "false", "plaintext-only"]),
+    ),
+    (
+        "hidden",
+        AttributeValues::BooleanOrEnumerated(&["hidden", "until-found"]),
+    ),
+];
+
+/// Normalizes an attribute name to the identifier it is checked against.
+///
+/// This must be kept in sync with
+/// `hypertext::validation::normalize_attribute_ident`.
+pub fn normalize_attribute_ident(name: &str) -> String {
+    let name = name
+        .split_once(':')
+        .map_or(name, |(namespace, _)| namespace);
+
+    normalize_ident(&name.replace('-', "_"))
+}
+
+/// Normalizes an underscore-separated name to a valid identifier.
+pub fn normalize_ident(name: &str) -> String {
+    if name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || NON_RAW_KEYWORDS.contains(&name)
+    {
+        format!("_{name}")
+    } else if RAW_KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Creates an identifier from a normalized name.
+pub fn ident(normalized: &str, span: Span) -> Ident {
+    normalized.strip_prefix("r#").map_or_else(
+        || Ident::new(normalized, span),
+        |raw| Ident::new_raw(raw, span),
+    )
+}
+
+/// Finds attributes on the same element with different names which are
+/// checked as the same identifier, returning an error message for each.
+pub fn attribute_collisions(names: &[(String, Span)]) -> Vec<(Span, String)> {
+    let mut collisions = Vec::new();
+
+    for (i, (name, span)) in names.iter().enumerate() {
+        if name.contains(':') {
+            continue;
+        }
+
+        let normalized = normalize_attribute_ident(name);
+
+        if let Some((other, _)) = names[..i].iter().find(|(other, _)| {
+            other != name && !other.contains(':') && normalize_attribute_ident(other) == normalized
+        }) {
+            collisions.push((
+                *span,
+                format!(
+                    "attributes `{other}` and `{name}` are both checked as `{normalized}`, so \
+                     they cannot be told apart"
+                ),
+            ));
+        }
+    }
+
+    collisions
+}
+
+/// Checks that an `<a>` or `<area>` element with a literal `target="_blank"`
+/// also has a `rel` containing `noopener` or `noreferrer`, returning a warning
+/// message if it does not.
+///
+/// Each attribute is given with its literal value, or [`None`] if its value is
+/// dynamic. Elements with a dynamic `target` or `rel` are never reported.
+pub fn blank_target_warning(
+    element: &str,
+    attrs: &[(String, Option<String>)],
+) -> Option<&'static str> {
+    if !matches!(element, "a" | "area") {
+        return None;
+    }
+
+    let values = |name: &'static str| {
+        attrs
+            .iter()
+            .filter(move |(attr, _)| attr == name)
+            .map(|(_, value)| value.as_deref())
+    };
+
+    let blank =
+        values("target").any(|value| value.is_some_and(|v| v.eq_ignore_ascii_case("_blank")));
+
+    let unprotected = values("rel").all(|value| {
+        value.is_some_and(|rel| {
+            !rel.split_ascii_whitespace().any(|keyword| {
+                keyword.eq_ignore_ascii_case("noopener")
+                    || keyword.eq_ignore_ascii_case("noreferrer")
+            })
+        })
+    });
+
+    (blank && unprotected).then_some(
+        "`target=\"_blank\"` without `rel=\"noopener\"` or `rel=\"noreferrer\"` lets the \
+         opened page access `window.opener`",
+    )
+}
+
+/// Checks a literal attribute value, or [`None`] for an attribute with no
+/// value, against the values the attribute accepts, returning an error message
+/// if it is invalid.
+///
+/// Keywords are matched ASCII case-insensitively, like browsers do.
+pub fn attribute_value_error(name: &str, value: Option<&str>) -> Option<String> {
+    let (_, values) = ATTRIBUTE_VALUES.iter().find(|(attr, _)| *attr == name)?;
+
+    let (keywords, boolean) = match *values {
+        AttributeValues::Enumerated(keywords) => (keywords, false),
+        AttributeValues::BooleanOrEnumerated(keywords) => (keywords, true),
+    };
+
+    let valid = value.map_or(boolean, |value| {
+        (boolean && value.is_empty())
+            || keywords
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(value))
+    });
+
+    if valid {
+        return None;
+    }
+
+    let mut expected = keywords
+        .iter()
+        .map(|keyword| format!("`\"{keyword}\"`"))
+        .collect::<Vec<_>>();
+
+    if boolean {
+        expected.insert(0, "no value".to_owned());
+    }
+
+    let last = expected.pop().unwrap_or_default();
+
+    Some(format!(
+        "`{name}` must have {} or {last}, but {}",
+        expected.join(", "),
+        value.map_or_else(
+            || "it has no value".to_owned(),
+            |value| format!("it is `\"{value}\"`")
+        ),
+    ))
+}
+
+/// Checks the name of a `data-*` attribute against the XML-compatible subset
+/// of names (lowercase ASCII letters, digits, and hyphens), returning an
+/// error message if it is invalid.
+#[cfg_attr(not(feature = "strict-data-attributes"), allow(dead_code))]
+pub fn data_attribute_error(name: &str) -> Option<String> {
+    let rest = name.strip_prefix("data-")?;
+
+    if rest
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return None;
+    }
+
+    let mut message = format!(
+        "`data-*` attribute names may only contain lowercase ASCII letters, digits, and \
+         hyphens, but `{name}` does not"
+    );
+
+    if rest.chars().any(|c| c.is_ascii_uppercase()) {
+        let kebab = rest.chars().fold(String::new(), |mut acc, c| {
+            if c.is_ascii_uppercase() {
+                acc.push('-');
+            }
+            acc.push(c.to_ascii_lowercase());
+            acc
+        });
+
+        let _ = write!(
+            message,
+            "\n\nbrowsers lowercase attribute names, so this is exposed as `dataset.{}`; use \
+             `data-{kebab}` to get `dataset.{}`",
+            dataset_name(&rest.to_ascii_lowercase()),
+            dataset_name(&kebab),
+        );
+    }
+
+    Some(message)
+}
+
+/// The error for an `.await` in a template.
+pub const AWAIT_ERROR: &str = "templates render synchronously, so `.await` can't be used in \
+                               them; await this value before the macro and splice the result";
+
+/// Finds an `.await` in the tokens of an expression, returning the span of
+/// the `await`.
+///
+/// Without this, rustc reports an `.await` in a splice as an error about the
+/// closure the macro generates. An `.await` inside a nested `async` block is
+/// fine, as it doesn't make the template itself asynchronous.
+pub fn await_span(tokens: TokenStream) -> Option<Span> {
+    let mut tokens = tokens.into_iter().peekable();
+    let mut after_async = false;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '.' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                    if ident == "await" {
+                        return Some(ident.span());
+                    }
+                }
+            }
+            TokenTree::Ident(ident) if ident == "async" => after_async = true,
+            TokenTree::Ident(ident) if after_async && ident == "move" => {}
+            TokenTree::Group(group) => {
+                if !after_async {
+                    if let Some(span) = await_span(group.stream()) {
+                        return Some(span);
+                    }
+                }
+
+                after_async = false;
+            }
+            _ => after_async = false,
+        }
+    }
+
+    None
+}
+
+/// Converts the part of a `data-*` attribute name after `data-` to its
+/// `dataset` property name.
+#[cfg_attr(not(feature = "strict-data-attributes"), allow(dead_code))]
+fn dataset_name(rest: &str) -> String {
+    let mut name = String::new();
+    let mut chars = rest.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match chars.peek() {
+            Some(next) if c == '-' && next.is_ascii_lowercase() => {
+                name.push(next.to_ascii_uppercase());
+                chars.next();
+            }
+            _ => name.push(c),
+        }
+    }
+
+    name
+}
+
+/// Checks the HTML of a `@raw` block, returning an error message if it opens
+/// a comment or `<script>` element without closing it, which would swallow
+/// the rest of the page.
+pub fn raw_html_error(html: &str) -> Option<&'static str> {
+    let html = html.to_ascii_lowercase();
+
+    // the HTML outside of comments, as `<script` inside them does nothing
+    let mut uncommented = String::with_capacity(html.len());
+    let mut rest = html.as_str();
+
+    while let Some(start) = rest.find("<!--") {
+        uncommented.push_str(&rest[..start]);
+
+        let Some(end) = rest[start + 4..].find("-->") else {
+            return Some("`@raw` HTML opens a comment without closing it with `-->`");
+        };
+        rest = &rest[start + 4 + end + 3..];
+    }
+
+    uncommented.push_str(rest);
+
+    if uncommented.matches("<script").count() != uncommented.matches("</script").count() {
+        return Some("`@raw` HTML opens a `<script>` element without closing it with `</script>`");
+    }
+
+    None
+}
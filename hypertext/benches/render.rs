@@ -0,0 +1,147 @@
+//! Benchmarks for rendering with [`hypertext`].
+//!
+//! Each group compares the macros against a hand-written equivalent which
+//! pushes straight to a [`String`], which is the best case the macros can
+//! achieve.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use hypertext::{html_elements, GlobalAttributes, RenderIterator, Renderable};
+
+const ITEMS: [&str; 8] = [
+    "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta",
+];
+
+fn page(c: &mut Criterion) {
+    let mut group = c.benchmark_group("page");
+
+    group.bench_function("maud", |b| {
+        b.iter(|| {
+            let items = black_box(ITEMS);
+
+            hypertext::maud! {
+                !DOCTYPE
+                html {
+                    head { title { "Benchmark" } }
+                    body {
+                        h1.title { "Items" }
+                        ul {
+                            @for item in items {
+                                li.item { (item) }
+                            }
+                        }
+                    }
+                }
+            }
+            .render()
+        });
+    });
+
+    group.bench_function("rsx", |b| {
+        b.iter(|| {
+            let items = black_box(ITEMS);
+
+            hypertext::rsx! {
+                <!DOCTYPE html>
+                <html>
+                    <head><title>Benchmark</title></head>
+                    <body>
+                        <h1 class="title">Items</h1>
+                        <ul>
+                            { items.iter().map(|&item| hypertext::rsx_move! {
+                                <li class="item">{item}</li>
+                            }).render_all() }
+                        </ul>
+                    </body>
+                </html>
+            }
+            .render()
+        });
+    });
+
+    group.bench_function("push_str", |b| {
+        b.iter(|| {
+            let items = black_box(ITEMS);
+            let mut output = String::new();
+
+            output.push_str(
+                "<!DOCTYPE html><html><head><title>Benchmark</title></head><body><h1 \
+                 class=\"title\">Items</h1><ul>",
+            );
+            for item in items {
+                output.push_str("<li class=\"item\">");
+                item.render_to(&mut output);
+                output.push_str("</li>");
+            }
+            output.push_str("</ul></body></html>");
+
+            output
+        });
+    });
+
+    group.finish();
+}
+
+fn statics(c: &mut Criterion) {
+    let mut group = c.benchmark_group("statics");
+
+    // adjacent static parts are coalesced into a single `push_str`, so this
+    // should match `push_str` rather than `push_str_each`
+    group.bench_function("maud", |b| {
+        b.iter(|| {
+            hypertext::maud! {
+                header.site {
+                    nav { a href="/" { "Home" } " | " a href="/about" { "About" } }
+                    h1 { "Title" }
+                }
+            }
+            .render()
+        });
+    });
+
+    group.bench_function("push_str", |b| {
+        b.iter(|| {
+            let mut output = String::new();
+
+            output.push_str(black_box(
+                "<header class=\"site\"><nav><a href=\"/\">Home</a> | <a \
+                 href=\"/about\">About</a></nav><h1>Title</h1></header>",
+            ));
+
+            output
+        });
+    });
+
+    group.bench_function("push_str_each", |b| {
+        b.iter(|| {
+            let mut output = String::new();
+
+            for part in black_box([
+                "<header class=\"site\">",
+                "<nav>",
+                "<a href=\"/\">",
+                "Home",
+                "</a>",
+                " | ",
+                "<a href=\"/about\">",
+                "About",
+                "</a>",
+                "</nav>",
+                "<h1>",
+                "Title",
+                "</h1>",
+                "</header>",
+            ]) {
+                output.push_str(part);
+            }
+
+            output
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, page, statics);
+criterion_main!(benches);
@@ -1,8 +1,65 @@
 extern crate alloc;
 
-use alloc::{borrow::Cow, rc::Rc, string::String, sync::Arc};
+use alloc::{borrow::Cow, rc::Rc, string::String, sync::Arc, vec::Vec};
 use core::fmt::{self, Display, Write};
 
+/// Build an inline `style` value from `property: value` declarations.
+///
+/// Each value is one or more string or number literals and `(expr)` splices,
+/// and declarations are joined with `; `. Numbers keep their suffix, so most
+/// units can be written directly (`10px`), but units starting with `e` (like
+/// `em`) are read as an exponent by Rust, so must be quoted. Property names are
+/// written like attribute names (`margin-top`), and custom properties start
+/// with `--`.
+///
+/// Like [`maud!`], this returns a [`Renderable`] which borrows what it uses,
+/// and everything is escaped for an attribute value. Values are not checked
+/// as CSS, so a dynamic value containing `;` can still add declarations.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{css, html_elements, maud, GlobalAttributes, Renderable};
+///
+/// let color = "rebeccapurple";
+/// let size = 2;
+///
+/// assert_eq!(
+///     maud! {
+///         p style=(css! { color: (color); font-size: (size) "rem"; margin: 0 }) {
+///             "Hi"
+///         }
+///     }
+///     .render(),
+///     r#"<p style="color: rebeccapurple; font-size: 2rem; margin: 0">Hi</p>"#,
+/// );
+/// ```
+pub use hypertext_macros::css;
+/// Convert Markdown to HTML at compile time.
+///
+/// The Markdown can be given as a string literal, or read from a file with
+/// `file = "..."`, relative to the crate's `CARGO_MANIFEST_DIR`. It is
+/// converted with the same options as [`Markdown`], and raw HTML in it is
+/// passed through as-is, so it must be trusted.
+///
+/// This expands to a [`Raw<&'static str>`](Raw), so it can be spliced into
+/// templates or used in `const` items. When reading a file, the crate is
+/// recompiled whenever it changes.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, markdown_static, maud, Raw, Renderable};
+///
+/// const INTRO: Raw<&str> = markdown_static!("Hello, *world*!");
+///
+/// assert_eq!(
+///     maud! { article { (INTRO) } }.render(),
+///     "<article><p>Hello, <em>world</em>!</p>\n</article>",
+/// );
+/// ```
+#[cfg(feature = "markdown")]
+pub use hypertext_macros::markdown_static;
 /// Generate HTML using [`maud`] syntax.
 ///
 /// Note that this is not a complete 1:1 port of [`maud`]'s syntax as it is
@@ -16,8 +73,155 @@ use core::fmt::{self, Display, Write};
 /// - Optional [`class`]es (`.some-class[condition]`) must come after all
 ///   required [`class`]es.
 ///
+/// Attributes are always rendered in the order they are written, which is the
+/// `id` shorthand, then the `class` shorthand (required [`class`]es, then
+/// optional ones), then the remaining attributes and spreads. This order is
+/// stable across releases, so the same template always renders the same
+/// bytes. To compare HTML from other sources regardless of attribute order,
+/// use [`canonicalize`].
+///
+/// Apart from bare names (`#main`, `.card`), the values of the `#` and `.`
+/// shorthands accept exactly what an attribute value does, so literals,
+/// splices and groups with control flow (`#{ "item-" (i) }`, `.{ @if wide {
+/// "wide" } @else { "narrow" } }`) work the same as in `id={ ... }`.
+///
+/// Conditional classes can also be listed among the attributes as
+/// `class:[("active", is_active), ("disabled", !enabled)]`, which is exactly
+/// the same as writing the toggled classes `."active"[is_active]
+/// ."disabled"[!enabled]` after any `.` classes, so no list is built at
+/// runtime. It can't be combined with a `class` attribute.
+///
+/// An attribute with a toggle (`title="Saved"[saved]`) is only rendered when
+/// the condition is true. Adding `else` and a second value
+/// (`draggable="true"[movable] else "false"`) always renders the attribute
+/// instead, with the first value when the condition is true and the second
+/// when it is false. Either value can be a literal, a splice or a group.
+///
+/// An attribute without a value (`input disabled;`) is rendered as just its
+/// name, and can also have a toggle (`input disabled[locked];`). For
+/// compatibility with older versions of maud, its name can be followed by a
+/// `?` (`input disabled?;`, `input disabled?[locked];`), which means exactly
+/// the same. Attributes are separated by spaces, so a `,` between them is an
+/// error.
+///
+/// Element and attribute names can be quoted (`"my-élément" "ünï"="1" {
+/// ... }`) to use names which aren't valid Rust identifiers, such as
+/// non-ASCII custom element names. Quoted names skip validation entirely, and
+/// cannot be empty or contain whitespace, quotes, `<`, `>`, `/` or `=`. To be
+/// told apart from text, a quoted element name must be directly followed by
+/// its `#` or `.` shorthands, an attribute with a value, or its body.
+///
+/// Element names can also have a namespace prefix (`svg:use`), which is
+/// checked as the element `svg_use`, as described in [`rsx!`].
+///
+/// String literals, including attribute values, are rendered exactly as
+/// written (after escaping). No whitespace normalization is performed, so
+/// newlines and indentation inside a multiline literal are preserved, and
+/// literals are never trimmed or unindented (like leading spaces in a
+/// `title`, or ASCII art in a `pre`). Raw strings (`r#"..."#`) are escaped
+/// like any other literal.
+///
+/// After an element's attributes, `..(attrs)` renders every attribute in an
+/// [`AttributeSet`]. Maps and other iterators of name-value pairs can be
+/// spread by wrapping them in [`Attributes`], or in [`DataAttrs`] to render
+/// each key as a `data-*` attribute.
+///
+/// Names starting with an uppercase letter are components: `Card title=("Hi")
+/// { ... }` renders the struct expression `Card { title: "Hi", children: ...
+/// }`, where the attribute values are literals or `(expr)` splices. The
+/// default children are passed as the `children` field, and each `@slot name
+/// { ... }` among them fills the field `name`, all as [`Renderable`]
+/// closures. A component without children or slots is written `Card;`. The
+/// `#id` and `.class` shorthands pass the `id` and `class` fields, with
+/// multiple classes joined by spaces.
+///
+/// Like the closures [`maud!`] itself returns, children and slot closures
+/// borrow what they use, so they are [`Clone`] and [`Copy`] as long as every
+/// captured value is only borrowed. A component bounding its children by
+/// `Renderable + Copy` can then render them more than once. Splicing an owned
+/// value (like a `(name)` where `name` is a [`String`]) moves it in, so the
+/// closure is only [`Clone`], and only if the value is.
+///
+/// To render an element whose name starts with an uppercase letter, write
+/// `@element Name`; `@component name` likewise forces a name to be a
+/// component. Using an element type as a component (for example, when a
+/// `Button` element is imported alongside the `Button` component) is
+/// reported with a warning next to the struct errors.
+///
+/// Component names are looked up like any other struct, so components from
+/// another crate must be imported. Libraries of components can generate a
+/// prelude importing all of them (and their elements) with
+/// [`component_prelude!`].
+///
+/// An `@rsx { ... }` block parses its contents with [`rsx!`] syntax instead,
+/// and renders them inline, so anything in scope (like `@let` bindings) can be
+/// used inside it.
+///
+/// An `@cfg(predicate) { ... }` block only compiles its contents if the
+/// predicate holds, like a `#[cfg(predicate)]` attribute.
+///
+/// `@include("partials/header.maud")` reads a file of maud at compile time,
+/// relative to the crate's `CARGO_MANIFEST_DIR` (ignoring a leading `/`, like
+/// [`script_sri!`]), and renders its nodes inline as if they were written in
+/// its place, so they can use anything in scope. `@let` bindings in the file
+/// don't leak out of it. The crate is recompiled whenever an included file
+/// changes, and a file which includes itself, directly or through other
+/// files, is an error.
+///
+/// `@raw { "<b>trusted</b>" }` renders its string literals verbatim, without
+/// escaping, like splicing a [`Raw`] value. Only literals are allowed, so
+/// every use is visible in the source, but their contents are still trusted
+/// completely: never paste user-controlled HTML into one, as that allows
+/// cross-site scripting. As a basic check, a `@raw` block which opens a
+/// comment or `<script>` element without closing it fails to compile, as it
+/// would swallow the rest of the page.
+///
+/// The heads of `@if` and `@while` are parsed as Rust conditions, so they can
+/// also be `let` patterns, like `@while let Some(Point { x, y }) = stack.pop()
+/// { ... }`.
+///
+/// Loops (`@for` and `@while`) can be followed by `@separated_by { ... }`,
+/// which renders its contents between each iteration, but not before the
+/// first or after the last. Iterations skipped with `@continue` (or cut short
+/// with `@break`) before rendering anything are not separated.
+///
+/// A `@for` loop can be keyed for client-side frameworks which diff lists by
+/// key, like `@for item in items keyed by (item.id) { li { ... } }`. Its body
+/// must be a single element, which is rendered with a `data-key` attribute
+/// holding the key.
+///
+/// `@break;` and `@continue;` can be used in the body of a loop. Loops can be
+/// labeled like `@'rows: for row in rows { ... }`, so that `@break 'rows;`
+/// applies to an outer loop.
+///
 /// Additionally, adding `!DOCTYPE` at the beginning of the invocation will
-/// render `"<!DOCTYPE html>"`.
+/// render `"<!DOCTYPE html>"`. Following it with a string literal (for
+/// example, `!DOCTYPE r#"html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN""#`)
+/// will instead render that literal verbatim as a legacy doctype.
+///
+/// Options can be given at the very beginning of the invocation as an inner
+/// attribute, like `#![newlines]`. The available options are:
+/// - `newlines`: Insert a newline between each top-level node (including the
+///   doctype), which makes the output of whole documents easier to read. Nested
+///   nodes are not affected.
+/// - `xhtml`: Close void elements XML-style (`<br />` instead of `<br>`), for
+///   XHTML documents and other consumers which require well-formed XML.
+///
+/// The macro evaluates to a closure which borrows anything it uses from its
+/// environment (use [`maud_move!`] to move instead), so its type is tied to
+/// the lifetime of those borrows. To store it, either make the field generic
+/// over [`Renderable`], in which case the lifetime is inferred, or box it as a
+/// `Box<dyn FnOnce(&mut String) + 'a>`, which names the lifetime explicitly
+/// and is itself [`Renderable`].
+///
+/// Splicing an owned value, like `(name)` where `name` is a [`String`], moves
+/// it into the closure, as rendering consumes it. Using the value after the
+/// invocation then fails with a "borrow of moved value" error pointing at the
+/// splice. Splice a reference (`(&name)`) to keep using it, as the compiler
+/// suggests.
+///
+/// Templates render synchronously, so an `.await` in a splice is a compile
+/// error. Await the value before the invocation, and splice the result.
 ///
 /// For more details, see the [maud book](https://maud.lambda.xyz).
 ///
@@ -49,6 +253,28 @@ pub use hypertext_macros::maud;
 pub use hypertext_macros::maud_move;
 /// Generate HTML using rsx syntax.
 ///
+/// This macro accepts the same options as [`maud!`].
+///
+/// An `@maud { ... }` node parses its contents with [`maud!`] syntax instead,
+/// and renders them inline. This can be nested in turn with `@rsx { ... }`.
+///
+/// As in [`maud!`], an `@cfg(predicate) { ... }` node only compiles its
+/// contents if the predicate holds, and `@include("...")` renders a file of
+/// rsx inline. Since included files are often hand-written HTML, their
+/// comments don't need their text quoted, so `<!-- don't edit -->` works
+/// there as well as `<!-- "don't edit" -->`.
+///
+/// Comments are rendered verbatim, except that a `>` which would end the
+/// comment early (like in `-->`) is escaped.
+///
+/// Element names can have a namespace prefix, like `<svg:use>`. The name is
+/// rendered as written, and checked as the element `svg_use`, so it can be
+/// defined in your own `html_elements` module like any other element.
+/// Namespaced attributes like `xlink:href` are checked against the
+/// [`AttributeNamespace`](crate::AttributeNamespace) `xlink` instead. HTML5
+/// doesn't use element namespaces, but XML serializations of it (like XHTML)
+/// do.
+///
 /// # Example
 ///
 /// ```
@@ -71,6 +297,33 @@ pub use hypertext_macros::rsx;
 /// generated closure, allowing it to take ownership of its environment. You
 /// will most likely need this when using [`rsx!`] inside an iterator method.
 pub use hypertext_macros::rsx_move;
+/// Generate a `<script>` element with a subresource integrity hash.
+///
+/// The script is read at compile time, relative to the crate's
+/// `CARGO_MANIFEST_DIR`, and its SHA-384 hash is emitted as the `integrity`
+/// attribute, along with `crossorigin="anonymous"`. By default, the file is
+/// read from the `src` path (ignoring a leading `/`), but it can be given
+/// separately with `file`.
+///
+/// This expands to a [`Raw`] value, so it can be spliced into templates or
+/// used in `const` items. The crate is recompiled whenever the file changes.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, script_sri, Renderable};
+///
+/// let page = maud! {
+///     head { (script_sri!(src = "/sri.js", file = "tests/fixtures/sri.js")) }
+/// };
+///
+/// assert!(page
+///     .render()
+///     .as_str()
+///     .starts_with(r#"<head><script src="/sri.js" integrity="sha384-"#));
+/// ```
+#[cfg(feature = "sri")]
+pub use hypertext_macros::script_sri;
 
 use crate::Rendered;
 
@@ -83,6 +336,16 @@ impl<T: Into<Self>> From<Rendered<T>> for String {
 
 /// A type that can be rendered to a string.
 ///
+/// The same trait is used for element content and attribute values, as both
+/// are escaped the same way. A function returning `impl Renderable` (like a
+/// computed `style`) can be spliced as either, with no extra annotations.
+///
+/// Mutable references to the closures [`maud!`] and [`rsx!`] return are
+/// already [`Renderable`], as every `&mut F` where `F` is
+/// `FnMut(&mut String)` is itself a closure. A blanket implementation for
+/// other `&mut T` would overlap with that, so only [`String`], [`str`] and
+/// [`Fragments`] are also rendered through `&mut` references.
+///
 /// # Example
 ///
 /// ```
@@ -125,194 +388,2007 @@ where
     fn render_to(self, output: &mut String);
 
     /// Renders this value to a string.
+    ///
+    /// This consumes the value, so anything it owns is dropped afterwards.
+    /// Values which are already HTML strings, like [`Raw<String>`], can be
+    /// converted with [`Raw::rendered`] instead, which moves the string
+    /// rather than copying it.
     #[inline]
     fn render(self) -> Rendered<String> {
         let mut output = String::new();
         self.render_to(&mut output);
         Rendered(output)
     }
-}
 
-/// A value rendered via its [`Display`] implementation.
-///
-/// This will handle escaping special characters for you.
-#[derive(Debug, Clone, Copy)]
-pub struct Displayed<T: Display>(pub T);
+    /// Renders this value to a string with at least `capacity` bytes
+    /// reserved up front.
+    ///
+    /// When the size of a page is roughly known, this avoids reallocating as
+    /// the output grows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, Renderable};
+    ///
+    /// let page = maud! { p { "Hello!" } }.render_with_capacity(4096);
+    ///
+    /// assert_eq!(page, "<p>Hello!</p>");
+    /// assert!(page.as_inner().capacity() >= 4096);
+    /// ```
+    #[inline]
+    fn render_with_capacity(self, capacity: usize) -> Rendered<String> {
+        let mut output = String::with_capacity(capacity);
+        self.render_to(&mut output);
+        Rendered(output)
+    }
 
-impl<T: Display> Renderable for Displayed<T> {
+    /// Renders this value to a string, with every non-ASCII character replaced
+    /// by a numeric character reference, for systems which only accept ASCII.
+    ///
+    /// This applies [`escape::ascii`](crate::escape::ascii) to the whole
+    /// output, on top of the normal escaping. References are only decoded in
+    /// text and attribute values, so non-ASCII element or attribute names,
+    /// and non-ASCII text in `<script>` or `<style>` elements, won't survive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+    ///
+    /// assert_eq!(
+    ///     maud! { p title="naïve" { "Café & 🎉" } }.render_ascii(),
+    ///     r#"<p title="na&#xEF;ve">Caf&#xE9; &amp; &#x1F389;</p>"#,
+    /// );
+    /// ```
     #[inline]
-    fn render_to(self, output: &mut String) {
-        struct Escaper<'a>(&'a mut String);
+    fn render_ascii(self) -> Rendered<String> {
+        let rendered = self.render().into_inner();
 
-        impl fmt::Write for Escaper<'_> {
-            #[inline]
-            fn write_str(&mut self, s: &str) -> fmt::Result {
-                html_escape::encode_double_quoted_attribute_to_string(s, self.0);
-                Ok(())
-            }
+        match crate::escape::ascii(&rendered) {
+            Cow::Borrowed(_) => Rendered(rendered),
+            Cow::Owned(ascii) => Rendered(ascii),
         }
-
-        // ignore errors, as we are writing to a string
-        let _ = write!(Escaper(output), "{}", self.0);
     }
-}
 
-impl<F: FnOnce(&mut String)> Renderable for F {
+    /// Renders this value to a string, then post-processes it with `f`.
+    ///
+    /// This is an escape hatch for cases templates can't express, like
+    /// replacing a placeholder inserted by a [`Raw`] fragment. `f` receives
+    /// the already-escaped HTML, and whatever it returns is trusted as-is, so
+    /// it must not introduce unescaped user input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, Raw, Renderable};
+    ///
+    /// let page = maud! { p { "Rendered in " (Raw("{{TIME}}")) } }
+    ///     .map_rendered(|html| html.replace("{{TIME}}", "3ms"));
+    ///
+    /// assert_eq!(page, "<p>Rendered in 3ms</p>");
+    /// ```
     #[inline]
-    fn render_to(self, output: &mut String) {
-        self(output);
+    fn map_rendered<F: FnOnce(String) -> String>(self, f: F) -> Rendered<String> {
+        Rendered(f(self.render().into_inner()))
     }
-}
 
-/// A raw value that is rendered without escaping.
-///
-/// This is useful for rendering raw HTML, but should be used with caution
-/// as it can lead to XSS vulnerabilities if used incorrectly. If you are
-/// unsure, render the actual string instead, as its implementation will
-/// escape any special characters.
-#[derive(Debug, Clone, Copy)]
-pub struct Raw<T: AsRef<str>>(pub T);
+    /// Wraps this value in a [`DisplayAdapter`], which implements [`Display`]
+    /// by rendering it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, Renderable};
+    ///
+    /// let name = "<Alice>";
+    /// let greeting = maud! { b { "Hi, " (name) "!" } };
+    ///
+    /// assert_eq!(
+    ///     format!("Subject: hello\n\n{}", greeting.as_display()),
+    ///     "Subject: hello\n\n<b>Hi, &lt;Alice&gt;!</b>",
+    /// );
+    /// ```
+    #[inline]
+    fn as_display(&self) -> DisplayAdapter<'_, Self>
+    where
+        Self: Clone,
+    {
+        DisplayAdapter(self)
+    }
 
-impl<T: AsRef<str>> Renderable for Raw<T> {
+    /// Renders this value, then calls `f` with everything rendered to the
+    /// output so far.
+    ///
+    /// This gives read-only access to the output in the middle of a render,
+    /// like recording the offset where some content ends to patch it later.
+    /// To observe the output before some content instead, tap an empty value
+    /// (like `()`) in front of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, Renderable};
+    ///
+    /// let mut start = 0;
+    /// let mut end = 0;
+    ///
+    /// let page = maud! {
+    ///     h1 { "Title" }
+    ///     (().tap_output(|output| start = output.len()))
+    ///     (maud! { p { "Body" } }.tap_output(|output| end = output.len()))
+    /// }
+    /// .render();
+    ///
+    /// assert_eq!(&page.as_str()[start..end], "<p>Body</p>");
+    /// ```
     #[inline]
-    fn render_to(self, output: &mut String) {
-        output.push_str(self.0.as_ref());
+    fn tap_output<F: FnOnce(&str)>(self, f: F) -> TapOutput<Self, F> {
+        TapOutput(self, f)
     }
-}
 
-/// An extension trait for [`IntoIterator`]s that can be rendered.
-pub trait RenderIterator: IntoIterator
-where
-    Self: Sized,
-    Self::Item: Renderable,
-{
-    /// Renders each item in this iterator.
+    /// Wraps this value with `f` if `cond` is true, or renders it as-is
+    /// otherwise.
+    ///
+    /// This is useful for markup which is only sometimes needed around some
+    /// content, like a link around a card which may not have a URL.
     ///
     /// # Example
     ///
     /// ```
-    /// use hypertext::{html_elements, maud, maud_move, GlobalAttributes, Renderable, RenderIterator};
+    /// use hypertext::{html_elements, maud, maud_move, Renderable};
     ///
-    /// let items = ["milks", "eggs", "bread"];
+    /// let card = |href: Option<&'static str>| {
+    ///     maud! { h2 { "Title" } }.wrap_if(href.is_some(), |title| {
+    ///         maud_move! { a href=[href] { (title) } }
+    ///     })
+    /// };
     ///
     /// assert_eq!(
-    ///     maud! {
-    ///         ul #shopping-list {
-    ///             (items
-    ///                 .iter()
-    ///                 .map(|&item| maud_move! { li { (item) } })
-    ///                 .render_all())
-    ///         }
-    ///     }.render(),
-    ///     r#"<ul id="shopping-list"><li>milks</li><li>eggs</li><li>bread</li></ul>"#
+    ///     card(Some("/post")).render(),
+    ///     r#"<a href="/post"><h2>Title</h2></a>"#
     /// );
+    /// assert_eq!(card(None).render(), "<h2>Title</h2>");
+    /// ```
     #[inline]
-    fn render_all(self) -> impl FnOnce(&mut String) {
-        |output| {
-            self.into_iter().for_each(|item| {
-                item.render_to(output);
-            });
+    fn wrap_if<F: FnOnce(Self) -> R, R: Renderable>(self, cond: bool, f: F) -> WrapIf<Self, R> {
+        if cond {
+            WrapIf::Wrapped(f(self))
+        } else {
+            WrapIf::Bare(self)
         }
     }
-}
-
-impl<I: IntoIterator> RenderIterator for I where Self::Item: Renderable {}
 
-impl Renderable for char {
+    /// Renders this value to a string, with the given [`Theme`] installed for
+    /// any [`Var`]s to resolve their values from.
+    ///
+    /// The theme is only installed for the duration of this render, and
+    /// nested calls will shadow it until they return.
+    ///
+    /// [`Theme`]: crate::theme::Theme
+    /// [`Var`]: crate::theme::Var
+    #[cfg(feature = "theme")]
     #[inline]
-    fn render_to(self, output: &mut String) {
-        match self {
-            '&' => output.push_str("&amp;"),
-            '<' => output.push_str("&lt;"),
-            '>' => output.push_str("&gt;"),
-            '"' => output.push_str("&quot;"),
-            '\'' => output.push_str("&#x27;"),
-            '/' => output.push_str("&#x2f;"),
-            c => output.push(c),
-        }
+    fn render_with(self, theme: &crate::theme::Theme) -> Rendered<String> {
+        crate::theme::scoped(theme, || self.render())
     }
-}
 
-impl Renderable for &str {
+    /// Renders this value to a string, then checks that it is well-formed
+    /// with [`validate::check`].
+    ///
+    /// This is intended for tests, to catch malformed output from [`Raw`]
+    /// fragments or manual implementations of this trait.
+    ///
+    /// # Errors
+    ///
+    /// Returns every error found in the rendered HTML.
+    ///
+    /// [`validate::check`]: crate::validate::check
+    #[cfg(feature = "validate")]
     #[inline]
-    fn render_to(self, output: &mut String) {
-        html_escape::encode_single_quoted_attribute_to_string(self, output);
+    fn render_validated(
+        self,
+    ) -> Result<Rendered<String>, alloc::vec::Vec<crate::validate::ValidationError>> {
+        let rendered = self.render();
+        crate::validate::check(rendered.as_str()).map(|()| rendered)
     }
 }
 
-impl Renderable for &String {
-    #[inline]
-    fn render_to(self, output: &mut String) {
-        self.as_str().render_to(output);
-    }
-}
+/// Checks whether a component invocation in [`maud!`] was actually an element
+/// type, which is reported with a deprecation warning (the only kind of
+/// warning macros can emit on stable) alongside the errors from constructing
+/// it as a struct.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct ComponentCheck<T>(core::marker::PhantomData<T>);
 
-impl Renderable for String {
+impl<T> ComponentCheck<T> {
     #[inline]
-    fn render_to(self, output: &mut String) {
-        self.as_str().render_to(output);
+    #[must_use]
+    pub const fn of(_: &T) -> Self {
+        Self(core::marker::PhantomData)
     }
 }
 
-impl Renderable for Cow<'_, str> {
+impl<T: crate::GlobalAttributes> ComponentCheck<T> {
+    #[deprecated(
+        note = "this is an element type, but capitalized names are treated as components: did \
+                you intend an element? write `@element Name` to render it as one"
+    )]
     #[inline]
-    fn render_to(self, output: &mut String) {
-        self.as_ref().render_to(output);
-    }
+    pub const fn check(&self) {}
 }
 
-impl Renderable for bool {
+/// The fallback for [`ComponentCheck::check`] for types which are not
+/// elements.
+#[doc(hidden)]
+pub trait NotAnElement {
     #[inline]
-    fn render_to(self, output: &mut String) {
-        if self {
-            output.push_str("true");
-        } else {
-            output.push_str("false");
-        }
-    }
+    fn check(&self) {}
 }
 
-macro_rules! render_via_itoa {
-    ($($Ty:ty)*) => {
-        $(
-            impl Renderable for $Ty {
-                #[inline]
-                fn render_to(self, output: &mut String) {
-                    output.push_str(itoa::Buffer::new().format(self));
-                }
-            }
-        )*
-    };
-}
+impl<T> NotAnElement for ComponentCheck<T> {}
 
-render_via_itoa! {
-    i8 i16 i32 i64 i128 isize
-    u8 u16 u32 u64 u128 usize
-}
+/// Joins the classes of a component's `.class` shorthand with spaces, skipping
+/// toggled classes which are off.
+///
+/// This is used by [`maud!`] when any of the classes are dynamic.
+#[doc(hidden)]
+#[inline]
+#[must_use]
+pub fn join_classes(classes: &[Option<&dyn Display>]) -> String {
+    let mut joined = String::new();
 
-macro_rules! render_via_ryu {
-    ($($Ty:ty)*) => {
-        $(
-            impl Renderable for $Ty {
-                #[inline]
-                fn render_to(self, output: &mut String) {
-                    output.push_str(ryu::Buffer::new().format(self));
-                }
-            }
-        )*
-    };
-}
+    for class in classes.iter().flatten() {
+        if !joined.is_empty() {
+            joined.push(' ');
+        }
 
-render_via_ryu! {
-    f32 f64
+        // ignore errors, as we are writing to a string
+        let _ = write!(joined, "{class}");
+    }
+
+    joined
 }
 
-impl<T: Renderable> Renderable for Option<T> {
-    #[inline]
-    fn render_to(self, output: &mut String) {
-        if let Some(value) = self {
-            value.render_to(output);
-        }
+#[macro_export]
+/// Create a prelude module for a library of components.
+///
+/// A component library usually has its own `html_elements` module (for its
+/// custom elements) and attribute traits, and every one of them must be in
+/// scope wherever its components are used, under exactly the names the macros
+/// look for. This generates a module re-exporting the elements module as
+/// `html_elements` (defaulting to [`html_elements`](crate::html_elements) if
+/// `elements = path;` is omitted), along with [`GlobalAttributes`],
+/// [`Renderable`] and the [`maud!`] and [`rsx!`] macros, followed by any items
+/// given, which are usually `pub use`s of the library's components and
+/// attribute traits.
+///
+/// Downstream crates can then `use the_library::prelude::*;` and use
+/// everything in it. They still need `hypertext` as a dependency, as the
+/// macros refer to it by name. Elements are looked up by whichever
+/// `html_elements` is in scope, so glob-importing `hypertext::*` alongside the
+/// prelude makes the name ambiguous, and the library's elements may not be
+/// found. Import the prelude on its own, or import its `html_elements`
+/// explicitly, which takes precedence over both globs.
+///
+/// Example:
+/// ```rust
+/// mod ui_kit {
+///     use hypertext::{attributes, elements, maud, Renderable};
+///
+///     pub mod html_elements {
+///         pub use hypertext::html_elements::*;
+///
+///         hypertext::elements! {
+///             /// A status badge.
+///             ui_badge {
+///                 /// The colour of the badge.
+///                 tone
+///             }
+///         }
+///     }
+///
+///     attributes! {
+///         /// Attributes understood by every element of the kit.
+///         pub trait UiAttributes {
+///             /// The visual variant of an element.
+///             ui_variant
+///         }
+///     }
+///
+///     pub struct Badge<'a> {
+///         pub tone: &'a str,
+///         pub label: &'a str,
+///     }
+///
+///     impl Renderable for Badge<'_> {
+///         fn render_to(self, output: &mut String) {
+///             maud! { ui-badge tone=(self.tone) { (self.label) } }.render_to(output);
+///         }
+///     }
+///
+///     hypertext::component_prelude! {
+///         /// Everything needed to use the kit.
+///         pub mod prelude {
+///             elements = super::html_elements;
+///
+///             pub use super::{Badge, UiAttributes};
+///         }
+///     }
+/// }
+///
+/// use ui_kit::prelude::*;
+///
+/// assert_eq!(
+///     maud! {
+///         div ui-variant="card" {
+///             Badge tone="info" label="New";
+///         }
+///     }
+///     .render(),
+///     r#"<div ui-variant="card"><ui-badge tone="info">New</ui-badge></div>"#,
+/// );
+/// ```
+macro_rules! component_prelude {
+    {
+        $(#[$meta:meta])*
+        $vis:vis mod $name:ident {
+            elements = $elements:path;
+
+            $($item:item)*
+        }
+    } => {
+        $(#[$meta])*
+        $vis mod $name {
+            #[doc(no_inline)]
+            pub use $elements as html_elements;
+            #[doc(no_inline)]
+            pub use $crate::{maud, maud_move, rsx, rsx_move, GlobalAttributes, Renderable};
+
+            $($item)*
+        }
+    };
+    {
+        $(#[$meta:meta])*
+        $vis:vis mod $name:ident {
+            $($item:item)*
+        }
+    } => {
+        $crate::component_prelude! {
+            $(#[$meta])*
+            $vis mod $name {
+                elements = $crate::html_elements;
+
+                $($item)*
+            }
+        }
+    };
+}
+
+/// Tracks the `@separated_by` of a loop in [`maud!`], so that iterations
+/// skipped with `@continue` or `@break` don't leave stray separators.
+///
+/// The separator is rendered at the start of each iteration after the first
+/// one which wasn't skipped, and removed again if the iteration is cut short
+/// before rendering anything.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Separated {
+    /// Whether any iteration has finished without being skipped.
+    rendered: bool,
+    /// Where the separator and the body of the current iteration start.
+    current: Option<(usize, usize)>,
+}
+
+impl Separated {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rendered: false,
+            current: None,
+        }
+    }
+
+    /// Finishes the previous iteration and starts a new one, returning
+    /// whether the separator should be rendered before it.
+    #[inline]
+    pub const fn start_iteration(&mut self, output: &str) -> bool {
+        self.finish_iteration();
+        self.current = Some((output.len(), output.len()));
+
+        self.rendered
+    }
+
+    /// Marks the start of the current iteration's body, after its separator.
+    #[inline]
+    pub const fn start_body(&mut self, output: &str) {
+        if let Some((_, body)) = &mut self.current {
+            *body = output.len();
+        }
+    }
+
+    /// Finishes the current iteration, if there is one.
+    #[inline]
+    pub const fn finish_iteration(&mut self) {
+        if self.current.take().is_some() {
+            self.rendered = true;
+        }
+    }
+
+    /// Finishes the current iteration early, for `@continue` or `@break`,
+    /// removing its separator if it didn't render anything.
+    #[inline]
+    pub fn cut_iteration(&mut self, output: &mut String) {
+        if let Some((separator, body)) = self.current.take() {
+            if output.len() == body {
+                output.truncate(separator);
+            } else {
+                self.rendered = true;
+            }
+        }
+    }
+}
+
+/// Counts how deeply templates are nested while rendering, so a component
+/// cycle panics with a clear message instead of overflowing the stack.
+///
+/// [`maud!`] and [`rsx!`] create one of these at the start of every closure
+/// they generate, but only when `debug_assertions` are enabled, so release
+/// builds have no overhead. The depth is counted per thread, so without the
+/// `std` feature this does nothing.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct RenderDepthGuard(());
+
+impl RenderDepthGuard {
+    /// Enters a nested render, panicking if the maximum depth is exceeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this would exceed the depth set by `set_max_render_depth`.
+    #[inline]
+    #[must_use]
+    // this only does anything with `std`
+    #[cfg_attr(not(feature = "std"), allow(clippy::missing_const_for_fn))]
+    pub fn enter() -> Self {
+        #[cfg(feature = "std")]
+        std_support::enter_render();
+
+        Self(())
+    }
+}
+
+impl Drop for RenderDepthGuard {
+    #[inline]
+    fn drop(&mut self) {
+        #[cfg(feature = "std")]
+        std_support::exit_render();
+    }
+}
+
+/// A value rendered via its [`Display`] implementation.
+///
+/// This will handle escaping special characters for you.
+#[derive(Debug, Clone, Copy)]
+pub struct Displayed<T: Display>(pub T);
+
+impl<T: Display> Renderable for Displayed<T> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        struct Escaper<'a>(&'a mut String);
+
+        impl fmt::Write for Escaper<'_> {
+            #[inline]
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                crate::escape::text_to(s, self.0);
+                Ok(())
+            }
+        }
+
+        // ignore errors, as we are writing to a string
+        let _ = write!(Escaper(output), "{}", self.0);
+    }
+}
+
+/// A float rendered with a fixed number of decimal places.
+///
+/// This formats the value like `format!("{:.*}", decimals, value)`, which
+/// rounds to the nearest representable result. The output is always ASCII, so
+/// it never needs escaping.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, Fixed, GlobalAttributes, Renderable};
+///
+/// assert_eq!(
+///     maud! { span.price { "$" (Fixed(19.999, 2)) } }.render(),
+///     r#"<span class="price">$20.00</span>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Fixed<T>(pub T, pub usize);
+
+macro_rules! impl_fixed {
+    ($($ty:ty)*) => {
+        $(
+            impl Renderable for Fixed<$ty> {
+                #[inline]
+                fn render_to(self, output: &mut String) {
+                    // ignore errors, as we are writing to a string
+                    let _ = write!(output, "{:.*}", self.1, self.0);
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed!(f32 f64);
+
+/// A whole HTML document rendered as the value of an `iframe`'s `srcdoc`
+/// attribute.
+///
+/// The document is rendered to a temporary string first, and then escaped as
+/// an attribute value, so the browser gets back exactly the rendered HTML.
+/// Splicing the document directly (`srcdoc=(page)`) would be wrong, as its
+/// markup is pushed as-is, so the first `"` in it would end the attribute.
+/// Splicing `page.render()` doesn't compile, as [`Rendered`] is not
+/// [`Renderable`].
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, GlobalAttributes, Renderable, SrcDoc};
+///
+/// let page = maud! { p title="a \"quote\"" { "Tom & Jerry" } };
+///
+/// assert_eq!(
+///     maud! { iframe srcdoc=(SrcDoc(page)) {} }.render(),
+///     r#"<iframe srcdoc="&lt;p title=&quot;a &amp;quot;quote&amp;quot;&quot;&gt;Tom &amp;amp; Jerry&lt;/p&gt;"></iframe>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SrcDoc<R: Renderable>(pub R);
+
+impl<R: Renderable> Renderable for SrcDoc<R> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        crate::escape::attribute_to(&self.0.render().into_inner(), output);
+    }
+}
+
+/// Items rendered with a separator between each of them.
+///
+/// This is returned by [`intersperse`].
+#[derive(Debug, Clone, Copy)]
+pub struct Intersperse<I, S> {
+    items: I,
+    separator: S,
+}
+
+/// Renders each of `items` with `separator` between them, but not before the
+/// first or after the last.
+///
+/// As rendering consumes the separator, a clone of it is rendered each time,
+/// so it is rendered one less time than there are items. [`maud!`] and
+/// [`rsx!`] output can be cloned as long as everything it captures can be.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, intersperse, rsx, rsx_move, Renderable};
+///
+/// let items = ["a", "<b>", "c"].map(|item| rsx_move! { <li>{item}</li> });
+/// let n = 2;
+///
+/// assert_eq!(
+///     rsx! { <ul>{intersperse(items, rsx! { <hr data-n={n}/> })}</ul> }.render(),
+///     r#"<ul><li>a</li><hr data-n="2"><li>&lt;b&gt;</li><hr data-n="2"><li>c</li></ul>"#,
+/// );
+/// ```
+#[inline]
+pub fn intersperse<I, S>(items: I, separator: S) -> Intersperse<I::IntoIter, S>
+where
+    I: IntoIterator,
+    I::Item: Renderable,
+    S: Renderable + Clone,
+{
+    Intersperse {
+        items: items.into_iter(),
+        separator,
+    }
+}
+
+impl<I, S> Renderable for Intersperse<I, S>
+where
+    I: Iterator,
+    I::Item: Renderable,
+    S: Renderable + Clone,
+{
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let mut items = self.items;
+
+        if let Some(first) = items.next() {
+            first.render_to(output);
+
+            for item in items {
+                self.separator.clone().render_to(output);
+                item.render_to(output);
+            }
+        }
+    }
+}
+
+/// Renders each of `fragments` separately, returning their ids along with
+/// their rendered HTML.
+///
+/// This is useful for responses made of several independent parts, like
+/// [htmx](https://htmx.org) out-of-band swaps, which `htmx::render_oob` (with
+/// the `htmx` feature) renders directly. To render fragments of different
+/// types, box them as `Box<dyn FnOnce(&mut String)>`, which is
+/// [`Renderable`].
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud_move, render_fragment_list};
+///
+/// let fragments = render_fragment_list(
+///     [("title", "Cart"), ("total", "$3")].map(|(id, text)| (id, maud_move! { h1 { (text) } })),
+/// );
+///
+/// assert_eq!(fragments[0].0, "title");
+/// assert_eq!(fragments[0].1, "<h1>Cart</h1>");
+/// assert_eq!(fragments[1].1, "<h1>$3</h1>");
+/// ```
+#[inline]
+pub fn render_fragment_list<I, K, R>(fragments: I) -> Vec<(String, Rendered<String>)>
+where
+    I: IntoIterator<Item = (K, R)>,
+    K: Into<String>,
+    R: Renderable,
+{
+    fragments
+        .into_iter()
+        .map(|(id, fragment)| (id.into(), fragment.render()))
+        .collect()
+}
+
+/// A [`Renderable`] value formatted via its rendered HTML.
+///
+/// This is returned by [`Renderable::as_display`], and is useful for passing
+/// HTML to APIs which accept any [`Display`] value, like [`format!`]. The
+/// output is exactly the same as [`Renderable::render`].
+///
+/// As rendering consumes the value, formatting renders a clone of it, so the
+/// value must implement [`Clone`] (which [`maud!`] and [`rsx!`] output does
+/// as long as everything it captures does). Since rendering always targets a
+/// [`String`], each format renders to a temporary string first.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayAdapter<'a, R: Renderable + Clone>(pub &'a R);
+
+impl<R: Renderable + Clone> Display for DisplayAdapter<'_, R> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+        self.0.clone().render_to(&mut output);
+        f.write_str(&output)
+    }
+}
+
+/// A value which calls a function with the output after it is rendered.
+///
+/// This is returned by [`Renderable::tap_output`].
+#[derive(Debug, Clone, Copy)]
+pub struct TapOutput<R, F>(R, F);
+
+impl<R: Renderable, F: FnOnce(&str)> Renderable for TapOutput<R, F> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.0.render_to(output);
+        (self.1)(output);
+    }
+}
+
+/// A value which may have been wrapped in other markup.
+///
+/// This is returned by [`Renderable::wrap_if`].
+#[derive(Debug, Clone, Copy)]
+pub enum WrapIf<T, R> {
+    /// The value, wrapped by the closure.
+    Wrapped(R),
+    /// The value, rendered as-is.
+    Bare(T),
+}
+
+impl<T: Renderable, R: Renderable> Renderable for WrapIf<T, R> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        match self {
+            Self::Wrapped(wrapped) => wrapped.render_to(output),
+            Self::Bare(bare) => bare.render_to(output),
+        }
+    }
+}
+
+/// Checks the props of a component at the start of its
+/// [`render_to`](Renderable::render_to), to catch invalid combinations during
+/// development.
+///
+/// By default, checks only run in debug builds (like [`debug_assert!`]), and
+/// panic when they fail. [`comment`](Self::comment) renders a visible HTML
+/// comment with the error instead, and [`always`](Self::always) also runs
+/// checks in release builds.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, PropsCheck, Renderable};
+///
+/// struct Progress {
+///     value: u32,
+///     max: u32,
+/// }
+///
+/// impl Progress {
+///     fn check(&self) -> Result<(), String> {
+///         if self.value <= self.max {
+///             Ok(())
+///         } else {
+///             Err(format!("value {} is greater than max {}", self.value, self.max))
+///         }
+///     }
+/// }
+///
+/// impl Renderable for Progress {
+///     fn render_to(self, output: &mut String) {
+///         PropsCheck::new().comment().run(&self, Self::check, output);
+///
+///         maud! { progress value=(self.value) max=(self.max) {} }.render_to(output);
+///     }
+/// }
+///
+/// assert_eq!(
+///     maud! { Progress value=5 max=10; }.render(),
+///     r#"<progress value="5" max="10"></progress>"#,
+/// );
+/// # #[cfg(debug_assertions)]
+/// assert_eq!(
+///     maud! { Progress value=11 max=10; }.render(),
+///     r#"<!-- invalid props: value 11 is greater than max 10 --><progress value="11" max="10"></progress>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[must_use]
+pub struct PropsCheck {
+    comment: bool,
+    always: bool,
+}
+
+impl PropsCheck {
+    /// Creates a check which panics on failure in debug builds.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            comment: false,
+            always: false,
+        }
+    }
+
+    /// Renders an HTML comment with the error instead of panicking.
+    #[inline]
+    pub const fn comment(self) -> Self {
+        Self {
+            comment: true,
+            ..self
+        }
+    }
+
+    /// Runs the check in release builds too.
+    #[inline]
+    pub const fn always(self) -> Self {
+        Self {
+            always: true,
+            ..self
+        }
+    }
+
+    /// Runs `check` on `props`, handling a failure as configured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the check fails, unless [`comment`](Self::comment) was set.
+    #[inline]
+    pub fn run<T, E: Display>(
+        self,
+        props: &T,
+        check: impl FnOnce(&T) -> Result<(), E>,
+        output: &mut String,
+    ) {
+        if !self.always && !cfg!(debug_assertions) {
+            return;
+        }
+
+        if let Err(err) = check(props) {
+            assert!(self.comment, "invalid props: {err}");
+
+            output.push_str("<!-- invalid props: ");
+            // escaping `>` means the error can't end the comment early
+            Displayed(err).render_to(output);
+            output.push_str(" -->");
+        }
+    }
+}
+
+impl<F: FnOnce(&mut String)> Renderable for F {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self(output);
+    }
+}
+
+/// A template as a plain function pointer, which renders by pushing its
+/// HTML to the output.
+///
+/// Like any `FnOnce(&mut String)`, this is [`Renderable`], so it can be
+/// spliced into templates directly. Unlike closures, every template function
+/// has the same type, and can be compared and hashed, which makes it useful
+/// for registries of templates defined in different modules. Calling one is
+/// safe: it is only rendering deferred until the function is called, so the
+/// function is responsible for escaping like any other [`Renderable`].
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use hypertext::{html_elements, maud, Renderable, TemplateFn};
+///
+/// fn header(output: &mut String) {
+///     maud! { header { "Welcome" } }.render_to(output);
+/// }
+///
+/// let templates = HashMap::from([("header", header as TemplateFn)]);
+///
+/// assert_eq!(
+///     maud! { body { (templates["header"]) } }.render(),
+///     "<body><header>Welcome</header></body>",
+/// );
+/// ```
+pub type TemplateFn = fn(&mut String);
+
+/// A raw value that is rendered without escaping.
+///
+/// This is useful for rendering raw HTML, but should be used with caution
+/// as it can lead to XSS vulnerabilities if used incorrectly. If you are
+/// unsure, render the actual string instead, as its implementation will
+/// escape any special characters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Raw<T: AsRef<str>>(pub T);
+
+/// Only string literals and other `&'static str`s can be converted, as they
+/// are written by the programmer. Runtime strings must be wrapped explicitly
+/// with `Raw(...)`, so every unescaped value is visible at its use.
+impl From<&'static str> for Raw<&'static str> {
+    #[inline]
+    fn from(value: &'static str) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: AsRef<str>> Raw<T> {
+    /// Converts this value into a [`Rendered`] value.
+    ///
+    /// No escaping is performed, as the inner value is already assumed to be
+    /// valid HTML.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{Raw, Rendered};
+    ///
+    /// let greeting = Raw(String::from("<b>Hello!</b>")).rendered();
+    ///
+    /// assert_eq!(greeting, "<b>Hello!</b>");
+    /// ```
+    #[inline]
+    pub fn rendered(self) -> Rendered<T> {
+        Rendered(self.0)
+    }
+
+    /// Extracts the inner value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Gets a reference to the inner value.
+    ///
+    /// This can be used in `const` items, so static fragments (like a set of
+    /// classes for an attribute value) can be inspected at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, GlobalAttributes, Raw, Renderable};
+    ///
+    /// const CLASSES: Raw<&str> = Raw("btn btn&#45;primary");
+    /// const LEN: usize = CLASSES.as_inner().len();
+    ///
+    /// assert_eq!(LEN, 19);
+    /// assert_eq!(
+    ///     maud! { button class=(CLASSES) { "Go" } }.render(),
+    ///     r#"<button class="btn btn&#45;primary">Go</button>"#,
+    /// );
+    /// ```
+    #[inline]
+    pub const fn as_inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<'a> Raw<&'a str> {
+    /// Converts this value into a [`Rendered`] value, like
+    /// [`rendered`](Self::rendered), but in `const` contexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{Raw, Rendered};
+    ///
+    /// const LOGO: Rendered<&str> = Raw("<b>Logo</b>").into_rendered();
+    ///
+    /// assert_eq!(LOGO, "<b>Logo</b>");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn into_rendered(self) -> Rendered<&'a str> {
+        Rendered(self.0)
+    }
+
+    /// Returns the length of the HTML in bytes.
+    ///
+    /// Like the other methods on `Raw<&str>`, this can be used in `const`
+    /// contexts, so statically assembled fragments can be inspected at compile
+    /// time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud_static, Raw};
+    ///
+    /// const BADGE: Raw<&str> = Raw(*maud_static! { span { "new" } }.as_inner());
+    /// const LEN: usize = BADGE.len();
+    ///
+    /// assert_eq!(LEN, 16);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the HTML is empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the HTML as bytes.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+/// Converts via [`Raw::rendered`], which moves the inner value instead of
+/// copying it into a new [`String`] like [`Renderable::render`] would.
+impl<T: AsRef<str>> From<Raw<T>> for Rendered<T> {
+    #[inline]
+    fn from(raw: Raw<T>) -> Self {
+        raw.rendered()
+    }
+}
+
+impl<T: AsRef<str>> Rendered<T> {
+    /// Converts this value into a [`Raw`] value, so it can be embedded into
+    /// another page.
+    ///
+    /// This is the reverse of [`Raw::rendered`]. The inner value is moved, not
+    /// copied, and no escaping is performed. As `Rendered` can be constructed
+    /// from any string, the caller is responsible for the contents being
+    /// trusted HTML, just like when constructing a [`Raw`] value directly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, maud_static, Renderable};
+    ///
+    /// let badge = maud_static! { span { "new" } }.into_raw();
+    /// let card = maud! { h2 { "Title" } }.render().into_raw();
+    ///
+    /// assert_eq!(
+    ///     maud! { div { (card) (badge) } }.render(),
+    ///     "<div><h2>Title</h2><span>new</span></div>",
+    /// );
+    /// ```
+    #[inline]
+    pub fn into_raw(self) -> Raw<T> {
+        Raw(self.0)
+    }
+}
+
+impl Rendered<String> {
+    /// Renders `renderable` to a string.
+    ///
+    /// This is the same as [`Renderable::render`], but can read better where
+    /// the type being constructed matters more than the value being rendered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, Rendered};
+    ///
+    /// let page = Rendered::from_renderable(maud! { p { "Hello!" } });
+    ///
+    /// assert_eq!(page, "<p>Hello!</p>");
+    /// ```
+    #[inline]
+    pub fn from_renderable(renderable: impl Renderable) -> Self {
+        renderable.render()
+    }
+}
+
+/// Renders a borrowed value via [`Rendered::from_renderable`], for types
+/// which are rendered by reference, like [`str`].
+impl<'a, R: ?Sized> From<&'a R> for Rendered<String>
+where
+    &'a R: Renderable,
+{
+    #[inline]
+    fn from(renderable: &'a R) -> Self {
+        Self::from_renderable(renderable)
+    }
+}
+
+/// Render HTML using [`maud`] syntax at compile time, with splices of
+/// constants.
+///
+/// This is a middle ground between [`maud!`] and [`maud_static!`]: splices
+/// (`(EXPR)`) are allowed, but each must be a constant integer, `&'static
+/// str` (which is escaped), or `Raw<&'static str>` (which is not), and control
+/// flow is not. Everything is concatenated during `const` evaluation, so the
+/// result is a [`Raw<&'static str>`](Raw) which can be used in `const` items.
+///
+/// This macro accepts the same options as [`maud!`].
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud_const, GlobalAttributes, Raw};
+///
+/// const COLS: usize = 3;
+/// const TITLE: &str = "Fish & Chips";
+///
+/// const GRID: Raw<&str> = maud_const! {
+///     div class=(COLS) { h2 { (TITLE) } }
+/// };
+///
+/// assert_eq!(
+///     GRID.into_inner(),
+///     r#"<div class="3"><h2>Fish &amp; Chips</h2></div>"#,
+/// );
+/// ```
+pub use hypertext_macros::maud_const;
+
+/// A part of a [`maud_const!`] template.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum ConstPart {
+    /// HTML which is pushed as-is.
+    Static(&'static str),
+    /// Text which is escaped.
+    Text(&'static str),
+    Signed(i128),
+    Unsigned(u128),
+}
+
+impl ConstPart {
+    /// The length of all of the parts when concatenated.
+    #[inline]
+    #[must_use]
+    pub const fn len(parts: &[Self]) -> usize {
+        let mut len = 0;
+        let mut i = 0;
+
+        while i < parts.len() {
+            len += parts[i].part_len();
+            i += 1;
+        }
+
+        len
+    }
+
+    const fn part_len(&self) -> usize {
+        match *self {
+            Self::Static(s) => s.len(),
+            Self::Text(s) => {
+                let bytes = s.as_bytes();
+                let mut len = 0;
+                let mut i = 0;
+
+                while i < bytes.len() {
+                    len += match crate::escape::escape_byte(bytes[i]) {
+                        Some(escaped) => escaped.len(),
+                        None => 1,
+                    };
+                    i += 1;
+                }
+
+                len
+            }
+            Self::Signed(n) => (n < 0) as usize + digits(n.unsigned_abs()),
+            Self::Unsigned(n) => digits(n),
+        }
+    }
+
+    /// Concatenates all of the parts, which must be exactly `N` bytes long.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not the [`len`](Self::len) of the parts.
+    #[inline]
+    #[must_use]
+    pub const fn concat<const N: usize>(parts: &[Self]) -> [u8; N] {
+        let mut buf = [0; N];
+        let mut pos = 0;
+        let mut i = 0;
+
+        while i < parts.len() {
+            match parts[i] {
+                Self::Static(s) => pos = write(&mut buf, pos, s.as_bytes()),
+                Self::Text(s) => {
+                    let bytes = s.as_bytes();
+                    let mut j = 0;
+
+                    while j < bytes.len() {
+                        pos = match crate::escape::escape_byte(bytes[j]) {
+                            Some(escaped) => write(&mut buf, pos, escaped.as_bytes()),
+                            None => write(&mut buf, pos, &[bytes[j]]),
+                        };
+                        j += 1;
+                    }
+                }
+                Self::Signed(n) => {
+                    if n < 0 {
+                        pos = write(&mut buf, pos, b"-");
+                    }
+                    pos = write_digits(&mut buf, pos, n.unsigned_abs());
+                }
+                Self::Unsigned(n) => pos = write_digits(&mut buf, pos, n),
+            }
+            i += 1;
+        }
+
+        assert!(pos == N, "length of parts does not match the buffer");
+
+        buf
+    }
+
+    /// Converts concatenated parts to a string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytes are not valid UTF-8, which cannot happen for
+    /// bytes from [`concat`](Self::concat).
+    #[inline]
+    #[must_use]
+    pub const fn as_str(bytes: &[u8]) -> &str {
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(_) => panic!("parts are not valid UTF-8"),
+        }
+    }
+}
+
+const fn digits(mut n: u128) -> usize {
+    let mut digits = 1;
+
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+
+    digits
+}
+
+const fn write<const N: usize>(buf: &mut [u8; N], pos: usize, bytes: &[u8]) -> usize {
+    let mut i = 0;
+
+    while i < bytes.len() {
+        buf[pos + i] = bytes[i];
+        i += 1;
+    }
+
+    pos + bytes.len()
+}
+
+const fn write_digits<const N: usize>(buf: &mut [u8; N], pos: usize, mut n: u128) -> usize {
+    let len = digits(n);
+    let mut i = len;
+
+    while i > 0 {
+        i -= 1;
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            buf[pos + i] = b'0' + (n % 10) as u8;
+        }
+        n /= 10;
+    }
+
+    pos + len
+}
+
+/// A splice in a [`maud_const!`] template, converted to a [`ConstPart`]
+/// depending on its type.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct ConstSplice<T>(pub T);
+
+impl ConstSplice<&'static str> {
+    #[inline]
+    #[must_use]
+    pub const fn into_part(self) -> ConstPart {
+        ConstPart::Text(self.0)
+    }
+}
+
+impl ConstSplice<Raw<&'static str>> {
+    #[inline]
+    #[must_use]
+    pub const fn into_part(self) -> ConstPart {
+        ConstPart::Static(self.0 .0)
+    }
+}
+
+macro_rules! impl_const_splice_int {
+    ($variant:ident: $($ty:ty)*) => {
+        $(
+            impl ConstSplice<$ty> {
+                #[inline]
+                #[must_use]
+                #[allow(clippy::cast_lossless, clippy::cast_sign_loss)]
+                pub const fn into_part(self) -> ConstPart {
+                    ConstPart::$variant(self.0 as _)
+                }
+            }
+        )*
+    };
+}
+
+impl_const_splice_int!(Signed: i8 i16 i32 i64 i128 isize);
+impl_const_splice_int!(Unsigned: u8 u16 u32 u64 u128 usize);
+
+/// Wraps a string which needs no escaping in a [`Raw`], checking that it
+/// contains none of `&`, `<`, `>` or `"`.
+///
+/// Used in a `const` item, the check happens at compile time, so the string
+/// can be spliced into templates without any escaping at runtime.
+///
+/// # Panics
+///
+/// Panics if the string contains a character which must be escaped. In a
+/// `const` item, this is a compile error.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{assert_no_html, html_elements, maud, Raw, Renderable};
+///
+/// const TITLE: Raw<&str> = assert_no_html("My Site");
+///
+/// assert_eq!(
+///     maud! { title { (TITLE) } }.render(),
+///     "<title>My Site</title>"
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub const fn assert_no_html(s: &str) -> Raw<&str> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        assert!(
+            !matches!(bytes[i], b'&' | b'<' | b'>' | b'"'),
+            "string contains `&`, `<`, `>` or `\"`, which must be escaped"
+        );
+
+        i += 1;
+    }
+
+    Raw(s)
+}
+
+#[cfg(feature = "ammonia")]
+impl Raw<String> {
+    /// Sanitizes untrusted HTML, making it safe to render without escaping.
+    ///
+    /// This uses [`ammonia`]'s default settings, which remove anything that
+    /// could run scripts (such as `<script>` elements and `on*` attributes)
+    /// while keeping common formatting tags.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{Raw, Renderable};
+    ///
+    /// let comment = Raw::sanitize("<b>Hi!</b><script>alert(1)</script>");
+    ///
+    /// assert_eq!(comment.render(), "<b>Hi!</b>");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sanitize(html: &str) -> Self {
+        Self(ammonia::clean(html))
+    }
+}
+
+/// Markdown which is converted to HTML when rendered.
+///
+/// The conversion uses [`pulldown_cmark`], with tables, strikethrough and
+/// footnotes enabled. Text is escaped as usual, but raw HTML blocks and
+/// inline HTML in the Markdown are passed through as-is, so the source must
+/// be trusted. With the `ammonia` feature, untrusted Markdown can be rendered
+/// with [`Markdown::sanitize`] instead.
+///
+/// To convert Markdown at compile time, use [`markdown_static!`].
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, Markdown, Renderable};
+///
+/// let post = "# Hello\n\nUse `<br>` for line breaks.";
+///
+/// assert_eq!(
+///     maud! { article { (Markdown(post)) } }.render(),
+///     "<article><h1>Hello</h1>\n<p>Use <code>&lt;br&gt;</code> for line breaks.</p>\n</article>",
+/// );
+/// ```
+#[cfg(feature = "markdown")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Markdown<T: AsRef<str>>(pub T);
+
+#[cfg(feature = "markdown")]
+impl<T: AsRef<str>> Markdown<T> {
+    /// Converts the Markdown to HTML, then sanitizes it with
+    /// [`Raw::sanitize`], removing anything that could run scripts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{Markdown, Renderable};
+    ///
+    /// let comment = Markdown("**Hi!** <script>alert(1)</script>").sanitize();
+    ///
+    /// assert_eq!(comment.render(), "<p><strong>Hi!</strong> </p>\n");
+    /// ```
+    #[cfg(feature = "ammonia")]
+    #[must_use]
+    #[inline]
+    pub fn sanitize(self) -> Raw<String> {
+        Raw::sanitize(self.render().as_str())
+    }
+}
+
+#[cfg(feature = "markdown")]
+impl<T: AsRef<str>> Renderable for Markdown<T> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        use pulldown_cmark::{html, Options, Parser};
+
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES;
+
+        html::push_html(output, Parser::new_ext(self.0.as_ref(), options));
+    }
+}
+
+impl<T: AsRef<str>> Renderable for Raw<T> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        output.push_str(self.0.as_ref());
+    }
+}
+
+/// A set of attributes which can be spread onto an element.
+///
+/// In [`maud!`], `..(attrs)` can be written after an element's attributes
+/// to render every attribute in the set.
+///
+/// This trait is usually derived. Each field is rendered as an attribute
+/// named after the field (with `_` replaced by `-`), unless renamed with
+/// `#[attr(rename = "...")]`. Fields of type [`Option`] are omitted when they
+/// are [`None`]. A field's value is rendered as-is by default. With
+/// `#[attr(display)]` it is rendered via its [`Display`] impl, with
+/// `#[attr(debug)]` via its [`Debug`](core::fmt::Debug) impl, and with
+/// `#[attr(with = path)]` it is passed to the function `path`, whose result
+/// is rendered. Either way, the value is escaped. Adding `#[attr(check =
+/// element)]` to the struct checks every attribute name against that element,
+/// just like the macros do (so `html_elements` and any attribute traits must be
+/// in scope).
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, AttributeSet, GlobalAttributes, Renderable};
+///
+/// #[derive(AttributeSet)]
+/// #[attr(check = a)]
+/// struct LinkAttrs {
+///     href: String,
+///     #[attr(rename = "target")]
+///     target_: Option<&'static str>,
+/// }
+///
+/// let attrs = LinkAttrs {
+///     href: "/about".into(),
+///     target_: None,
+/// };
+///
+/// assert_eq!(
+///     maud! { a.link ..(attrs) { "About" } }.render(),
+///     r#"<a class="link" href="/about">About</a>"#,
+/// );
+/// ```
+pub trait AttributeSet {
+    /// Renders each attribute in this set to the given string, each preceded
+    /// by a space.
+    ///
+    /// The implementation must handle escaping any special characters.
+    fn render_attributes(self, output: &mut String);
+}
+
+/// An [`AttributeSet`](trait@AttributeSet) of name-value pairs only known at
+/// runtime, such as a map of attributes loaded from configuration.
+///
+/// Values are escaped, and pairs whose name is not a valid attribute name
+/// (empty, or containing whitespace, control characters, quotes, `<`, `>`,
+/// `/` or `=`) are skipped, as they could otherwise break out of the tag.
+/// The names are not checked against the element.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use hypertext::{html_elements, maud, Attributes, Renderable};
+///
+/// let attrs = BTreeMap::from([
+///     ("data-theme", "dark"),
+///     ("title", "\"quoted\""),
+///     ("onclick=\"alert(1)\"", "skipped"),
+/// ]);
+///
+/// assert_eq!(
+///     maud! { div ..(Attributes(&attrs)) {} }.render(),
+///     r#"<div data-theme="dark" title="&quot;quoted&quot;"></div>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attributes<I>(pub I);
+
+impl<I, K, V> AttributeSet for Attributes<I>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    #[inline]
+    fn render_attributes(self, output: &mut String) {
+        for (name, value) in self.0 {
+            let name = name.as_ref();
+
+            if !is_attribute_name(name) {
+                continue;
+            }
+
+            output.push(' ');
+            output.push_str(name);
+            output.push_str("=\"");
+            crate::escape::attribute_to(value.as_ref(), output);
+            output.push('"');
+        }
+    }
+}
+
+/// An [`AttributeSet`](trait@AttributeSet) of `data-*` attributes from
+/// key-value pairs, such as a map of hooks for JavaScript.
+///
+/// Each pair renders as `data-{key}="{value}"`, with the value escaped, in the
+/// order they are iterated, so pass an ordered map (like a
+/// [`BTreeMap`](alloc::collections::BTreeMap)) for deterministic output.
+///
+/// Keys may only contain lowercase ASCII letters, digits, and hyphens, so
+/// that they map to `dataset` properties as expected. Pairs with any other
+/// key are skipped, and fail a debug assertion.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use hypertext::{html_elements, maud, DataAttrs, Renderable};
+///
+/// let hooks = BTreeMap::from([("controller", "menu"), ("menu-open", "false")]);
+///
+/// assert_eq!(
+///     maud! { nav ..(DataAttrs(&hooks)) {} }.render(),
+///     r#"<nav data-controller="menu" data-menu-open="false"></nav>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataAttrs<I>(pub I);
+
+impl<I, K, V> AttributeSet for DataAttrs<I>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    #[inline]
+    fn render_attributes(self, output: &mut String) {
+        for (key, value) in self.0 {
+            let key = key.as_ref();
+
+            let valid = !key.is_empty()
+                && key
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-');
+
+            debug_assert!(valid, "invalid `data-*` attribute key `{key}`");
+
+            if !valid {
+                continue;
+            }
+
+            output.push_str(" data-");
+            output.push_str(key);
+            output.push_str("=\"");
+            crate::escape::attribute_to(value.as_ref(), output);
+            output.push('"');
+        }
+    }
+}
+
+/// Whether `name` can be rendered as an attribute name without escaping.
+fn is_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '<' | '>' | '/' | '=')
+        })
+}
+
+/// Derive [`Renderable`] for an enum of attribute values.
+///
+/// Each variant must be a unit variant, and renders as its name in
+/// `kebab-case`, unless given with `#[attribute("...")]`. The values are
+/// escaped at compile time, so rendering is a single `push_str`. This is also
+/// derived for references to the enum.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, AttributeRenderable, Renderable};
+///
+/// #[derive(AttributeRenderable)]
+/// enum Size {
+///     #[attribute("sm")]
+///     Small,
+///     #[attribute("md")]
+///     Medium,
+///     ExtraLarge,
+/// }
+///
+/// assert_eq!(
+///     maud! { div data-size=(Size::Medium) data-max=(Size::ExtraLarge) {} }.render(),
+///     r#"<div data-size="md" data-max="extra-large"></div>"#,
+/// );
+/// ```
+pub use hypertext_macros::AttributeRenderable;
+/// Derive [`AttributeSet`](trait@AttributeSet) for a struct.
+pub use hypertext_macros::AttributeSet;
+
+/// An extension trait for [`IntoIterator`]s that can be rendered.
+pub trait RenderIterator: IntoIterator
+where
+    Self: Sized,
+    Self::Item: Renderable,
+{
+    /// Renders each item in this iterator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, maud_move, GlobalAttributes, Renderable, RenderIterator};
+    ///
+    /// let items = ["milks", "eggs", "bread"];
+    ///
+    /// assert_eq!(
+    ///     maud! {
+    ///         ul #shopping-list {
+    ///             (items
+    ///                 .iter()
+    ///                 .map(|&item| maud_move! { li { (item) } })
+    ///                 .render_all())
+    ///         }
+    ///     }.render(),
+    ///     r#"<ul id="shopping-list"><li>milks</li><li>eggs</li><li>bread</li></ul>"#
+    /// );
+    #[inline]
+    fn render_all(self) -> impl FnOnce(&mut String) {
+        |output| {
+            self.into_iter().for_each(|item| {
+                item.render_to(output);
+            });
+        }
+    }
+}
+
+impl<I: IntoIterator> RenderIterator for I where Self::Item: Renderable {}
+
+/// An extension trait for pushing HTML onto the [`String`] passed to
+/// [`Renderable::render_to`], which is useful in manual implementations.
+///
+/// Each method returns the string again, so pushes can be chained.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, RenderBuffer, Renderable};
+///
+/// struct Greeting<'a>(&'a str);
+///
+/// impl Renderable for Greeting<'_> {
+///     fn render_to(self, output: &mut String) {
+///         output
+///             .push_renderable(maud! { b { "Hi" } })
+///             .push_escaped(", ")
+///             .push_renderable(self.0)
+///             .push_escaped("!");
+///     }
+/// }
+///
+/// assert_eq!(Greeting("<Alice>").render(), "<b>Hi</b>, &lt;Alice&gt;!");
+/// ```
+pub trait RenderBuffer {
+    /// Renders `value` onto the end of this string.
+    fn push_renderable(&mut self, value: impl Renderable) -> &mut Self;
+
+    /// Escapes `s` as text, and pushes it onto the end of this string.
+    fn push_escaped(&mut self, s: &str) -> &mut Self;
+
+    /// Pushes already-rendered HTML onto the end of this string, without
+    /// escaping it again.
+    fn extend_from(&mut self, rendered: Rendered<impl AsRef<str>>) -> &mut Self;
+}
+
+/// A growable sequence of rendered fragments, which is a nameable type for
+/// storing markup built up dynamically.
+///
+/// Each fragment is rendered when it is added, and stored as a [`Raw<String>`].
+/// Boxing the unrendered values instead isn't possible in general, as
+/// [`Renderable::render_to`] consumes the value, and the closures [`maud!`]
+/// and [`rsx!`] return borrow from their environment. Rendering eagerly keeps
+/// [`Fragments`] free of lifetimes, and [`Clone`], [`Send`] and [`Sync`], at
+/// the cost of one allocation per fragment.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, maud_move, Fragments, Renderable};
+///
+/// let mut items = Fragments::new();
+///
+/// for name in ["Alice", "Bob"] {
+///     items.push(maud_move! { li { (name) } });
+/// }
+///
+/// assert_eq!(items.len(), 2);
+/// assert_eq!(items[1].0, "<li>Bob</li>");
+/// assert_eq!(
+///     maud! { ul { (&items) } }.render(),
+///     "<ul><li>Alice</li><li>Bob</li></ul>",
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Fragments(Vec<Raw<String>>);
+
+impl Fragments {
+    /// Creates an empty sequence.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Renders `value` and adds it to the end of the sequence.
+    #[inline]
+    pub fn push(&mut self, value: impl Renderable) {
+        self.0.push(Raw(value.render().into_inner()));
+    }
+
+    /// Returns the number of fragments.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether there are no fragments.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns an iterator over the rendered fragments.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, Raw<String>> {
+        self.0.iter()
+    }
+}
+
+impl<R: Renderable> Extend<R> for Fragments {
+    #[inline]
+    fn extend<I: IntoIterator<Item = R>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<R: Renderable> FromIterator<R> for Fragments {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = R>>(iter: I) -> Self {
+        let mut fragments = Self::new();
+        fragments.extend(iter);
+        fragments
+    }
+}
+
+impl IntoIterator for Fragments {
+    type IntoIter = alloc::vec::IntoIter<Raw<String>>;
+    type Item = Raw<String>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Fragments {
+    type IntoIter = core::slice::Iter<'a, Raw<String>>;
+    type Item = &'a Raw<String>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl core::ops::Index<usize> for Fragments {
+    type Output = Raw<String>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl Renderable for Fragments {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        (&self).render_to(output);
+    }
+}
+
+impl Renderable for &Fragments {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        for fragment in self {
+            output.push_str(&fragment.0);
+        }
+    }
+}
+
+impl Renderable for &mut Fragments {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        (&*self).render_to(output);
+    }
+}
+
+impl RenderBuffer for String {
+    #[inline]
+    fn push_renderable(&mut self, value: impl Renderable) -> &mut Self {
+        value.render_to(self);
+        self
+    }
+
+    #[inline]
+    fn push_escaped(&mut self, s: &str) -> &mut Self {
+        crate::escape::text_to(s, self);
+        self
+    }
+
+    #[inline]
+    fn extend_from(&mut self, rendered: Rendered<impl AsRef<str>>) -> &mut Self {
+        self.push_str(rendered.as_str());
+        self
+    }
+}
+
+/// A value which renders nothing.
+///
+/// This is useful as the default for a generic [`Renderable`] field, so that
+/// a component can be constructed with `..Default::default()`. `()` also
+/// renders nothing, and can be used in the same way.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, maud, EmptyRenderable, Renderable};
+///
+/// #[derive(Default)]
+/// struct Card<R: Renderable = EmptyRenderable> {
+///     title: String,
+///     children: R,
+/// }
+///
+/// impl<R: Renderable> Renderable for Card<R> {
+///     fn render_to(self, output: &mut String) {
+///         maud! { div { h2 { (self.title) } (self.children) } }.render_to(output);
+///     }
+/// }
+///
+/// let card: Card = Card {
+///     title: "Hello".into(),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(card.render(), "<div><h2>Hello</h2></div>");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EmptyRenderable;
+
+impl Renderable for EmptyRenderable {
+    #[inline]
+    fn render_to(self, _: &mut String) {}
+}
+
+impl Renderable for () {
+    #[inline]
+    fn render_to(self, _: &mut String) {}
+}
+
+impl Renderable for char {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.encode_utf8(&mut [0; 4]).render_to(output);
+    }
+}
+
+impl Renderable for &str {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        crate::escape::text_to(self, output);
+    }
+}
+
+impl Renderable for &String {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.as_str().render_to(output);
+    }
+}
+
+impl Renderable for &mut str {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        (&*self).render_to(output);
+    }
+}
+
+impl Renderable for &mut String {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.as_str().render_to(output);
+    }
+}
+
+impl Renderable for String {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.as_str().render_to(output);
+    }
+}
+
+impl Renderable for Cow<'_, str> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.as_ref().render_to(output);
+    }
+}
+
+impl Renderable for bool {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        if self {
+            output.push_str("true");
+        } else {
+            output.push_str("false");
+        }
+    }
+}
+
+macro_rules! render_via_itoa {
+    ($($Ty:ty)*) => {
+        $(
+            impl Renderable for $Ty {
+                #[inline]
+                fn render_to(self, output: &mut String) {
+                    output.push_str(itoa::Buffer::new().format(self));
+                }
+            }
+        )*
+    };
+}
+
+render_via_itoa! {
+    i8 i16 i32 i64 i128 isize
+    u8 u16 u32 u64 u128 usize
+}
+
+macro_rules! render_via_ryu {
+    ($($Ty:ty)*) => {
+        $(
+            impl Renderable for $Ty {
+                #[inline]
+                fn render_to(self, output: &mut String) {
+                    output.push_str(ryu::Buffer::new().format(self));
+                }
+            }
+        )*
+    };
+}
+
+render_via_ryu! {
+    f32 f64
+}
+
+impl<T: Renderable> Renderable for Option<T> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        if let Some(value) = self {
+            value.render_to(output);
+        }
+    }
+}
+
+/// Renders every item in order, like [`RenderIterator::render_all`].
+impl<T: Renderable> Renderable for Vec<T> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        for item in self {
+            item.render_to(output);
+        }
     }
 }
 
@@ -335,3 +2411,353 @@ where
         (&*self).render_to(output);
     }
 }
+
+#[cfg(feature = "std")]
+pub use std_support::{render_parallel, set_max_render_depth};
+
+#[cfg(feature = "std")]
+mod std_support {
+    extern crate std;
+
+    use core::{
+        cell::Cell,
+        num::NonZeroUsize,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+    use std::{
+        panic,
+        path::{Path, PathBuf},
+        thread, thread_local,
+        vec::Vec,
+    };
+
+    use super::{Displayed, Renderable, String};
+    use crate::Rendered;
+
+    /// The default for [`set_max_render_depth`].
+    const DEFAULT_MAX_RENDER_DEPTH: usize = 512;
+
+    static MAX_RENDER_DEPTH: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_RENDER_DEPTH);
+
+    thread_local! {
+        static RENDER_DEPTH: Cell<usize> = const { Cell::new(0) };
+    }
+
+    /// Sets how deeply [`maud!`](crate::maud) and [`rsx!`](crate::rsx)
+    /// templates may be nested while rendering on any thread, which defaults
+    /// to 512.
+    ///
+    /// In builds with `debug_assertions`, rendering deeper than this panics
+    /// with a message pointing at a possible component cycle (like a component
+    /// which renders itself), instead of overflowing the stack. Release builds
+    /// don't count the depth at all.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// use hypertext::{html_elements, maud_move, set_max_render_depth, Renderable};
+    ///
+    /// // never stops recursing
+    /// fn tree(depth: usize) -> impl Renderable {
+    ///     maud_move! { ul { li { (depth) } (tree(depth + 1)) } }
+    /// }
+    ///
+    /// set_max_render_depth(16);
+    ///
+    /// // panics with "hypertext: render depth exceeded 16 — possible component cycle"
+    /// tree(0).render();
+    /// ```
+    #[inline]
+    pub fn set_max_render_depth(depth: usize) {
+        MAX_RENDER_DEPTH.store(depth, Ordering::Relaxed);
+    }
+
+    pub(super) fn enter_render() {
+        let depth = RENDER_DEPTH.get() + 1;
+        let max = MAX_RENDER_DEPTH.load(Ordering::Relaxed);
+
+        assert!(
+            depth <= max,
+            "hypertext: render depth exceeded {max} — possible component cycle"
+        );
+
+        RENDER_DEPTH.set(depth);
+    }
+
+    pub(super) fn exit_render() {
+        RENDER_DEPTH.set(RENDER_DEPTH.get().saturating_sub(1));
+    }
+
+    /// The thread-local state of a render, which [`render_parallel`] installs
+    /// on its threads.
+    #[derive(Clone)]
+    struct Scopes {
+        depth: usize,
+        #[cfg(feature = "theme")]
+        theme: Option<crate::theme::Theme>,
+        #[cfg(feature = "csp")]
+        nonce: Option<crate::csp::Nonce>,
+    }
+
+    impl Scopes {
+        fn current() -> Self {
+            Self {
+                depth: RENDER_DEPTH.get(),
+                #[cfg(feature = "theme")]
+                theme: crate::theme::current(),
+                #[cfg(feature = "csp")]
+                nonce: crate::csp::current(),
+            }
+        }
+
+        fn enter<T>(self, f: impl FnOnce() -> T) -> T {
+            let Self {
+                depth,
+                #[cfg(feature = "theme")]
+                theme,
+                #[cfg(feature = "csp")]
+                nonce,
+            } = self;
+
+            RENDER_DEPTH.set(depth);
+
+            #[cfg(feature = "theme")]
+            let f = move || match &theme {
+                Some(theme) => crate::theme::scoped(theme, f),
+                None => f(),
+            };
+
+            #[cfg(feature = "csp")]
+            let f = move || match nonce {
+                Some(nonce) => crate::csp::scoped(nonce, f),
+                None => f(),
+            };
+
+            f()
+        }
+    }
+
+    /// Renders the items on multiple threads, returning the results in the
+    /// same order as `items`.
+    ///
+    /// The items are split into one contiguous chunk per available thread (as
+    /// given by [`thread::available_parallelism`]), and each chunk is rendered
+    /// sequentially on a scoped thread, so this is only worth it for large,
+    /// independent sections of a page. Join the results with
+    /// [`concat`](slice::concat) (via [`Rendered::into_inner`]) to get the
+    /// same HTML as rendering the items sequentially.
+    ///
+    /// The innermost [`Theme`](crate::theme::Theme) and
+    /// [`Nonce`](crate::csp::Nonce) installed on the calling thread, along
+    /// with its render depth, are installed on every thread. While a
+    /// [`FragmentRegistry`](crate::htmx::FragmentRegistry) is rendering, the
+    /// items are instead rendered sequentially on the calling thread, so
+    /// their fragments are still registered.
+    ///
+    /// # Panics
+    ///
+    /// If rendering any item panics, the panic is propagated once all threads
+    /// have finished.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud_move, render_parallel, Renderable};
+    ///
+    /// let sections = (1..=3)
+    ///     .map(|i| maud_move! { section { "Section " (i) } })
+    ///     .collect();
+    ///
+    /// let html = render_parallel(sections)
+    ///     .into_iter()
+    ///     .map(|rendered| rendered.into_inner())
+    ///     .collect::<String>();
+    ///
+    /// assert_eq!(
+    ///     html,
+    ///     "<section>Section 1</section><section>Section 2</section><section>Section 3</section>",
+    /// );
+    /// ```
+    #[inline]
+    pub fn render_parallel<R: Renderable + Send>(items: Vec<R>) -> Vec<Rendered<String>> {
+        let threads = thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(items.len());
+
+        #[cfg(feature = "htmx")]
+        let threads = if crate::htmx::is_registering() {
+            1
+        } else {
+            threads
+        };
+
+        if threads <= 1 {
+            return items.into_iter().map(Renderable::render).collect();
+        }
+
+        let scopes = Scopes::current();
+
+        let chunk_size = items.len().div_ceil(threads);
+        let mut items = items.into_iter();
+        let chunks = (0..threads)
+            .map(|_| items.by_ref().take(chunk_size).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        thread::scope(|scope| {
+            // every thread must be spawned before any is joined
+            #[allow(clippy::needless_collect)]
+            let handles = chunks
+                .into_iter()
+                .map(|chunk| {
+                    let scopes = scopes.clone();
+
+                    scope.spawn(move || {
+                        scopes.enter(|| {
+                            chunk
+                                .into_iter()
+                                .map(Renderable::render)
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|err| panic::resume_unwind(err))
+                })
+                .collect()
+        })
+    }
+
+    /// Renders the path via [`Path::display`], so any non-UTF-8 sequences are
+    /// replaced with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    impl Renderable for &Path {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            Displayed(self.display()).render_to(output);
+        }
+    }
+
+    /// Renders like `&Path`.
+    impl Renderable for &PathBuf {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            self.as_path().render_to(output);
+        }
+    }
+
+    /// Renders like `&Path`.
+    impl Renderable for PathBuf {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            self.as_path().render_to(output);
+        }
+    }
+}
+
+#[cfg(feature = "humantime")]
+mod humantime_support {
+    extern crate std;
+
+    use core::{fmt::Write, time::Duration};
+    use std::time::SystemTime;
+
+    use super::{Displayed, Renderable, String};
+
+    /// Renders the duration via [`humantime::format_duration`], as
+    /// space-separated units from largest to smallest, omitting zero units.
+    ///
+    /// For example, `Duration::from_secs(9420)` renders as `2h 37m`, and
+    /// `Duration::from_millis(32)` renders as `32ms`. A zero duration renders
+    /// as `0s`.
+    impl Renderable for Duration {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            Displayed(humantime::format_duration(self)).render_to(output);
+        }
+    }
+
+    /// Renders the duration via [`humantime::format_duration`], as
+    /// space-separated units from largest to smallest, omitting zero units.
+    impl Renderable for &Duration {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            (*self).render_to(output);
+        }
+    }
+
+    /// Renders the time as an RFC 3339 UTC timestamp with second precision,
+    /// e.g. `2018-02-14T00:28:07Z`, in the proleptic Gregorian calendar.
+    ///
+    /// This is the format expected by the `datetime` attribute of
+    /// [`time`](crate::html_elements::time) elements. Times before the Unix
+    /// epoch are rounded down to the second. RFC 3339 only covers the years
+    /// 0000 to 9999, so later years are written with more digits (which HTML
+    /// still accepts), and earlier years with a leading `-`, as in ISO 8601.
+    impl Renderable for SystemTime {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            let secs = match self.duration_since(Self::UNIX_EPOCH) {
+                Ok(since) => i128::from(since.as_secs()),
+                Err(err) => {
+                    let before = err.duration();
+
+                    -i128::from(before.as_secs()) - i128::from(before.subsec_nanos() > 0)
+                }
+            };
+
+            let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+            let secs_of_day = secs.rem_euclid(86_400);
+
+            if year < 0 {
+                output.push('-');
+            }
+
+            // ignore errors, as we are writing to a string
+            let _ = write!(
+                output,
+                "{:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+                year.abs(),
+                secs_of_day / 3600,
+                secs_of_day / 60 % 60,
+                secs_of_day % 60,
+            );
+        }
+    }
+
+    /// Renders the time as an RFC 3339 UTC timestamp with second precision,
+    /// e.g. `2018-02-14T00:28:07Z`.
+    impl Renderable for &SystemTime {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            (*self).render_to(output);
+        }
+    }
+
+    /// Converts days since the Unix epoch into a `(year, month, day)` date,
+    /// using Howard Hinnant's `civil_from_days` algorithm.
+    const fn civil_from_days(days: i128) -> (i128, i128, i128) {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        (year, month, day)
+    }
+}
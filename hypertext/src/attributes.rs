@@ -6,6 +6,63 @@ pub struct Attribute;
 #[derive(Debug, Clone, Copy)]
 pub struct AttributeNamespace;
 
+#[macro_export]
+/// Create a trait of custom attributes.
+///
+/// The trait extends [`GlobalAttributes`] and is implemented for every type
+/// implementing it, so bringing it into scope is enough to use its attributes
+/// on any element. Attributes followed by a colon (like `hx_on:`) are defined
+/// as an [`AttributeNamespace`], allowing `hx-on:click="..."`.
+///
+/// Example:
+/// ```rust
+/// use hypertext::{attributes, html_elements, maud, GlobalAttributes, Renderable};
+///
+/// attributes! {
+///     /// Attributes for [htmx](https://htmx.org).
+///     pub trait HtmxAttributes {
+///         /// Issues a `GET` request to the given URL.
+///         hx_get
+///         /// Handles an event.
+///         hx_on:
+///     }
+/// }
+///
+/// assert_eq!(
+///     maud! { div hx-get="/api" hx-on:click="alert()" {} }.render(),
+///     r#"<div hx-get="/api" hx-on:click="alert()"></div>"#,
+/// );
+/// ```
+macro_rules! attributes {
+    {
+        $(#[$trait_meta:meta])*
+        $vis:vis trait $trait:ident {
+            $($attrs:tt)*
+        }
+    } => {
+        $(#[$trait_meta])*
+        #[allow(non_upper_case_globals)]
+        $vis trait $trait: $crate::GlobalAttributes {
+            $crate::attributes!(@attrs $($attrs)*);
+        }
+
+        impl<T: $crate::GlobalAttributes> $trait for T {}
+    };
+    (@attrs) => {};
+    (@attrs $(#[$attr_meta:meta])* $attr:ident: $($rest:tt)*) => {
+        $(#[$attr_meta])*
+        const $attr: $crate::AttributeNamespace = $crate::AttributeNamespace;
+
+        $crate::attributes!(@attrs $($rest)*);
+    };
+    (@attrs $(#[$attr_meta:meta])* $attr:ident $($rest:tt)*) => {
+        $(#[$attr_meta])*
+        const $attr: $crate::Attribute = $crate::Attribute;
+
+        $crate::attributes!(@attrs $($rest)*);
+    };
+}
+
 /// Global HTML attributes.
 ///
 /// This trait must be in scope to use well-known attributes such as
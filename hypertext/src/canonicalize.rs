@@ -0,0 +1,186 @@
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+/// Elements whose contents are not parsed as HTML.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// Re-orders the attributes within each start tag of `html` alphabetically by
+/// name, so equal HTML from different sources can be compared or hashed.
+///
+/// The whitespace between attributes is normalized to a single space, and
+/// attributes with the same name keep their relative order. Everything else,
+/// including attribute values, text, comments and the contents of `<script>`
+/// and `<style>` elements, is copied as-is.
+///
+/// This uses a small, tolerant scanner rather than a full HTML5 parser, so
+/// malformed input is never rejected. Anything that can't be scanned as a tag
+/// is copied unchanged.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::canonicalize;
+///
+/// assert_eq!(
+///     canonicalize(r#"<a  title="x" href="/"  class="link">Home</a>"#),
+///     canonicalize(r#"<a class="link" href="/" title="x">Home</a>"#),
+/// );
+/// assert_eq!(
+///     canonicalize(r#"<input type="checkbox" checked id="c">"#),
+///     r#"<input checked id="c" type="checkbox">"#,
+/// );
+/// ```
+#[inline]
+#[must_use]
+pub fn canonicalize(html: &str) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if rest.starts_with("<!--") {
+            let end = rest[4..].find("-->").map_or(rest.len(), |len| 4 + len + 3);
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        }
+
+        let Some((tag, name, after)) = start_tag(rest) else {
+            // not a start tag, so copy the `<` and keep scanning after it
+            output.push('<');
+            rest = &rest[1..];
+            continue;
+        };
+
+        output.push_str(&tag);
+        rest = after;
+
+        if RAW_TEXT_ELEMENTS
+            .iter()
+            .any(|raw| raw.eq_ignore_ascii_case(name))
+        {
+            let end = raw_text_end(rest, name);
+            output.push_str(&rest[..end]);
+            rest = &rest[end..];
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Scans a start tag at the beginning of `html`, returning it with its
+/// attributes sorted, its name, and the input after it.
+fn start_tag(html: &str) -> Option<(String, &str, &str)> {
+    let bytes = html.as_bytes();
+
+    if !bytes.get(1).is_some_and(u8::is_ascii_alphabetic) {
+        return None;
+    }
+
+    let name_end = 1 + html[1..]
+        .find(|c: char| c.is_ascii_whitespace() || matches!(c, '/' | '>'))
+        .unwrap_or(html.len() - 1);
+    let name = &html[1..name_end];
+
+    let mut attrs = Vec::new();
+    let mut pos = name_end;
+
+    let self_closing = loop {
+        pos += html[pos..]
+            .find(|c: char| !c.is_ascii_whitespace())
+            .unwrap_or(html.len() - pos);
+
+        match bytes.get(pos)? {
+            b'>' => break false,
+            b'/' if bytes.get(pos + 1) == Some(&b'>') => {
+                pos += 1;
+                break true;
+            }
+            _ => {
+                // a stray character (like a lone `/`) is kept as an attribute
+                let len = attribute_len(&html[pos..])?.max(1);
+                let attr = &html[pos..pos + len];
+                let attr_name = attr.split('=').next().unwrap_or(attr).trim_end();
+
+                attrs.push((attr_name, attr));
+                pos += len;
+            }
+        }
+    };
+
+    attrs.sort_by_key(|&(attr_name, _)| attr_name);
+
+    let mut tag = String::with_capacity(pos + 1);
+    tag.push('<');
+    tag.push_str(name);
+
+    for (_, attr) in attrs {
+        tag.push(' ');
+        tag.push_str(attr);
+    }
+
+    tag.push_str(if self_closing { "/>" } else { ">" });
+
+    Some((tag, name, &html[pos + 1..]))
+}
+
+/// Returns the length of the attribute at the beginning of `html`, or [`None`]
+/// if its quoted value is never terminated.
+fn attribute_len(html: &str) -> Option<usize> {
+    let bytes = html.as_bytes();
+
+    let mut pos = html
+        .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+        .unwrap_or(html.len());
+    let name_end = pos;
+
+    pos += html[pos..]
+        .find(|c: char| !c.is_ascii_whitespace())
+        .unwrap_or(html.len() - pos);
+
+    if bytes.get(pos) != Some(&b'=') {
+        return Some(name_end);
+    }
+
+    pos += 1;
+    pos += html[pos..]
+        .find(|c: char| !c.is_ascii_whitespace())
+        .unwrap_or(html.len() - pos);
+
+    match bytes.get(pos) {
+        Some(&quote @ (b'"' | b'\'')) => {
+            let len = html[pos + 1..].find(char::from(quote))?;
+            Some(pos + len + 2)
+        }
+        _ => Some(
+            pos + html[pos..]
+                .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                .unwrap_or(html.len() - pos),
+        ),
+    }
+}
+
+/// Returns the offset of the closing tag of the raw text element `name` in
+/// `html`, or its length if there is none.
+fn raw_text_end(html: &str, name: &str) -> usize {
+    let mut pos = 0;
+
+    while let Some(len) = html[pos..].find("</") {
+        pos += len;
+
+        if html
+            .get(pos + 2..pos + 2 + name.len())
+            .is_some_and(|after| after.eq_ignore_ascii_case(name))
+        {
+            return pos;
+        }
+
+        pos += 2;
+    }
+
+    html.len()
+}
@@ -0,0 +1,9 @@
+//! Ready-made components for common HTML patterns.
+//!
+//! These are built with the crate's own macros, and can be rendered just like
+//! any other [`Renderable`](crate::Renderable).
+
+pub mod code;
+#[cfg(feature = "serde_json")]
+pub mod json;
+pub mod meta;
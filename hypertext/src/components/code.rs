@@ -0,0 +1,80 @@
+//! Components for showing source code.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use crate::{html_elements, maud, maud_move, GlobalAttributes, Renderable};
+
+/// A block of source code, shown literally in a `<pre><code>` element.
+///
+/// The source is escaped like any other text, so HTML in it (like `<div>`) is
+/// shown as written instead of being rendered. If [`language`](Self::language)
+/// is set, the `<code>` element gets the class `language-*`, which most
+/// syntax highlighters recognize.
+///
+/// With [`line_numbers`](Self::line_numbers) set, each line is wrapped in a
+/// `<span class="line" data-line="N">`, with the lines still separated by
+/// newlines. The numbers are meant to be shown with CSS (like `.line::before
+/// { content: attr(data-line) }`), so they aren't copied along with the code.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{components::code::SourceListing, Renderable};
+///
+/// let listing = SourceListing {
+///     source: "<p>Tom & Jerry</p>".into(),
+///     language: Some("html".into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     listing.render(),
+///     r#"<pre><code class="language-html">&lt;p&gt;Tom &amp; Jerry&lt;/p&gt;</code></pre>"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceListing {
+    /// The source code to show.
+    pub source: String,
+
+    /// The language of the source, such as `rust` or `html`.
+    pub language: Option<String>,
+
+    /// Whether to wrap each line in a numbered `<span>`.
+    pub line_numbers: bool,
+}
+
+impl Renderable for &SourceListing {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let class = self
+            .language
+            .as_ref()
+            .map(|language| maud_move! { "language-" (language) });
+
+        maud! {
+            pre {
+                code class=[class] {
+                    @if self.line_numbers {
+                        @for (i, line) in self.source.lines().enumerate() {
+                            @if i > 0 { "\n" }
+                            span.line data-line=(i + 1) { (line) }
+                        }
+                    } @else {
+                        (&self.source)
+                    }
+                }
+            }
+        }
+        .render_to(output);
+    }
+}
+
+impl Renderable for SourceListing {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        (&self).render_to(output);
+    }
+}
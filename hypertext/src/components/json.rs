@@ -0,0 +1,78 @@
+//! Components for showing JSON values.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use serde_json::Value;
+
+use crate::{html_elements, maud, Renderable};
+
+/// A JSON value rendered as human-readable HTML, for debugging and admin
+/// pages.
+///
+/// Objects are rendered as `<dl>`s of their keys and values, arrays as
+/// `<ol>`s, `null` as an em dash (`—`), and other values as text. Keys and
+/// strings are escaped like any other text.
+///
+/// Values nested deeper than [`MAX_DEPTH`](Self::MAX_DEPTH) are rendered as
+/// an ellipsis (`…`) instead, so pathologically nested input can't produce
+/// huge output or overflow the stack.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{components::json::JsonTable, Renderable};
+///
+/// let value = serde_json::json!({ "name": "<Alice>", "tags": ["a", null] });
+///
+/// assert_eq!(
+///     JsonTable(&value).render(),
+///     "<dl><dt>name</dt><dd>&lt;Alice&gt;</dd><dt>tags</dt><dd><ol><li>a</li><li>—</li></ol></dd></dl>",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct JsonTable<'a>(pub &'a Value);
+
+impl JsonTable<'_> {
+    /// How deeply arrays and objects are nested before being elided.
+    pub const MAX_DEPTH: usize = 16;
+}
+
+impl Renderable for JsonTable<'_> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        render_value(self.0, 0, output);
+    }
+}
+
+fn render_value(value: &Value, depth: usize, output: &mut String) {
+    let nested = |value| move |output: &mut String| render_value(value, depth + 1, output);
+
+    match value {
+        Value::Array(_) | Value::Object(_) if depth >= JsonTable::MAX_DEPTH => {
+            output.push('…');
+        }
+        Value::Object(map) => maud! {
+            dl {
+                @for (key, value) in map {
+                    dt { (key) }
+                    dd { (nested(value)) }
+                }
+            }
+        }
+        .render_to(output),
+        Value::Array(values) => maud! {
+            ol {
+                @for value in values {
+                    li { (nested(value)) }
+                }
+            }
+        }
+        .render_to(output),
+        Value::Null => output.push('—'),
+        Value::Bool(value) => value.render_to(output),
+        Value::Number(number) => crate::Displayed(number).render_to(output),
+        Value::String(value) => value.render_to(output),
+    }
+}
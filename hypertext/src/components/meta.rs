@@ -0,0 +1,186 @@
+//! Components for document metadata.
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use crate::{html_elements, maud, Attribute, GlobalAttributes, Renderable};
+
+#[allow(non_upper_case_globals)]
+trait MetaAttributes: GlobalAttributes {
+    const property: Attribute = Attribute;
+}
+
+impl<T: GlobalAttributes> MetaAttributes for T {}
+
+/// [OpenGraph](https://ogp.me) metadata.
+///
+/// Only the `<meta>` tags whose fields are set will be rendered.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{components::meta::OpenGraph, Renderable};
+///
+/// let og = OpenGraph {
+///     title: Some("Hello, world!".into()),
+///     type_: Some("website".into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     og.render(),
+///     r#"<meta property="og:title" content="Hello, world!"><meta property="og:type" content="website">"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OpenGraph {
+    /// The title of the object (`og:title`).
+    pub title: Option<String>,
+
+    /// A short description of the object (`og:description`).
+    pub description: Option<String>,
+
+    /// The canonical URL of the object (`og:url`).
+    pub url: Option<String>,
+
+    /// An image URL representing the object (`og:image`).
+    pub image: Option<String>,
+
+    /// The name of the overall site (`og:site_name`).
+    pub site_name: Option<String>,
+
+    /// The type of the object, such as `website` or `article` (`og:type`).
+    pub type_: Option<String>,
+}
+
+impl Renderable for &OpenGraph {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let properties = [
+            ("og:title", &self.title),
+            ("og:type", &self.type_),
+            ("og:url", &self.url),
+            ("og:image", &self.image),
+            ("og:description", &self.description),
+            ("og:site_name", &self.site_name),
+        ];
+
+        maud! {
+            @for (property, content) in properties {
+                @if let Some(content) = content {
+                    meta property=(property) content=(content);
+                }
+            }
+        }
+        .render_to(output);
+    }
+}
+
+impl Renderable for OpenGraph {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        (&self).render_to(output);
+    }
+}
+
+/// [Twitter card](https://developer.x.com/en/docs/x-for-websites/cards/overview/markup)
+/// metadata.
+///
+/// Only the `<meta>` tags whose fields are set will be rendered.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{components::meta::TwitterCard, Renderable};
+///
+/// let card = TwitterCard {
+///     card: Some("summary".into()),
+///     site: Some("@example".into()),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     card.render(),
+///     r#"<meta name="twitter:card" content="summary"><meta name="twitter:site" content="@example">"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TwitterCard {
+    /// The type of card, such as `summary` or `summary_large_image`
+    /// (`twitter:card`).
+    pub card: Option<String>,
+
+    /// The username of the website (`twitter:site`).
+    pub site: Option<String>,
+
+    /// The username of the content creator (`twitter:creator`).
+    pub creator: Option<String>,
+
+    /// The title of the content (`twitter:title`).
+    pub title: Option<String>,
+
+    /// A short description of the content (`twitter:description`).
+    pub description: Option<String>,
+
+    /// An image URL representing the content (`twitter:image`).
+    pub image: Option<String>,
+}
+
+impl Renderable for &TwitterCard {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let names = [
+            ("twitter:card", &self.card),
+            ("twitter:site", &self.site),
+            ("twitter:creator", &self.creator),
+            ("twitter:title", &self.title),
+            ("twitter:description", &self.description),
+            ("twitter:image", &self.image),
+        ];
+
+        maud! {
+            @for (name, content) in names {
+                @if let Some(content) = content {
+                    meta name=(name) content=(content);
+                }
+            }
+        }
+        .render_to(output);
+    }
+}
+
+impl Renderable for TwitterCard {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        (&self).render_to(output);
+    }
+}
+
+/// The standard `<link>` tags for a favicon.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{components::meta::Favicon, Renderable};
+///
+/// assert_eq!(
+///     Favicon("/favicon.png").render(),
+///     r#"<link rel="icon" href="/favicon.png"><link rel="apple-touch-icon" href="/favicon.png">"#,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Favicon<T: AsRef<str>>(pub T);
+
+impl<T: AsRef<str>> Renderable for Favicon<T> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let href = self.0.as_ref();
+
+        maud! {
+            link rel="icon" href=(href);
+            link rel="apple-touch-icon" href=(href);
+        }
+        .render_to(output);
+    }
+}
@@ -0,0 +1,136 @@
+//! Content Security Policy nonces resolved at render time.
+//!
+//! A [`Nonce`] is installed for the duration of a render with [`with_nonce`].
+//! Templates can then use [`CspNonce`] as the value of `nonce` attributes,
+//! which renders the currently installed nonce.
+//!
+//! # Example
+//!
+//! ```
+//! use hypertext::{
+//!     csp::{self, CspNonce, Nonce},
+//!     html_elements, maud, GlobalAttributes, Renderable,
+//! };
+//!
+//! let page = || maud! { script nonce=(CspNonce) { "init();" } };
+//!
+//! assert_eq!(
+//!     csp::with_nonce(Nonce::new("r4nd0m"), page()).render(),
+//!     r#"<script nonce="r4nd0m">init();</script>"#
+//! );
+//! ```
+
+extern crate std;
+
+use std::{cell::RefCell, string::String, thread_local, vec::Vec};
+
+use crate::Renderable;
+
+thread_local! {
+    static NONCES: RefCell<Vec<Nonce>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Gets the innermost installed nonce.
+pub(crate) fn current() -> Option<Nonce> {
+    NONCES.with_borrow(|nonces| nonces.last().cloned())
+}
+
+/// Installs `nonce` for the duration of `f`.
+pub(crate) fn scoped<T>(nonce: Nonce, f: impl FnOnce() -> T) -> T {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            NONCES.with_borrow_mut(Vec::pop);
+        }
+    }
+
+    NONCES.with_borrow_mut(|nonces| nonces.push(nonce));
+    let _guard = Guard;
+
+    f()
+}
+
+/// A Content Security Policy nonce.
+///
+/// This renders as its value, escaped like any other string. It should be
+/// freshly generated for every response, and also sent in the
+/// `Content-Security-Policy` header.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Nonce(pub String);
+
+impl Nonce {
+    /// Creates a nonce with the given value.
+    #[inline]
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Gets the value of this nonce.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Renderable for Nonce {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.0.render_to(output);
+    }
+}
+
+impl Renderable for &Nonce {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.as_str().render_to(output);
+    }
+}
+
+/// Installs `nonce` while rendering `renderable`.
+///
+/// Any [`CspNonce`]s rendered by `renderable` render this nonce. Nested calls
+/// shadow it until they return.
+#[inline]
+pub const fn with_nonce<R: Renderable>(nonce: Nonce, renderable: R) -> WithNonce<R> {
+    WithNonce { nonce, renderable }
+}
+
+/// A [`Renderable`] value rendered with a [`Nonce`] installed.
+///
+/// This is returned by [`with_nonce`].
+#[derive(Debug, Clone)]
+pub struct WithNonce<R> {
+    nonce: Nonce,
+    renderable: R,
+}
+
+impl<R: Renderable> Renderable for WithNonce<R> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        scoped(self.nonce, || self.renderable.render_to(output));
+    }
+}
+
+/// A placeholder for the currently installed [`Nonce`].
+///
+/// If no nonce is installed, this renders nothing and logs a debug message,
+/// so the browser will block the element instead of it silently running
+/// without a valid nonce.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CspNonce;
+
+impl Renderable for CspNonce {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        NONCES.with_borrow(|nonces| {
+            if let Some(nonce) = nonces.last() {
+                nonce.render_to(output);
+            } else {
+                log::debug!("`CspNonce` rendered without a nonce installed");
+            }
+        });
+    }
+}
@@ -0,0 +1,253 @@
+//! The escaping used when rendering, for building HTML outside of templates.
+//!
+//! These are exactly what [`maud!`](crate::maud) and [`rsx!`](crate::rsx) use
+//! for literals and dynamic values, so strings escaped here are guaranteed to
+//! match template output. Every context uses the same escaping (`&`, `<`, `>`
+//! and `"`), so text and attribute values are interchangeable, but using the
+//! matching function documents intent.
+//!
+//! # Example
+//!
+//! ```
+//! use hypertext::{escape, html_elements, maud, Raw, Renderable};
+//!
+//! let name = "<Alice>";
+//! let message = format!("<b>{}</b> joined", escape::text(name));
+//!
+//! assert_eq!(
+//!     maud! { p { (Raw(&message)) } }.render(),
+//!     maud! { p { b { (name) } " joined" } }.render(),
+//! );
+//! ```
+extern crate alloc;
+
+use alloc::{borrow::Cow, string::String};
+use core::fmt::Write;
+
+/// Escapes a string for use as text content.
+///
+/// Returns [`Cow::Borrowed`] if nothing needed escaping.
+#[inline]
+#[must_use]
+pub fn text(s: &str) -> Cow<'_, str> {
+    html_escape::encode_double_quoted_attribute(s)
+}
+
+/// Escapes a string for use as text content, appending it to `output`.
+#[inline]
+pub fn text_to(s: &str, output: &mut String) {
+    html_escape::encode_double_quoted_attribute_to_string(s, output);
+}
+
+/// Escapes a string for use as a (double-quoted) attribute value.
+///
+/// Returns [`Cow::Borrowed`] if nothing needed escaping.
+#[inline]
+#[must_use]
+pub fn attribute(s: &str) -> Cow<'_, str> {
+    html_escape::encode_double_quoted_attribute(s)
+}
+
+/// Escapes a string for use as a (double-quoted) attribute value, appending
+/// it to `output`.
+#[inline]
+pub fn attribute_to(s: &str, output: &mut String) {
+    html_escape::encode_double_quoted_attribute_to_string(s, output);
+}
+
+/// Replaces every non-ASCII character in `s` with a numeric character
+/// reference (like `&#xE9;` for `é`), so the result is pure ASCII.
+///
+/// Each character is a full Unicode scalar value, so characters outside the
+/// Basic Multilingual Plane (like emoji) become a single reference, never a
+/// pair of UTF-16 surrogates. Returns [`Cow::Borrowed`] if `s` is already
+/// ASCII.
+///
+/// This is only valid for text and attribute values, where references are
+/// decoded. See [`Renderable::render_ascii`](crate::Renderable::render_ascii)
+/// to apply it to a whole render.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::escape;
+///
+/// assert_eq!(escape::ascii("café 🎉"), "caf&#xE9; &#x1F389;");
+/// ```
+#[inline]
+#[must_use]
+pub fn ascii(s: &str) -> Cow<'_, str> {
+    if s.is_ascii() {
+        return Cow::Borrowed(s);
+    }
+
+    let mut output = String::with_capacity(s.len() + 8);
+    ascii_to(s, &mut output);
+    Cow::Owned(output)
+}
+
+/// Replaces every non-ASCII character in `s` with a numeric character
+/// reference, appending the result to `output`.
+///
+/// See [`ascii`].
+#[inline]
+pub fn ascii_to(s: &str, output: &mut String) {
+    for c in s.chars() {
+        if c.is_ascii() {
+            output.push(c);
+        } else {
+            // ignore errors, as we are writing to a string
+            let _ = write!(output, "&#x{:X};", u32::from(c));
+        }
+    }
+}
+
+/// Returns the length in bytes of `s` once escaped for use as text content,
+/// without allocating.
+///
+/// This is always exactly `text(s).len()`.
+#[inline]
+#[must_use]
+pub fn escaped_len_text(s: &str) -> usize {
+    escaped_len(s)
+}
+
+/// Returns the length in bytes of `s` once escaped for use as a
+/// (double-quoted) attribute value, without allocating.
+///
+/// This is always exactly `attribute(s).len()`.
+#[inline]
+#[must_use]
+pub fn escaped_len_attribute(s: &str) -> usize {
+    escaped_len(s)
+}
+
+/// The length of `s` escaped like
+/// [`html_escape::encode_double_quoted_attribute`], which every context uses.
+fn escaped_len(s: &str) -> usize {
+    s.bytes()
+        .map(|byte| escape_byte(byte).map_or(1, str::len))
+        .sum()
+}
+
+/// The escaped form of `byte`, matching [`text`], or `None` if it isn't
+/// escaped.
+pub(crate) const fn escape_byte(byte: u8) -> Option<&'static str> {
+    match byte {
+        b'&' => Some("&amp;"),
+        b'<' => Some("&lt;"),
+        b'>' => Some("&gt;"),
+        b'"' => Some("&quot;"),
+        _ => None,
+    }
+}
+
+/// Returns whether `s` is at most `max_bytes` long once escaped with
+/// `escaped_len`, which is [`escaped_len_text`] or
+/// [`escaped_len_attribute`].
+///
+/// This is useful for deciding whether to truncate values before rendering,
+/// like in layout-sensitive HTML emails.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::escape::{self, fits_within};
+///
+/// assert!(fits_within("Tom & Jerry", 15, escape::escaped_len_text));
+/// assert!(!fits_within("Tom & Jerry", 14, escape::escaped_len_text));
+/// ```
+#[inline]
+#[must_use]
+pub fn fits_within(s: &str, max_bytes: usize, escaped_len: fn(&str) -> usize) -> bool {
+    // escaping never makes strings shorter
+    s.len() <= max_bytes && escaped_len(s) <= max_bytes
+}
+
+#[cfg(feature = "unstable-context")]
+pub use self::context::{AttributeValue, Context, Escaped, Node};
+
+/// User-implementable escaping contexts.
+///
+/// This is unstable, and may change in any release.
+#[cfg(feature = "unstable-context")]
+mod context {
+    use core::marker::PhantomData;
+
+    use super::String;
+    use crate::Renderable;
+
+    /// A context which strings are escaped for.
+    ///
+    /// [`Node`] and [`AttributeValue`] escape like templates do. Other
+    /// contexts can escape for other targets, like a different markup
+    /// language, and values are rendered in them with [`Escaped`].
+    pub trait Context {
+        /// Escapes `s` for this context, appending it to `output`.
+        fn escape_to(s: &str, output: &mut String);
+    }
+
+    /// The context of text content, escaped with [`text_to`](super::text_to).
+    #[derive(Debug, Clone, Copy)]
+    pub struct Node;
+
+    impl Context for Node {
+        #[inline]
+        fn escape_to(s: &str, output: &mut String) {
+            super::text_to(s, output);
+        }
+    }
+
+    /// The context of (double-quoted) attribute values, escaped with
+    /// [`attribute_to`](super::attribute_to).
+    #[derive(Debug, Clone, Copy)]
+    pub struct AttributeValue;
+
+    impl Context for AttributeValue {
+        #[inline]
+        fn escape_to(s: &str, output: &mut String) {
+            super::attribute_to(s, output);
+        }
+    }
+
+    /// A string rendered with the escaping of the context `C`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{
+    ///     escape::{Context, Escaped},
+    ///     html_elements, maud, Renderable,
+    /// };
+    ///
+    /// /// Escapes for a shell, by single-quoting.
+    /// struct Shell;
+    ///
+    /// impl Context for Shell {
+    ///     fn escape_to(s: &str, output: &mut String) {
+    ///         output.push('\'');
+    ///         output.push_str(&s.replace('\'', r"'\''"));
+    ///         output.push('\'');
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Escaped::<Shell, _>::new("it's").render(), r"'it'\''s'",);
+    /// ```
+    #[derive(Debug, Clone, Copy)]
+    pub struct Escaped<C, T>(pub T, PhantomData<C>);
+
+    impl<C: Context, T: AsRef<str>> Escaped<C, T> {
+        /// Creates a new value which renders `value` escaped for `C`.
+        #[inline]
+        pub const fn new(value: T) -> Self {
+            Self(value, PhantomData)
+        }
+    }
+
+    impl<C: Context, T: AsRef<str>> Renderable for Escaped<C, T> {
+        #[inline]
+        fn render_to(self, output: &mut String) {
+            C::escape_to(self.0.as_ref(), output);
+        }
+    }
+}
@@ -1231,6 +1231,57 @@ elements! {
     }
 }
 
+#[cfg(feature = "experimental-elements")]
+elements! {
+    /// **Experimental:** Mirrors the content of the selected `option` of a
+    /// customizable `select`, inside its `button`.
+    ///
+    /// This is part of the customizable `select` proposal, and may change or be
+    /// removed without a breaking release.
+    selectedcontent
+
+    /// **Experimental:** A child navigable which is isolated from the
+    /// embedding document, used for privacy-preserving ad rendering.
+    ///
+    /// This is not yet part of the HTML standard, and may change or be removed
+    /// without a breaking release.
+    fencedframe {
+        /// Permissions policy to be applied to the frame's contents
+        allow
+
+        /// Horizontal dimension
+        width
+
+        /// Vertical dimension
+        height
+    }
+
+    /// **Experimental:** A preview of another document which can be seamlessly
+    /// navigated into.
+    ///
+    /// This is not yet part of the HTML standard, and may change or be removed
+    /// without a breaking release.
+    portal {
+        /// Address of the resource
+        src
+
+        /// Referrer policy for fetches initiated by the element
+        referrerpolicy
+    }
+
+    /// **Experimental:** An interactive 3D model.
+    ///
+    /// This is not yet part of the HTML standard, and may change or be removed
+    /// without a breaking release.
+    model {
+        /// Address of the resource
+        src
+
+        /// Whether the model can be rotated and zoomed by the user
+        interactive
+    }
+}
+
 macro_rules! void {
     ($($el:ident)*) => {
         $(impl crate::VoidElement for $el {})*
@@ -0,0 +1,279 @@
+//! Fragments of a page which can be re-rendered on their own, for
+//! [htmx](https://htmx.org) out-of-band swaps.
+//!
+//! A [`Fragment`] (created with [`fragment`]) renders its contents inline,
+//! with its id on their root element. When a page is rendered with
+//! [`FragmentRegistry::render`], every fragment in it is registered, so that
+//! [`FragmentRegistry::render_only`] can later re-render just some of them,
+//! each marked with `hx-swap-oob` to replace the matching element in the
+//! page.
+//!
+//! # Example
+//!
+//! ```
+//! use std::{cell::Cell, rc::Rc};
+//!
+//! use hypertext::{
+//!     html_elements,
+//!     htmx::{fragment, FragmentRegistry},
+//!     maud, maud_move, Renderable,
+//! };
+//!
+//! let count = Rc::new(Cell::new(1));
+//! let badge = {
+//!     let count = count.clone();
+//!     maud_move! { span { (count.get()) } }
+//! };
+//!
+//! let mut registry = FragmentRegistry::new();
+//!
+//! assert_eq!(
+//!     registry.render(maud! { header { (fragment("cart", badge)) } }),
+//!     r#"<header><span id="cart">1</span></header>"#,
+//! );
+//!
+//! count.set(2);
+//!
+//! assert_eq!(
+//!     registry.render_only(["cart"]),
+//!     r#"<span id="cart" hx-swap-oob="true">2</span>"#,
+//! );
+//! ```
+
+extern crate std;
+
+use std::{boxed::Box, cell::RefCell, string::String, thread_local, vec::Vec};
+
+use crate::{Renderable, Rendered};
+
+type Render = Box<dyn Fn(&mut String)>;
+
+thread_local! {
+    static REGISTRIES: RefCell<Vec<Vec<(String, Render)>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns whether a [`FragmentRegistry`] is rendering on the current thread.
+pub(crate) fn is_registering() -> bool {
+    REGISTRIES.with_borrow(|registries| !registries.is_empty())
+}
+
+/// Creates a fragment with the given id, which renders `renderable` with that
+/// id on its root element.
+///
+/// `renderable` should render a single root element without an id of its
+/// own. Builds with `debug_assertions` panic if it renders no element at all.
+#[inline]
+pub fn fragment<R: Renderable + Clone + 'static>(
+    id: impl Into<String>,
+    renderable: R,
+) -> Fragment<R> {
+    Fragment {
+        id: id.into(),
+        renderable,
+    }
+}
+
+/// A part of a page which can be re-rendered on its own.
+///
+/// This is created with [`fragment`]. As fragments are re-rendered from a
+/// clone of their contents, the contents must be [`Clone`] and `'static`, so
+/// templates should be created with [`maud_move!`](crate::maud_move) or
+/// [`rsx_move!`](crate::rsx_move), capturing owned values.
+#[derive(Debug, Clone)]
+pub struct Fragment<R> {
+    id: String,
+    renderable: R,
+}
+
+impl<R: Renderable + Clone + 'static> Renderable for Fragment<R> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        REGISTRIES.with_borrow_mut(|registries| {
+            if let Some(fragments) = registries.last_mut() {
+                let renderable = self.renderable.clone();
+                fragments.push((
+                    self.id.clone(),
+                    Box::new(move |output| renderable.clone().render_to(output)),
+                ));
+            }
+        });
+
+        render_with_id(&self.id, false, output, |output| {
+            self.renderable.render_to(output);
+        });
+    }
+}
+
+/// Renders `f`, adding the given id to the first element it renders, marked
+/// as an out-of-band swap if `oob` is set.
+fn render_with_id(id: &str, oob: bool, output: &mut String, f: impl FnOnce(&mut String)) {
+    let start = output.len();
+    f(output);
+
+    let Some(end) = start_tag_name_end(&output[start..]) else {
+        debug_assert!(false, "htmx fragment `{id}` has no root element");
+        return;
+    };
+
+    let mut attributes = String::from(r#" id=""#);
+    crate::escape::attribute_to(id, &mut attributes);
+    attributes.push('"');
+    if oob {
+        attributes.push_str(r#" hx-swap-oob="true""#);
+    }
+
+    output.insert_str(start + end, &attributes);
+}
+
+/// The index just past the name of the first start tag in `html`, skipping
+/// any comments before it.
+fn start_tag_name_end(html: &str) -> Option<usize> {
+    let mut i = 0;
+
+    while let Some(offset) = html[i..].find('<') {
+        let name = i + offset + 1;
+        let rest = &html[name..];
+
+        if rest.starts_with("!--") {
+            i = name + rest.find("-->")? + 3;
+        } else if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            let len = rest
+                .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+                .unwrap_or(rest.len());
+
+            return Some(name + len);
+        } else {
+            i = name;
+        }
+    }
+
+    None
+}
+
+/// Renders each of `fragments` with its id and `hx-swap-oob="true"` added to
+/// its root element, for a response made only of out-of-band swaps.
+///
+/// As with [`fragment`], each should render a single root element without an
+/// id of its own.
+///
+/// Use [`render_fragment_list`](crate::render_fragment_list) to get each
+/// fragment's HTML separately instead.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{html_elements, htmx::render_oob, maud, Renderable};
+///
+/// assert_eq!(
+///     render_oob([("total", maud! { b { "$3" } })]),
+///     r#"<b id="total" hx-swap-oob="true">$3</b>"#,
+/// );
+/// ```
+#[inline]
+pub fn render_oob<I, K, R>(fragments: I) -> Rendered<String>
+where
+    I: IntoIterator<Item = (K, R)>,
+    K: AsRef<str>,
+    R: Renderable,
+{
+    let mut output = String::new();
+
+    for (id, fragment) in fragments {
+        render_with_id(id.as_ref(), true, &mut output, |output| {
+            fragment.render_to(output);
+        });
+    }
+
+    Rendered(output)
+}
+
+/// The [`Fragment`]s of a rendered page, which can be re-rendered on their
+/// own.
+#[derive(Default)]
+pub struct FragmentRegistry {
+    fragments: Vec<(String, Render)>,
+}
+
+impl FragmentRegistry {
+    /// Creates an empty registry.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fragments: Vec::new(),
+        }
+    }
+
+    /// Renders `page` to a string, registering every [`Fragment`] rendered
+    /// in it.
+    ///
+    /// Fragments are only registered with the innermost registry rendering on
+    /// the current thread, and replace any fragments already registered with
+    /// the same id.
+    #[inline]
+    pub fn render(&mut self, page: impl Renderable) -> Rendered<String> {
+        struct Guard;
+
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                REGISTRIES.with_borrow_mut(Vec::pop);
+            }
+        }
+
+        REGISTRIES.with_borrow_mut(|registries| registries.push(Vec::new()));
+        let guard = Guard;
+
+        let rendered = page.render();
+
+        let fragments = REGISTRIES.with_borrow_mut(|registries| {
+            registries
+                .last_mut()
+                .map(core::mem::take)
+                .unwrap_or_default()
+        });
+        drop(guard);
+
+        for (id, render) in fragments {
+            self.fragments.retain(|(existing, _)| *existing != id);
+            self.fragments.push((id, render));
+        }
+
+        rendered
+    }
+
+    /// Returns whether a fragment with the given id is registered.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, id: &str) -> bool {
+        self.fragments.iter().any(|(existing, _)| existing == id)
+    }
+
+    /// Re-renders the fragments with the given ids, in order, each with
+    /// `hx-swap-oob="true"` added to its root element.
+    ///
+    /// Ids which aren't registered are skipped.
+    #[inline]
+    pub fn render_only<'a>(&self, ids: impl IntoIterator<Item = &'a str>) -> Rendered<String> {
+        let mut output = String::new();
+
+        for id in ids {
+            if let Some((id, render)) = self.fragments.iter().find(|(existing, _)| existing == id) {
+                render_with_id(id, true, &mut output, render);
+            }
+        }
+
+        Rendered(output)
+    }
+}
+
+impl core::fmt::Debug for FragmentRegistry {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FragmentRegistry")
+            .field(
+                "fragments",
+                &self.fragments.iter().map(|(id, _)| id).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
@@ -32,6 +32,27 @@
 //! struct, and then proceeds to attempt to access the corresponding associated
 //! type for each attribute you use.
 //!
+//! Literal references to `id`s in `for`, `headers`, and `aria-labelledby`
+//! attributes are also checked against the literal `id`s in the same
+//! invocation, and a warning (a deprecation warning, as that is the only kind
+//! macros can emit on stable) is emitted for any that can't be found. Dynamic
+//! values are never checked.
+//!
+//! Similarly, an `a` or `area` element with a literal `target="_blank"` warns
+//! unless it also has a `rel` containing `noopener` or `noreferrer`. Elements
+//! with a dynamic `target` or `rel` (or with spread attributes) are skipped.
+//!
+//! Literal values of `hidden`, `contenteditable`, and `autocapitalize` are
+//! checked against the keywords they accept (`hidden` and `contenteditable`
+//! may also be given no value, as boolean attributes), so a typo like
+//! `hidden="until_found"` is a compile error.
+//!
+//! Attributes starting with `data-` are not checked, as any name is valid.
+//! Enabling the `strict-data-attributes` feature will instead require their
+//! names to only contain lowercase ASCII letters, digits, and hyphens,
+//! catching names like `data-userId` which don't map to
+//! `dataset.userId` as one might expect.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -95,17 +116,64 @@
 //!     r#"<div hx-get="/api/endpoint">Hello, world!</div>"#,
 //! );
 //! ```
+//!
+//! The [`attributes!`] macro can generate such a trait from a list of
+//! attribute names, and [`validation::normalize_attribute_ident`] documents
+//! exactly how attribute names are mapped to identifiers.
+//!
+//! ## IDE Mode
+//!
+//! Compiling with `RUSTFLAGS="--cfg hypertext_ide"` (for example, in your
+//! editor's rust-analyzer settings) makes the macros generate a minimal
+//! expansion, which is much cheaper to analyze. Every expression you write is
+//! kept with its original span, so type inference, go-to-definition and errors
+//! still work, but static HTML and all element and attribute checks are
+//! skipped. The output is wrong in this mode, so never use it for real builds.
+//!
+//! ## Compile-Time Statistics
+//!
+//! Setting `HYPERTEXT_STATS=path.jsonl` while compiling makes every macro
+//! invocation append a JSON record to that file, with its location and the
+//! number of nodes, checked elements and attributes, dynamic parts and emitted
+//! tokens, which helps find the templates that are the most expensive to
+//! compile. Cargo doesn't know about this variable, so only crates which are
+//! recompiled are recorded (run `cargo clean -p your-crate` first). Nothing is
+//! written when it is unset.
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 #![warn(clippy::missing_inline_in_public_items)]
 
+extern crate self as hypertext;
+
 #[cfg(feature = "alloc")]
 mod alloc;
 mod attributes;
+#[cfg(feature = "alloc")]
+mod canonicalize;
+#[cfg(feature = "components")]
+pub mod components;
+#[cfg(feature = "csp")]
+pub mod csp;
+#[cfg(feature = "alloc")]
+pub mod escape;
 pub mod html_elements;
+#[cfg(feature = "htmx")]
+pub mod htmx;
+#[cfg(feature = "stimulus")]
+pub mod stimulus;
+#[cfg(feature = "theme")]
+pub mod theme;
+#[cfg(feature = "validate")]
+pub mod validate;
+#[cfg(feature = "alloc")]
+pub mod validation;
+#[cfg(feature = "alloc")]
+pub mod values;
 mod web;
 
 pub use attributes::{Attribute, AttributeNamespace, GlobalAttributes};
+#[cfg(feature = "alloc")]
+pub use canonicalize::canonicalize;
 /// Render static HTML using [`maud`] syntax.
 ///
 /// For details about the syntax, see [`maud!`].
@@ -116,6 +184,8 @@ pub use attributes::{Attribute, AttributeNamespace, GlobalAttributes};
 /// Note that the macro cannot process any dynamic content, so you cannot use
 /// any expressions inside the macro.
 ///
+/// This macro accepts the same options as [`maud!`].
+///
 /// # Example
 ///
 /// ```
@@ -141,6 +211,8 @@ pub use hypertext_macros::maud_static;
 /// Note that the macro cannot process any dynamic content, so you cannot use
 /// any expressions inside the macro.
 ///
+/// This macro accepts the same options as [`maud!`].
+///
 /// # Example
 ///
 /// ```
@@ -163,6 +235,56 @@ pub use self::alloc::*;
 /// Elements that can be self-closing.
 pub trait VoidElement {}
 
+/// Whether an element has a closing tag, as returned by [`element_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ElementKind {
+    /// An element with a closing tag, like `div`.
+    Normal,
+    /// An element without a closing tag, like `br`, which implements
+    /// [`VoidElement`].
+    Void,
+}
+
+/// Looks up the kind of a standard HTML element by its name.
+///
+/// This is the runtime equivalent of the elements in [`html_elements`], for
+/// rendering elements whose names are only known at runtime. Names must be
+/// lowercase, and custom elements return [`None`].
+///
+/// # Example
+///
+/// ```
+/// use hypertext::{element_kind, ElementKind};
+///
+/// assert_eq!(element_kind("br"), Some(ElementKind::Void));
+/// assert_eq!(element_kind("div"), Some(ElementKind::Normal));
+/// assert_eq!(element_kind("my-element"), None);
+/// ```
+#[inline]
+#[must_use]
+pub fn element_kind(name: &str) -> Option<ElementKind> {
+    match name {
+        "area" | "base" | "br" | "col" | "embed" | "hr" | "img" | "input" | "link" | "meta"
+        | "source" | "track" | "wbr" => Some(ElementKind::Void),
+        "html" | "head" | "title" | "style" | "body" | "article" | "section" | "nav" | "aside"
+        | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "hgroup" | "header" | "footer" | "address"
+        | "p" | "pre" | "blockquote" | "ol" | "ul" | "menu" | "li" | "dl" | "dt" | "dd"
+        | "figure" | "figcaption" | "main" | "search" | "div" | "a" | "em" | "strong" | "small"
+        | "s" | "cite" | "q" | "dfn" | "abbr" | "ruby" | "rt" | "rp" | "data" | "time" | "code"
+        | "var" | "samp" | "kbd" | "sup" | "sub" | "i" | "b" | "u" | "mark" | "bdi" | "bdo"
+        | "span" | "ins" | "del" | "picture" | "iframe" | "object" | "video" | "audio" | "map"
+        | "table" | "caption" | "colgroup" | "tbody" | "thead" | "tfoot" | "tr" | "td" | "th"
+        | "form" | "label" | "button" | "select" | "datalist" | "optgroup" | "option"
+        | "textarea" | "output" | "progress" | "meter" | "fieldset" | "legend" | "details"
+        | "summary" | "dialog" | "script" | "noscript" | "template" | "slot" | "canvas" => {
+            Some(ElementKind::Normal)
+        }
+        #[cfg(feature = "experimental-elements")]
+        "selectedcontent" | "fencedframe" | "portal" | "model" => Some(ElementKind::Normal),
+        _ => None,
+    }
+}
+
 /// A rendered HTML string.
 ///
 /// This type is returned by [`Renderable::render`] ([`Rendered<String>`]), as
@@ -171,7 +293,7 @@ pub trait VoidElement {}
 /// This type intentionally does **not** implement [`Renderable`] to prevent
 /// anti-patterns such as rendering to a string then embedding that HTML string
 /// into another page.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct Rendered<T>(pub T);
 
 impl<T> Rendered<T> {
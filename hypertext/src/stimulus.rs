@@ -0,0 +1,164 @@
+//! Attribute values for [Stimulus](https://stimulus.hotwired.dev).
+//!
+//! Stimulus is wired up with `data-*` attributes, which are not checked by
+//! the macros. These types build their values, so that the syntax of each
+//! value is always correct.
+//!
+//! # Example
+//!
+//! ```
+//! use hypertext::{
+//!     html_elements, maud,
+//!     stimulus::{Action, Controller, Target},
+//!     GlobalAttributes, Renderable,
+//! };
+//!
+//! assert_eq!(
+//!     maud! {
+//!         div data-controller=(Controller("dropdown")) {
+//!             button data-action=(Action::new("click", "dropdown", "toggle")) { "Menu" }
+//!             ul ..(Target::new("dropdown", "menu")) {}
+//!         }
+//!     }
+//!     .render(),
+//!     r#"<div data-controller="dropdown"><button data-action="click-&gt;dropdown#toggle">Menu</button><ul data-dropdown-target="menu"></ul></div>"#,
+//! );
+//! ```
+
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+use crate::{AttributeSet, Renderable};
+
+/// The name of a controller, as the value of `data-controller`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Controller<'a>(pub &'a str);
+
+impl Renderable for Controller<'_> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.0.render_to(output);
+    }
+}
+
+/// An action descriptor, as the value of `data-action`.
+///
+/// This renders as `event->controller#method` (with the `>` escaped, like in
+/// any other attribute value).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Action<'a> {
+    /// The event to listen for, such as `click`.
+    pub event: &'a str,
+    /// The identifier of the controller.
+    pub controller: &'a str,
+    /// The method of the controller to call.
+    pub method: &'a str,
+}
+
+impl<'a> Action<'a> {
+    /// Creates a new action descriptor.
+    #[must_use]
+    #[inline]
+    pub const fn new(event: &'a str, controller: &'a str, method: &'a str) -> Self {
+        Self {
+            event,
+            controller,
+            method,
+        }
+    }
+}
+
+impl Renderable for Action<'_> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.event.render_to(output);
+        output.push_str("-&gt;");
+        self.controller.render_to(output);
+        output.push('#');
+        self.method.render_to(output);
+    }
+}
+
+/// A target of a controller.
+///
+/// As the attribute name depends on the controller
+/// (`data-[controller]-target`), this is an [`AttributeSet`] which renders the
+/// whole attribute when spread with `..(target)`. Rendering it directly only
+/// renders the target's name.
+///
+/// Controller identifiers may only contain lowercase ASCII letters, digits,
+/// and hyphens, as they become part of the attribute name. Spreading a target
+/// with any other controller renders nothing, and fails a debug assertion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Target<'a> {
+    /// The identifier of the controller.
+    pub controller: &'a str,
+    /// The name of the target.
+    pub name: &'a str,
+}
+
+impl<'a> Target<'a> {
+    /// Creates a new target.
+    #[must_use]
+    #[inline]
+    pub const fn new(controller: &'a str, name: &'a str) -> Self {
+        Self { controller, name }
+    }
+}
+
+impl Renderable for Target<'_> {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        self.name.render_to(output);
+    }
+}
+
+impl AttributeSet for Target<'_> {
+    #[inline]
+    fn render_attributes(self, output: &mut String) {
+        let valid = is_identifier(self.controller);
+
+        debug_assert!(
+            valid,
+            "invalid Stimulus controller identifier `{}`",
+            self.controller
+        );
+
+        if !valid {
+            return;
+        }
+
+        output.push(' ');
+        output.push_str(&target_attr(self.controller));
+        output.push_str("=\"");
+        self.render_to(output);
+        output.push('"');
+    }
+}
+
+/// Returns the name of the attribute for targets of the given controller.
+///
+/// The controller is not validated, so only pass trusted identifiers.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::stimulus::target_attr;
+///
+/// assert_eq!(target_attr("dropdown"), "data-dropdown-target");
+/// ```
+#[must_use]
+#[inline]
+pub fn target_attr(controller: &str) -> String {
+    format!("data-{controller}-target")
+}
+
+/// Whether `controller` is a valid controller identifier, which can be
+/// rendered in an attribute name without escaping.
+fn is_identifier(controller: &str) -> bool {
+    !controller.is_empty()
+        && controller
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
@@ -0,0 +1,155 @@
+//! Theme tokens resolved at render time.
+//!
+//! A [`Theme`] is a set of named tokens (such as `accent-color`) which is
+//! installed for the duration of a render with [`Renderable::render_with`].
+//! Templates can then reference tokens with [`Var`], which looks up its value
+//! in the currently installed theme.
+//!
+//! # Example
+//!
+//! ```
+//! use hypertext::{
+//!     html_elements, maud,
+//!     theme::{Theme, Var},
+//!     GlobalAttributes, Renderable,
+//! };
+//!
+//! let light = Theme::new().with("fg", "black");
+//! let dark = Theme::new().with("fg", "white");
+//!
+//! let page = || maud! { p style={ "color: " (Var::new("fg")) } { "Hi!" } };
+//!
+//! assert_eq!(
+//!     page().render_with(&light),
+//!     r#"<p style="color: black">Hi!</p>"#
+//! );
+//! assert_eq!(
+//!     page().render_with(&dark),
+//!     r#"<p style="color: white">Hi!</p>"#
+//! );
+//! ```
+
+extern crate std;
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    string::{String, ToString},
+    thread_local,
+    vec::Vec,
+};
+
+use crate::Renderable;
+
+thread_local! {
+    static THEMES: RefCell<Vec<Theme>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Gets the innermost installed theme.
+pub(crate) fn current() -> Option<Theme> {
+    THEMES.with_borrow(|themes| themes.last().cloned())
+}
+
+/// Installs `theme` for the duration of `f`.
+pub(crate) fn scoped<T>(theme: &Theme, f: impl FnOnce() -> T) -> T {
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            THEMES.with_borrow_mut(Vec::pop);
+        }
+    }
+
+    THEMES.with_borrow_mut(|themes| themes.push(theme.clone()));
+    let _guard = Guard;
+
+    f()
+}
+
+/// A set of named theme tokens.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Theme {
+    tokens: BTreeMap<&'static str, String>,
+}
+
+impl Theme {
+    /// Creates an empty theme.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tokens: BTreeMap::new(),
+        }
+    }
+
+    /// Sets the value of a token, returning the theme.
+    #[inline]
+    #[must_use]
+    pub fn with(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Sets the value of a token.
+    #[inline]
+    pub fn insert(&mut self, name: &'static str, value: impl Into<String>) {
+        self.tokens.insert(name, value.into());
+    }
+
+    /// Gets the value of a token.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.tokens.get(name).map(String::as_str)
+    }
+}
+
+/// A reference to a token of the currently installed [`Theme`].
+///
+/// If no theme is installed or the token is missing from it, the default
+/// value is rendered instead, if any. The value is escaped like any other
+/// string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Var {
+    name: &'static str,
+    default: Option<&'static str>,
+}
+
+impl Var {
+    /// Creates a reference to the token with the given name.
+    #[inline]
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            default: None,
+        }
+    }
+
+    /// Sets the value to fall back to if the token is missing.
+    #[inline]
+    #[must_use]
+    pub const fn with_default(self, default: &'static str) -> Self {
+        Self {
+            default: Some(default),
+            ..self
+        }
+    }
+}
+
+impl Renderable for Var {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let value = THEMES.with_borrow(|themes| {
+            themes
+                .last()
+                .and_then(|theme| theme.get(self.name))
+                .map(ToString::to_string)
+        });
+
+        match value {
+            Some(value) => value.render_to(output),
+            None => self.default.render_to(output),
+        }
+    }
+}
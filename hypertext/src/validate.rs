@@ -0,0 +1,425 @@
+//! A lint for rendered HTML, to catch malformed output in tests.
+//!
+//! Templates written with [`maud!`](crate::maud) and [`rsx!`](crate::rsx) are
+//! always well-formed, but [`Raw`](crate::Raw) fragments and manual
+//! [`Renderable`](crate::Renderable) implementations are not checked by the
+//! type system. [`check`] looks for the most common mistakes in their output:
+//!
+//! - unclosed and mismatched tags (void elements need no closing tag)
+//! - unquoted or unterminated attribute values
+//! - duplicate `id` values
+//! - `&` not followed by a character reference
+//!
+//! This is not a full HTML5 parser, so it will not catch everything, and named
+//! character references are only checked for their syntax.
+//!
+//! # Example
+//!
+//! ```
+//! use hypertext::{
+//!     html_elements, maud,
+//!     validate::{self, ValidationErrorKind},
+//!     GlobalAttributes, Raw, Renderable,
+//! };
+//!
+//! assert!(maud! { div #main { "a &amp; b" } }
+//!     .render_validated()
+//!     .is_ok());
+//!
+//! let errors = maud! { main { (Raw("<div>")) } }
+//!     .render_validated()
+//!     .unwrap_err();
+//!
+//! assert_eq!(errors[0].offset, 6);
+//! assert_eq!(
+//!     errors[0].kind,
+//!     ValidationErrorKind::UnclosedTag { name: "div".into() }
+//! );
+//! assert!(validate::check("<p>a & b</p>").is_err());
+//! ```
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+/// Elements which never have a closing tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// Elements whose contents are not parsed as HTML.
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+/// An error found by [`check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The byte offset in the HTML where the error was found.
+    pub offset: usize,
+    /// What is wrong.
+    pub kind: ValidationErrorKind,
+}
+
+/// The kind of a [`ValidationError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationErrorKind {
+    /// An element was opened but never closed.
+    UnclosedTag {
+        /// The name of the element.
+        name: String,
+    },
+    /// A closing tag with no matching open element.
+    UnexpectedClosingTag {
+        /// The name of the element.
+        name: String,
+    },
+    /// A tag, comment or declaration which is not terminated or has an
+    /// invalid name.
+    MalformedTag,
+    /// An attribute value without quotes.
+    UnquotedAttribute {
+        /// The name of the attribute.
+        name: String,
+    },
+    /// An attribute value with no closing quote.
+    UnterminatedAttribute {
+        /// The name of the attribute.
+        name: String,
+    },
+    /// An `id` which was already used by another element.
+    DuplicateId {
+        /// The repeated `id`.
+        id: String,
+        /// The byte offset of the first element with this `id`.
+        first: usize,
+    },
+    /// An `&` which does not start a character reference.
+    InvalidEntity,
+}
+
+impl Display for ValidationError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ValidationErrorKind::UnclosedTag { name } => write!(f, "unclosed tag `<{name}>`"),
+            ValidationErrorKind::UnexpectedClosingTag { name } => {
+                write!(f, "unexpected closing tag `</{name}>`")
+            }
+            ValidationErrorKind::MalformedTag => f.write_str("malformed tag"),
+            ValidationErrorKind::UnquotedAttribute { name } => {
+                write!(f, "value of attribute `{name}` is not quoted")
+            }
+            ValidationErrorKind::UnterminatedAttribute { name } => {
+                write!(f, "value of attribute `{name}` is not terminated")
+            }
+            ValidationErrorKind::DuplicateId { id, first } => {
+                write!(f, "duplicate id `{id}`, first used at byte {first}")
+            }
+            ValidationErrorKind::InvalidEntity => {
+                f.write_str("`&` is not followed by a character reference")
+            }
+        }?;
+
+        write!(f, " at byte {}", self.offset)
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+/// Checks that `html` is well-formed.
+///
+/// # Errors
+///
+/// Returns every error found, ordered by their offset.
+#[inline]
+pub fn check(html: &str) -> Result<(), Vec<ValidationError>> {
+    let mut checker = Checker {
+        html,
+        pos: 0,
+        open: Vec::new(),
+        ids: BTreeMap::new(),
+        errors: Vec::new(),
+    };
+
+    checker.run();
+
+    if checker.errors.is_empty() {
+        Ok(())
+    } else {
+        checker.errors.sort_by_key(|error| error.offset);
+        Err(checker.errors)
+    }
+}
+
+struct Checker<'a> {
+    html: &'a str,
+    pos: usize,
+    open: Vec<(&'a str, usize)>,
+    ids: BTreeMap<&'a str, usize>,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Checker<'a> {
+    fn run(&mut self) {
+        while let Some(byte) = self.peek() {
+            match byte {
+                b'<' => self.tag(),
+                b'&' => {
+                    self.entity(self.pos);
+                    self.pos += 1;
+                }
+                _ => self.pos += 1,
+            }
+        }
+
+        for (name, offset) in core::mem::take(&mut self.open) {
+            self.error(
+                offset,
+                ValidationErrorKind::UnclosedTag { name: name.into() },
+            );
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.html.as_bytes().get(self.pos).copied()
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.html[self.pos..]
+    }
+
+    fn error(&mut self, offset: usize, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError { offset, kind });
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|byte| byte.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    /// Skips past `end`, or reports a malformed tag at `start` if it never
+    /// appears.
+    fn skip_past(&mut self, start: usize, end: &str) {
+        if let Some(len) = self.rest().find(end) {
+            self.pos += len + end.len();
+        } else {
+            self.error(start, ValidationErrorKind::MalformedTag);
+            self.pos = self.html.len();
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(u8) -> bool) -> &'a str {
+        let start = self.pos;
+
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+
+        &self.html[start..self.pos]
+    }
+
+    fn tag(&mut self) {
+        let start = self.pos;
+        self.pos += 1;
+
+        if self.rest().starts_with("!--") {
+            self.skip_past(start, "-->");
+        } else if matches!(self.peek(), Some(b'!' | b'?')) {
+            self.skip_past(start, ">");
+        } else if self.peek() == Some(b'/') {
+            self.pos += 1;
+            self.closing_tag(start);
+        } else if self.peek().is_some_and(|byte| byte.is_ascii_alphabetic()) {
+            self.opening_tag(start);
+        } else {
+            self.error(start, ValidationErrorKind::MalformedTag);
+        }
+    }
+
+    fn name(&mut self) -> &'a str {
+        self.take_while(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b':' | b'_'))
+    }
+
+    fn opening_tag(&mut self, start: usize) {
+        let name = self.name();
+
+        let self_closing = loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b'>') => {
+                    self.pos += 1;
+                    break false;
+                }
+                Some(b'/') if self.rest().starts_with("/>") => {
+                    self.pos += 2;
+                    break true;
+                }
+                Some(_) => {
+                    if !self.attribute(start) {
+                        return;
+                    }
+                }
+                None => {
+                    self.error(start, ValidationErrorKind::MalformedTag);
+                    return;
+                }
+            }
+        };
+
+        let is = |names: &[&str]| names.iter().any(|n| n.eq_ignore_ascii_case(name));
+
+        if self_closing || is(&VOID_ELEMENTS) {
+            return;
+        }
+
+        self.open.push((name, start));
+
+        if is(&RAW_TEXT_ELEMENTS) {
+            // skip to the closing tag, which is then checked as usual
+            while let Some(len) = self.rest().find("</") {
+                self.pos += len;
+
+                let after = &self.rest().as_bytes()[2..];
+                if after
+                    .get(..name.len())
+                    .is_some_and(|after| after.eq_ignore_ascii_case(name.as_bytes()))
+                {
+                    return;
+                }
+
+                self.pos += 2;
+            }
+
+            self.pos = self.html.len();
+        }
+    }
+
+    /// Checks an attribute, returning whether the rest of the tag can still be
+    /// checked.
+    fn attribute(&mut self, start: usize) -> bool {
+        let name = self.take_while(|byte| {
+            !byte.is_ascii_whitespace() && !matches!(byte, b'=' | b'>' | b'/' | b'"' | b'\'')
+        });
+
+        if name.is_empty() {
+            self.error(start, ValidationErrorKind::MalformedTag);
+            self.skip_past(start, ">");
+            return false;
+        }
+
+        self.skip_whitespace();
+
+        if self.peek() != Some(b'=') {
+            return true;
+        }
+
+        self.pos += 1;
+        self.skip_whitespace();
+
+        let value_start = self.pos;
+
+        let Some(quote @ (b'"' | b'\'')) = self.peek() else {
+            self.error(
+                value_start,
+                ValidationErrorKind::UnquotedAttribute { name: name.into() },
+            );
+            self.take_while(|byte| !byte.is_ascii_whitespace() && byte != b'>');
+            return true;
+        };
+
+        self.pos += 1;
+        let value = self.take_while(|byte| byte != quote);
+
+        if self.peek().is_none() {
+            self.error(
+                value_start,
+                ValidationErrorKind::UnterminatedAttribute { name: name.into() },
+            );
+            return false;
+        }
+
+        self.pos += 1;
+
+        for (i, _) in value.match_indices('&') {
+            self.entity(value_start + 1 + i);
+        }
+
+        if name.eq_ignore_ascii_case("id") {
+            if let Some(&first) = self.ids.get(value) {
+                self.error(
+                    start,
+                    ValidationErrorKind::DuplicateId {
+                        id: value.into(),
+                        first,
+                    },
+                );
+            } else {
+                self.ids.insert(value, start);
+            }
+        }
+
+        true
+    }
+
+    fn closing_tag(&mut self, start: usize) {
+        let name = self.name();
+        self.skip_whitespace();
+
+        if name.is_empty() || self.peek() != Some(b'>') {
+            self.error(start, ValidationErrorKind::MalformedTag);
+            self.skip_past(start, ">");
+            return;
+        }
+
+        self.pos += 1;
+
+        if let Some(index) = self
+            .open
+            .iter()
+            .rposition(|(open, _)| open.eq_ignore_ascii_case(name))
+        {
+            for (name, offset) in self.open.split_off(index + 1) {
+                self.error(
+                    offset,
+                    ValidationErrorKind::UnclosedTag { name: name.into() },
+                );
+            }
+
+            self.open.pop();
+        } else {
+            self.error(
+                start,
+                ValidationErrorKind::UnexpectedClosingTag { name: name.into() },
+            );
+        }
+    }
+
+    /// Checks that the `&` at `offset` starts a character reference.
+    fn entity(&mut self, offset: usize) {
+        let rest = &self.html.as_bytes()[offset + 1..];
+
+        let len = match rest {
+            [b'#', b'x' | b'X', rest @ ..] => {
+                let digits = rest.iter().take_while(|b| b.is_ascii_hexdigit()).count();
+                (digits > 0).then_some(2 + digits)
+            }
+            [b'#', rest @ ..] => {
+                let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+                (digits > 0).then_some(1 + digits)
+            }
+            [first, ..] if first.is_ascii_alphabetic() => Some(
+                rest.iter()
+                    .take_while(|b| b.is_ascii_alphanumeric())
+                    .count(),
+            ),
+            _ => None,
+        };
+
+        if len.is_none_or(|len| rest.get(len) != Some(&b';')) {
+            self.error(offset, ValidationErrorKind::InvalidEntity);
+        }
+    }
+}
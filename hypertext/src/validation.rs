@@ -0,0 +1,108 @@
+//! Rules used when checking names at compile time.
+extern crate alloc;
+
+use alloc::{format, string::String};
+
+/// Keywords which are checked as raw identifiers.
+const RAW_KEYWORDS: [&str; 50] = [
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "become",
+    "box",
+    "break",
+    "const",
+    "continue",
+    "do",
+    "dyn",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "final",
+    "fn",
+    "for",
+    "gen",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "macro",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "override",
+    "priv",
+    "pub",
+    "ref",
+    "return",
+    "static",
+    "struct",
+    "trait",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "unsafe",
+    "unsized",
+    "use",
+    "virtual",
+    "where",
+    "while",
+    "yield",
+    "union",
+    "macro_rules",
+];
+
+/// Keywords which cannot be raw identifiers.
+const NON_RAW_KEYWORDS: [&str; 4] = ["crate", "self", "Self", "super"];
+
+/// Normalizes an attribute name to the identifier the macros check it
+/// against.
+///
+/// Use this to find the name of the constant to define in a custom attribute
+/// trait (see [`attributes!`](crate::attributes)). The rules are:
+/// 1. If the name contains a colon, only the part before the first colon is
+///    used, and it is checked as an
+///    [`AttributeNamespace`](crate::AttributeNamespace).
+/// 2. Every `-` is replaced with `_`.
+/// 3. Rust keywords become raw identifiers (`type` becomes `r#type`).
+/// 4. Names which are empty, start with a digit, or are keywords that cannot be
+///    raw identifiers (`crate`, `self`, `Self`, and `super`) are prefixed with
+///    `_`.
+///
+/// Because of rule 2, `foo-bar` and `foo_bar` are checked as the same
+/// attribute, so using both on one element is a compile error.
+///
+/// # Example
+///
+/// ```
+/// use hypertext::validation::normalize_attribute_ident;
+///
+/// assert_eq!(normalize_attribute_ident("hx-get"), "hx_get");
+/// assert_eq!(normalize_attribute_ident("hx-on:click"), "hx_on");
+/// assert_eq!(normalize_attribute_ident("type"), "r#type");
+/// assert_eq!(normalize_attribute_ident("3d"), "_3d");
+/// ```
+#[must_use]
+#[inline]
+pub fn normalize_attribute_ident(name: &str) -> String {
+    let name = name
+        .split_once(':')
+        .map_or(name, |(namespace, _)| namespace)
+        .replace('-', "_");
+
+    if name.is_empty()
+        || name.starts_with(|c: char| c.is_ascii_digit())
+        || NON_RAW_KEYWORDS.contains(&&*name)
+    {
+        format!("_{name}")
+    } else if RAW_KEYWORDS.contains(&&*name) {
+        format!("r#{name}")
+    } else {
+        name
+    }
+}
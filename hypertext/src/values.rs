@@ -0,0 +1,199 @@
+//! Typed values for attributes which only accept a few keywords.
+//!
+//! Each type renders exactly the keyword from the HTML specification, so it
+//! can be spliced into the matching attribute, and passed around in your own
+//! code instead of a string.
+//!
+//! # Example
+//!
+//! ```
+//! use hypertext::{
+//!     html_elements, maud,
+//!     values::{Loading, Rel, Target},
+//!     Renderable,
+//! };
+//!
+//! assert_eq!(
+//!     maud! {
+//!         a href="/" target=(Target::Blank) rel=(Rel::NOOPENER | Rel::NOREFERRER) {
+//!             img src="/logo.png" loading=(Loading::Lazy);
+//!         }
+//!     }
+//!     .render(),
+//!     r#"<a href="/" target="_blank" rel="noopener noreferrer"><img src="/logo.png" loading="lazy"></a>"#,
+//! );
+//! ```
+
+extern crate alloc;
+
+use alloc::string::String;
+use core::ops::{BitOr, BitOrAssign};
+
+use crate::Renderable;
+
+macro_rules! keywords {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $keyword:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl $name {
+            /// Returns the keyword this value renders as.
+            #[inline]
+            #[must_use]
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $keyword,)*
+                }
+            }
+        }
+
+        impl Renderable for $name {
+            #[inline]
+            fn render_to(self, output: &mut String) {
+                output.push_str(self.as_str());
+            }
+        }
+    };
+}
+
+keywords! {
+    /// A value of the `target` attribute of links and forms.
+    pub enum Target {
+        /// `_blank`, a new browsing context.
+        Blank => "_blank",
+        /// `_self`, the current browsing context.
+        Self_ => "_self",
+        /// `_parent`, the parent of the current browsing context.
+        Parent => "_parent",
+        /// `_top`, the topmost browsing context.
+        Top => "_top",
+    }
+}
+
+keywords! {
+    /// A value of the `loading` attribute of `img` and `iframe` elements.
+    pub enum Loading {
+        /// `lazy`, deferred until the element is near the viewport.
+        Lazy => "lazy",
+        /// `eager`, loaded immediately.
+        Eager => "eager",
+    }
+}
+
+keywords! {
+    /// A value of the `decoding` attribute of `img` elements.
+    pub enum Decoding {
+        /// `sync`, decoded synchronously with other content.
+        Sync => "sync",
+        /// `async`, decoded asynchronously.
+        Async => "async",
+        /// `auto`, left to the browser.
+        Auto => "auto",
+    }
+}
+
+/// A set of link types, for the `rel` attribute.
+///
+/// Sets are combined with `|`, and render their link types separated by
+/// spaces, always in the order of the constants below, regardless of the
+/// order they were combined in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rel(u16);
+
+impl Rel {
+    /// `canonical`.
+    pub const CANONICAL: Self = Self(1 << 9);
+    /// `external`.
+    pub const EXTERNAL: Self = Self(1 << 3);
+    /// `icon`.
+    pub const ICON: Self = Self(1 << 7);
+    /// The link types, in the order they are rendered.
+    const KEYWORDS: [(Self, &'static str); 10] = [
+        (Self::NOOPENER, "noopener"),
+        (Self::NOREFERRER, "noreferrer"),
+        (Self::NOFOLLOW, "nofollow"),
+        (Self::EXTERNAL, "external"),
+        (Self::UGC, "ugc"),
+        (Self::SPONSORED, "sponsored"),
+        (Self::STYLESHEET, "stylesheet"),
+        (Self::ICON, "icon"),
+        (Self::PRELOAD, "preload"),
+        (Self::CANONICAL, "canonical"),
+    ];
+    /// `nofollow`.
+    pub const NOFOLLOW: Self = Self(1 << 2);
+    /// `noopener`.
+    pub const NOOPENER: Self = Self(1 << 0);
+    /// `noreferrer`.
+    pub const NOREFERRER: Self = Self(1 << 1);
+    /// `preload`.
+    pub const PRELOAD: Self = Self(1 << 8);
+    /// `sponsored`.
+    pub const SPONSORED: Self = Self(1 << 5);
+    /// `stylesheet`.
+    pub const STYLESHEET: Self = Self(1 << 6);
+    /// `ugc`, for user-generated content.
+    pub const UGC: Self = Self(1 << 4);
+
+    /// Creates an empty set.
+    #[inline]
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns whether this set has no link types.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns whether this set has all the link types in `other`.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Rel {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Rel {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Renderable for Rel {
+    #[inline]
+    fn render_to(self, output: &mut String) {
+        let mut first = true;
+
+        for (rel, keyword) in Self::KEYWORDS {
+            if self.contains(rel) {
+                if !first {
+                    output.push(' ');
+                }
+                output.push_str(keyword);
+                first = false;
+            }
+        }
+    }
+}
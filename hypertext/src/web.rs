@@ -56,12 +56,37 @@ mod poem_support {
 
     use poem::{web::Html, IntoResponse, Response};
 
-    use crate::Rendered;
+    use crate::{Raw, Rendered};
 
+    /// Responds with the HTML and a `Content-Type` of `text/html;
+    /// charset=utf-8`, so handlers can return rendered pages directly.
+    ///
+    /// Other statuses can be set with [`IntoResponse::with_status`], and
+    /// errors can be handled by returning a [`poem::Result`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hypertext::{html_elements, maud, Renderable, Rendered};
+    /// use poem::{handler, web::Path};
+    ///
+    /// #[handler]
+    /// fn hello(Path(name): Path<String>) -> Rendered<String> {
+    ///     maud! { h1 { "Hello, " (name) "!" } }.render()
+    /// }
+    /// ```
     impl<T: Into<String> + Send> IntoResponse for Rendered<T> {
         #[inline]
         fn into_response(self) -> Response {
             Html(self.0).into_response()
         }
     }
+
+    /// Responds with the HTML as-is, like [`Rendered`].
+    impl<T: AsRef<str> + Into<String> + Send> IntoResponse for Raw<T> {
+        #[inline]
+        fn into_response(self) -> Response {
+            Html(self.0).into_response()
+        }
+    }
 }
@@ -0,0 +1,13 @@
+use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+fn main() {
+    let name = "Alice";
+
+    let _ = maud! {
+        div #greeting title="Greeting" {
+            h1 { "Hello, " (name) "!" }
+            p { "Welcome." }
+        }
+    }
+    .render();
+}
@@ -0,0 +1,31 @@
+//! Tests for the minimal expansion generated with `--cfg hypertext_ide`.
+//!
+//! Run with `RUSTFLAGS="--cfg hypertext_ide" cargo test --test ide`.
+#![cfg(hypertext_ide)]
+
+use hypertext::Renderable;
+
+#[test]
+fn only_dynamic_parts() {
+    let name = "<Alice>";
+    let count: u32 = 3;
+
+    // checks are skipped, so unknown elements are accepted
+    assert_eq!(
+        hypertext::maud! {
+            not_an_element title=(name) {
+                @for i in 0..count {
+                    span { (i + 1) }
+                }
+            }
+        }
+        .render(),
+        "&lt;Alice&gt;123"
+    );
+
+    assert_eq!(
+        hypertext::rsx! { <p class="static">{name.len()}</p> }.render(),
+        "7"
+    );
+    assert_eq!(hypertext::maud_static! { p { "static" } }, "");
+}
@@ -106,3 +106,3213 @@ fn elements_macro() {
         r#"<div><my_element my_attribute="test">Hello, world!</my_element></div>"#
     );
 }
+
+#[test]
+fn raw_attributes() {
+    use hypertext::{html_elements, GlobalAttributes, Raw, Renderable};
+
+    const CLASSES: Raw<&str> = Raw("card card&#45;wide");
+    const STYLE: &str = Raw("color: red").0;
+    const CLASSES_LEN: usize = CLASSES.as_inner().len();
+
+    assert_eq!(CLASSES_LEN, 18);
+    assert_eq!(STYLE, "color: red");
+    assert_eq!(CLASSES.into_inner(), "card card&#45;wide");
+
+    assert_eq!(
+        hypertext::maud! { div class=(CLASSES) style=(Raw(STYLE)) { "body" } }.render(),
+        "<div class=\"card card&#45;wide\" style=\"color: red\">body</div>"
+    );
+    assert_eq!(
+        hypertext::rsx! { <div class=CLASSES>body</div> }.render(),
+        "<div class=\"card card&#45;wide\">body</div>"
+    );
+}
+
+#[test]
+fn attribute_value_functions() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    fn style(color: &str, size: u8) -> impl Renderable + '_ {
+        hypertext::maud_move! { "color: " (color) "; font-size: " (size) "px" }
+    }
+
+    assert_eq!(
+        hypertext::maud! { p style=(style("red", 12)) { "text" } }.render(),
+        "<p style=\"color: red; font-size: 12px\">text</p>"
+    );
+    assert_eq!(
+        hypertext::rsx! { <p style=style("\"blue\"", 14)>text</p> }.render(),
+        "<p style=\"color: &quot;blue&quot;; font-size: 14px\">text</p>"
+    );
+}
+
+#[test]
+fn namespaced_elements() {
+    use hypertext::{GlobalAttributes, Renderable};
+
+    mod html_elements {
+        use hypertext::elements;
+
+        elements! {
+            /// An SVG `use` element, written with its namespace prefix.
+            svg_use {
+                /// Reference to the element to duplicate
+                href
+            }
+
+            /// An SVG `g` element, written with its namespace prefix.
+            svg_g
+        }
+
+        impl svg_use {
+            /// The `xlink` namespace.
+            #[allow(non_upper_case_globals)]
+            pub const xlink: hypertext::AttributeNamespace = hypertext::AttributeNamespace;
+        }
+
+        impl hypertext::VoidElement for svg_use {}
+    }
+
+    let expected =
+        "<svg:g class=\"icons\"><svg:use href=\"#icon\"><svg:use xlink:href=\"#other\"></svg:g>";
+
+    assert_eq!(
+        hypertext::rsx! {
+            <svg:g class="icons">
+                <svg:use href="#icon"/>
+                <svg:use xlink:href="#other"/>
+            </svg:g>
+        }
+        .render(),
+        expected
+    );
+    assert_eq!(
+        hypertext::maud! {
+            svg:g.icons {
+                svg:use href="#icon";
+                svg:use xlink:href="#other";
+            }
+        }
+        .render(),
+        expected
+    );
+}
+
+#[test]
+fn nested_options_and_vecs() {
+    use hypertext::{html_elements, Renderable};
+
+    let badge = |text| hypertext::maud_move! { b { (text) } };
+
+    assert_eq!(Some(Some(badge("x"))).render(), "<b>x</b>");
+    assert_eq!(Some(None::<&str>).render(), "");
+    assert_eq!(None::<Option<&str>>.render(), "");
+
+    let items = vec![Some("a"), None, Some("<c>"), None];
+    assert_eq!(
+        hypertext::maud! { p { (items) } }.render(),
+        "<p>a&lt;c&gt;</p>"
+    );
+
+    let sections = vec![vec![Some(badge("1")), None], vec![], vec![Some(badge("2"))]];
+    assert_eq!(sections.render(), "<b>1</b><b>2</b>");
+}
+
+#[test]
+fn assert_no_html() {
+    use hypertext::{html_elements, Raw, Renderable};
+
+    const TITLE: Raw<&str> = hypertext::assert_no_html("My Site: 'Home'");
+
+    assert_eq!(
+        hypertext::maud! { title { (TITLE) } }.render(),
+        "<title>My Site: 'Home'</title>"
+    );
+
+    let result = std::panic::catch_unwind(|| hypertext::assert_no_html("a < b"));
+    assert!(result.is_err());
+}
+
+#[test]
+fn escape_quotes() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    let value = r#"x" onclick="alert('hi')"#;
+
+    assert_eq!(
+        hypertext::maud! { div title=(value) { (value) } }.render(),
+        r#"<div title="x&quot; onclick=&quot;alert('hi')">x&quot; onclick=&quot;alert('hi')</div>"#
+    );
+    assert_eq!(
+        hypertext::rsx! { <div title={value}>{value}</div> }.render(),
+        r#"<div title="x&quot; onclick=&quot;alert('hi')">x&quot; onclick=&quot;alert('hi')</div>"#
+    );
+}
+
+#[test]
+fn raw_rendered() {
+    use std::borrow::Cow;
+
+    use hypertext::{Raw, Rendered};
+
+    const STATIC: Rendered<&str> = Raw("<b>static</b>").into_rendered();
+
+    assert_eq!(STATIC, "<b>static</b>");
+    assert_eq!(Raw("<b>str</b>").rendered(), "<b>str</b>");
+    assert_eq!(
+        Raw(String::from("<b>string</b>")).rendered(),
+        Rendered(String::from("<b>string</b>"))
+    );
+    assert_eq!(
+        Raw(Cow::Borrowed("<b>cow</b>")).rendered().as_str(),
+        "<b>cow</b>"
+    );
+    assert_eq!(Rendered::<String>::default().as_str(), "");
+
+    let html = String::from("<b>moved</b>");
+    let ptr = html.as_ptr();
+    let rendered = Raw(html).rendered();
+    assert_eq!(rendered.as_str().as_ptr(), ptr);
+
+    let html = String::from("<b>converted</b>");
+    let ptr = html.as_ptr();
+    let rendered = Rendered::from(Raw(html));
+    assert_eq!(rendered.as_str().as_ptr(), ptr);
+}
+
+#[test]
+fn doctype() {
+    use hypertext::{html_elements, Renderable};
+
+    assert_eq!(
+        hypertext::maud! { !DOCTYPE html {} }.render(),
+        "<!DOCTYPE html><html></html>"
+    );
+    assert_eq!(
+        hypertext::rsx! { <!DOCTYPE html><html></html> }.render(),
+        "<!DOCTYPE html><html></html>"
+    );
+
+    assert_eq!(
+        hypertext::maud! {
+            !DOCTYPE r#"html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd""#
+            html {}
+        }
+        .render(),
+        r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd"><html></html>"#
+    );
+    assert_eq!(
+        hypertext::rsx! {
+            <!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd">
+            <html></html>
+        }
+        .render(),
+        r#"<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.0 Strict//EN" "http://www.w3.org/TR/xhtml1/DTD/xhtml1-strict.dtd"><html></html>"#
+    );
+}
+
+#[cfg(feature = "components")]
+#[test]
+fn open_graph() {
+    use hypertext::{components::meta::OpenGraph, Renderable};
+
+    let full = OpenGraph {
+        title: Some("Title".into()),
+        description: Some(r#"A "quoted" <description> & more"#.into()),
+        url: Some("https://example.com/?a=1&b=2".into()),
+        image: Some("https://example.com/image.png".into()),
+        site_name: Some("Example".into()),
+        type_: Some("article".into()),
+    };
+
+    assert_eq!(
+        full.render(),
+        concat!(
+            r#"<meta property="og:title" content="Title">"#,
+            r#"<meta property="og:type" content="article">"#,
+            r#"<meta property="og:url" content="https://example.com/?a=1&amp;b=2">"#,
+            r#"<meta property="og:image" content="https://example.com/image.png">"#,
+            r#"<meta property="og:description" content="A &quot;quoted&quot; &lt;description&gt; &amp; more">"#,
+            r#"<meta property="og:site_name" content="Example">"#,
+        )
+    );
+
+    let minimal = OpenGraph {
+        title: Some("Title".into()),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        minimal.render(),
+        r#"<meta property="og:title" content="Title">"#
+    );
+    assert_eq!(OpenGraph::default().render(), "");
+}
+
+#[test]
+fn data_attributes() {
+    use hypertext::{html_elements, Renderable};
+
+    assert_eq!(
+        hypertext::maud! { div data-user-id="1" data-2fa="on" {} }.render(),
+        r#"<div data-user-id="1" data-2fa="on"></div>"#
+    );
+    assert_eq!(
+        hypertext::rsx! { <div data-user-id="1"></div> }.render(),
+        r#"<div data-user-id="1"></div>"#
+    );
+}
+
+#[test]
+fn multiline_literals() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    assert_eq!(
+        hypertext::maud! {
+            div title="first line\n    second line" {
+                "some\n    text"
+            }
+        }
+        .render(),
+        "<div title=\"first line\n    second line\">some\n    text</div>"
+    );
+    assert_eq!(
+        hypertext::rsx! {
+            <div title="first line\n    second line"></div>
+        }
+        .render(),
+        "<div title=\"first line\n    second line\"></div>"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn paths() {
+    use std::path::{Path, PathBuf};
+
+    use hypertext::{html_elements, Renderable};
+
+    let path = Path::new("files/<a & \"b\">.txt");
+    let path_buf = PathBuf::from(path);
+
+    assert_eq!(
+        hypertext::maud! { a href=(path) { (path) } }.render(),
+        r#"<a href="files/&lt;a &amp; &quot;b&quot;&gt;.txt">files/&lt;a &amp; &quot;b&quot;&gt;.txt</a>"#
+    );
+    assert_eq!(
+        hypertext::maud! { a href=(&path_buf) { (path_buf) } }.render(),
+        r#"<a href="files/&lt;a &amp; &quot;b&quot;&gt;.txt">files/&lt;a &amp; &quot;b&quot;&gt;.txt</a>"#
+    );
+}
+
+#[cfg(feature = "theme")]
+#[test]
+fn theme() {
+    use hypertext::{
+        html_elements,
+        theme::{Theme, Var},
+        GlobalAttributes, Renderable,
+    };
+
+    let light = Theme::new().with("bg", "white").with("fg", "black");
+    let dark = Theme::new().with("bg", "black").with("fg", r#""white""#);
+
+    let page = || {
+        hypertext::maud! {
+            div style={ "background: " (Var::new("bg")) "; color: " (Var::new("fg")) } {
+                span.accent style={ "color: " (Var::new("accent").with_default("red")) } {}
+            }
+        }
+    };
+
+    assert_eq!(
+        page().render_with(&light),
+        r#"<div style="background: white; color: black"><span class="accent" style="color: red"></span></div>"#
+    );
+    assert_eq!(
+        page().render_with(&dark),
+        r#"<div style="background: black; color: &quot;white&quot;"><span class="accent" style="color: red"></span></div>"#
+    );
+    assert_eq!(
+        page().render(),
+        r#"<div style="background: ; color: "><span class="accent" style="color: red"></span></div>"#
+    );
+
+    let nested = hypertext::maud! {
+        (Var::new("fg"))
+        (|output: &mut String| output.push_str(&Var::new("fg").render_with(&dark).into_inner()))
+        (Var::new("fg"))
+    };
+
+    assert_eq!(nested.render_with(&light), "black&quot;white&quot;black");
+}
+
+#[test]
+fn newlines() {
+    use hypertext::{html_elements, maud_static, rsx_static, Renderable};
+
+    let name = "Alice";
+
+    assert_eq!(
+        hypertext::maud! {
+            h1 { "Hello" }
+            "Hi!"
+            p { span { "a" } span { (name) } }
+        }
+        .render(),
+        "<h1>Hello</h1>Hi!<p><span>a</span><span>Alice</span></p>"
+    );
+    assert_eq!(
+        hypertext::maud! {
+            #![newlines]
+            h1 { "Hello" }
+            "Hi!"
+            p { span { "a" } span { (name) } }
+        }
+        .render(),
+        "<h1>Hello</h1>\nHi!\n<p><span>a</span><span>Alice</span></p>"
+    );
+    assert_eq!(
+        hypertext::rsx! {
+            #![newlines]
+            <h1>Hello</h1>
+            "Hi!"
+            <p><span>a</span><span>b</span></p>
+        }
+        .render(),
+        "<h1>Hello</h1>\nHi!\n<p><span>a</span><span>b</span></p>"
+    );
+
+    assert_eq!(
+        maud_static! {
+            #![newlines]
+            !DOCTYPE
+            html { head {} body {} }
+        },
+        "<!DOCTYPE html>\n<html><head></head><body></body></html>"
+    );
+    assert_eq!(
+        rsx_static! {
+            #![newlines]
+            <!DOCTYPE html>
+            <html><head></head><body></body></html>
+        },
+        "<!DOCTYPE html>\n<html><head></head><body></body></html>"
+    );
+}
+
+#[test]
+fn attributes_macro() {
+    use hypertext::{html_elements, Renderable};
+
+    hypertext::attributes! {
+        /// Attributes for a small frontend framework.
+        trait FrameworkAttributes {
+            /// Binds some data.
+            x_data
+            x_show
+            /// Listens to an event.
+            x_on:
+        }
+    }
+
+    assert_eq!(
+        hypertext::maud! {
+            div x-data="{ open: false }" {
+                button x-on:click="open = !open" { "Toggle" }
+                p x-show="open" { "Hello!" }
+            }
+        }
+        .render(),
+        r#"<div x-data="{ open: false }"><button x-on:click="open = !open">Toggle</button><p x-show="open">Hello!</p></div>"#
+    );
+}
+
+#[test]
+fn attribute_idents() {
+    use hypertext::{html_elements, validation::normalize_attribute_ident, Renderable};
+
+    for (name, ident) in [
+        ("hx-get", "hx_get"),
+        ("hx-swap-oob", "hx_swap_oob"),
+        ("hx-on:click", "hx_on"),
+        ("hx-on::after-request", "hx_on"),
+        ("x-on:click.prevent", "x_on"),
+        ("up-target", "up_target"),
+        ("aria-label", "aria_label"),
+        ("type", "r#type"),
+        ("for", "r#for"),
+        ("async", "r#async"),
+        ("self", "_self"),
+        ("3d", "_3d"),
+    ] {
+        assert_eq!(normalize_attribute_ident(name), ident, "{name}");
+    }
+
+    hypertext::attributes! {
+        trait FrameworkAttributes {
+            hx_swap_oob
+            up_target
+            r#async
+            _self
+            x_on:
+        }
+    }
+
+    assert_eq!(
+        hypertext::maud! {
+            div hx-swap-oob="true" up-target="main" async self="1" x-on:click="go()" {}
+        }
+        .render(),
+        r#"<div hx-swap-oob="true" up-target="main" async self="1" x-on:click="go()"></div>"#
+    );
+    assert_eq!(
+        hypertext::rsx! {
+            <div hx-swap-oob="true" up-target="main" async self="1" x-on:click="go()"></div>
+        }
+        .render(),
+        r#"<div hx-swap-oob="true" up-target="main" async self="1" x-on:click="go()"></div>"#
+    );
+}
+
+#[test]
+fn separated_by() {
+    use hypertext::{html_elements, Renderable};
+
+    let list = |items: &[&str]| {
+        hypertext::maud! {
+            p {
+                @for &item in items {
+                    b { (item) }
+                } @separated_by {
+                    ", "
+                }
+            }
+        }
+        .render()
+    };
+
+    assert_eq!(list(&[]), "<p></p>");
+    assert_eq!(list(&["a"]), "<p><b>a</b></p>");
+    assert_eq!(
+        list(&["a", "b", "c"]),
+        "<p><b>a</b>, <b>b</b>, <b>c</b></p>"
+    );
+
+    let mut countdown = (1..=3).rev().peekable();
+
+    assert_eq!(
+        hypertext::maud! {
+            @while countdown.peek().is_some() {
+                (countdown.next())
+            } @separated_by {
+                @for _ in 0..2 { "." } @separated_by { " " }
+            }
+        }
+        .render(),
+        "3. .2. .1"
+    );
+}
+
+#[test]
+fn attribute_set() {
+    use hypertext::{html_elements, AttributeSet, Renderable};
+
+    #[derive(AttributeSet)]
+    #[attr(check = a, check = area)]
+    struct LinkAttrs {
+        href: String,
+        rel: Option<String>,
+        #[attr(rename = "target")]
+        target_: Option<&'static str>,
+        data_index: u32,
+    }
+
+    let link = |rel: Option<&str>, target_| LinkAttrs {
+        href: "/search?q=<rust>&lang=en".into(),
+        rel: rel.map(Into::into),
+        target_,
+        data_index: 1,
+    };
+
+    assert_eq!(
+        hypertext::maud! {
+            a.link ..(link(Some("noopener \"x\""), Some("_blank"))) { "Search" }
+        }
+        .render(),
+        r#"<a class="link" href="/search?q=&lt;rust&gt;&amp;lang=en" rel="noopener &quot;x&quot;" target="_blank" data-index="1">Search</a>"#
+    );
+    assert_eq!(
+        hypertext::maud! {
+            map name="m" {
+                area shape="rect" ..(link(None, None));
+            }
+        }
+        .render(),
+        r#"<map name="m"><area shape="rect" href="/search?q=&lt;rust&gt;&amp;lang=en" data-index="1"></map>"#
+    );
+}
+
+#[test]
+fn default_components() {
+    use hypertext::{html_elements, GlobalAttributes, Raw, Renderable};
+
+    #[derive(Default)]
+    struct Alert<R: Renderable = ()> {
+        title: String,
+        icon: Raw<&'static str>,
+        children: R,
+    }
+
+    impl<R: Renderable> Renderable for Alert<R> {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud! {
+                div.alert {
+                    (self.icon)
+                    strong { (self.title) }
+                    (self.children)
+                }
+            }
+            .render_to(output);
+        }
+    }
+
+    let alert: Alert = Alert {
+        title: "Heads up!".into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        alert.render(),
+        r#"<div class="alert"><strong>Heads up!</strong></div>"#
+    );
+
+    let alert: Alert = Alert {
+        icon: "<i>!</i>".into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        alert.render(),
+        r#"<div class="alert"><i>!</i><strong></strong></div>"#
+    );
+}
+
+#[test]
+fn stored_borrows() {
+    use hypertext::{html_elements, Renderable};
+
+    struct Layout<R: Renderable> {
+        body: R,
+    }
+
+    struct Section<'a> {
+        heading: &'a str,
+        body: Box<dyn FnOnce(&mut String) + 'a>,
+    }
+
+    impl<R: Renderable> Renderable for Layout<R> {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud! { main { (self.body) } }.render_to(output);
+        }
+    }
+
+    impl Renderable for Section<'_> {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud! { section { h2 { (self.heading) } (self.body) } }.render_to(output);
+        }
+    }
+
+    let items = vec![String::from("one"), String::from("two")];
+
+    let layout = Layout {
+        body: hypertext::maud! {
+            @for item in &items {
+                p { (item) }
+            }
+        },
+    };
+    let section = Section {
+        heading: &items[0],
+        body: Box::new(hypertext::maud! { (items.len()) " items" }),
+    };
+
+    assert_eq!(layout.render(), "<main><p>one</p><p>two</p></main>");
+    assert_eq!(section.render(), "<section><h2>one</h2>2 items</section>");
+    assert_eq!(items.len(), 2);
+}
+
+#[cfg(feature = "stimulus")]
+#[test]
+fn stimulus() {
+    use hypertext::{
+        html_elements,
+        stimulus::{target_attr, Action, Controller, Target},
+        GlobalAttributes, Renderable,
+    };
+
+    let dropdown = |label: &str| {
+        let menu = Target::new("dropdown", "menu");
+
+        hypertext::maud_move! {
+            div.dropdown data-controller=(Controller("dropdown")) {
+                button
+                    type="button"
+                    data-action=(Action::new("click", "dropdown", "toggle"))
+                    ..(Target::new("dropdown", "button"))
+                {
+                    (label)
+                }
+                ul hidden ..(menu) {
+                    li { "Item" }
+                }
+            }
+        }
+        .render()
+    };
+
+    assert_eq!(
+        dropdown("Options"),
+        r#"<div class="dropdown" data-controller="dropdown"><button type="button" data-action="click-&gt;dropdown#toggle" data-dropdown-target="button">Options</button><ul hidden data-dropdown-target="menu"><li>Item</li></ul></div>"#
+    );
+    assert_eq!(target_attr("dropdown"), "data-dropdown-target");
+    assert_eq!(Target::new("dropdown", "menu").render(), "menu");
+    assert_eq!(
+        Action {
+            event: "keydown.enter",
+            controller: "search",
+            method: "submit",
+        }
+        .render(),
+        "keydown.enter-&gt;search#submit"
+    );
+}
+
+#[cfg(all(feature = "stimulus", debug_assertions))]
+#[test]
+#[should_panic = "invalid Stimulus controller identifier `x\" onclick=\"alert(1)`"]
+fn stimulus_hostile_controller() {
+    use hypertext::{html_elements, maud, stimulus::Target, Renderable};
+
+    let controller = String::from(r#"x" onclick="alert(1)"#);
+
+    let _ = maud! { ul ..(Target::new(&controller, "menu")) {} }.render();
+}
+
+#[cfg(all(feature = "stimulus", not(debug_assertions)))]
+#[test]
+fn stimulus_hostile_controller() {
+    use hypertext::{html_elements, maud, stimulus::Target, Renderable};
+
+    let controller = String::from(r#"x" onclick="alert(1)"#);
+
+    assert_eq!(
+        maud! { ul ..(Target::new(&controller, "menu")) {} }.render(),
+        "<ul></ul>"
+    );
+}
+
+#[test]
+fn xhtml() {
+    use hypertext::{html_elements, maud_static, rsx_static, Renderable};
+
+    let src = "/logo.png";
+
+    assert_eq!(
+        hypertext::maud! {
+            #![xhtml]
+            p { "a" br; "b" }
+            img src=(src) alt="Logo";
+        }
+        .render(),
+        r#"<p>a<br />b</p><img src="/logo.png" alt="Logo" />"#
+    );
+    assert_eq!(
+        hypertext::rsx! {
+            #![xhtml]
+            <p>a<br>b</p>
+            <img src=src alt="Logo" />
+        }
+        .render(),
+        r#"<p>a<br />b</p><img src="/logo.png" alt="Logo" />"#
+    );
+    assert_eq!(
+        maud_static! {
+            #![newlines, xhtml]
+            hr;
+            br;
+        },
+        "<hr />\n<br />"
+    );
+    assert_eq!(rsx_static! { #![xhtml] <hr> }, "<hr />");
+    assert_eq!(hypertext::maud! { br; }.render(), "<br>");
+}
+
+#[cfg(feature = "ammonia")]
+#[test]
+fn sanitize() {
+    use hypertext::{html_elements, Raw, Renderable};
+
+    let comment = Raw::sanitize(
+        r#"<p onclick="steal()">Nice <em>post</em>! <a href="javascript:alert(1)">link</a></p><script>alert("xss")</script>"#,
+    );
+
+    assert_eq!(
+        hypertext::maud! { div { (comment) } }.render(),
+        r#"<div><p>Nice <em>post</em>! <a rel="noopener noreferrer">link</a></p></div>"#
+    );
+    assert_eq!(
+        Raw::sanitize("<ul><li><code>a &lt; b</code></li></ul>").render(),
+        "<ul><li><code>a &lt; b</code></li></ul>"
+    );
+}
+
+#[test]
+fn escaping_contexts() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    let tricky = r#"a&b "c" <d> 'e'/f"#;
+    let escaped = "a&amp;b &quot;c&quot; &lt;d&gt; 'e'/f";
+
+    assert_eq!(
+        hypertext::maud! {
+            div #{ "a&b \"c\" <d> 'e'/f" } .{ "a&b \"c\" <d> 'e'/f" } title="a&b \"c\" <d> 'e'/f" {
+                "a&b \"c\" <d> 'e'/f"
+            }
+        }
+        .render(),
+        hypertext::maud! {
+            div #(tricky) .(tricky) title=(tricky) {
+                (tricky)
+            }
+        }
+        .render()
+    );
+    assert_eq!(
+        hypertext::maud! { div #(tricky) .(tricky) title=(tricky) { (tricky) } }
+            .render()
+            .into_inner(),
+        format!(r#"<div id="{escaped}" class="{escaped}" title="{escaped}">{escaped}</div>"#)
+    );
+    assert_eq!(
+        hypertext::rsx! { <div id="a&b \"c\" <d> 'e'/f" title=tricky>"a&b \"c\" <d> 'e'/f"</div> }
+            .render()
+            .into_inner(),
+        format!(r#"<div id="{escaped}" title="{escaped}">{escaped}</div>"#)
+    );
+
+    for c in tricky.chars() {
+        assert_eq!(c.render(), c.to_string().render(), "{c:?}");
+    }
+
+    assert_eq!(
+        hypertext::maud! { (1_000) " " (5u8) " " (true) " " ('&') }.render(),
+        hypertext::maud! { (1_000) " " 5u8 " " true " " "&" }.render()
+    );
+    assert_eq!(
+        hypertext::maud! { p title=1_000 { 1_000 } }.render(),
+        hypertext::maud! { p title=(1_000) { (1_000) } }.render()
+    );
+    assert_eq!(
+        hypertext::rsx! { <p title=1_000>{1_000}</p> }.render(),
+        r#"<p title="1000">1000</p>"#
+    );
+}
+
+#[test]
+fn display_adapter() {
+    use core::fmt::Write;
+
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    let items = ["a & b", "<c>"];
+    let list = hypertext::maud! {
+        ul.items {
+            @for item in items {
+                li { (item) }
+            }
+        }
+    };
+
+    assert_eq!(format!("{}", list.as_display()), list.render().into_inner());
+
+    let mut output = String::from("<!-- list -->\n");
+    write!(output, "{}", list.as_display()).unwrap();
+
+    assert_eq!(
+        output,
+        "<!-- list -->\n<ul class=\"items\"><li>a &amp; b</li><li>&lt;c&gt;</li></ul>"
+    );
+}
+
+#[cfg(feature = "humantime")]
+#[test]
+fn durations() {
+    use std::time::{Duration, SystemTime};
+
+    use hypertext::{html_elements, Renderable};
+
+    for (duration, expected) in [
+        (Duration::ZERO, "0s"),
+        (Duration::from_millis(32), "32ms"),
+        (Duration::from_secs(200), "3m 20s"),
+        (Duration::from_mins(157), "2h 37m"),
+        (Duration::new(90_061, 5_000_000), "1day 1h 1m 1s 5ms"),
+    ] {
+        assert_eq!(
+            hypertext::maud! { span { (duration) } }
+                .render()
+                .into_inner(),
+            format!("<span>{expected}</span>")
+        );
+    }
+
+    let time = SystemTime::UNIX_EPOCH + Duration::new(1_518_568_087, 123_456_789);
+
+    assert_eq!(
+        hypertext::maud! { time datetime=(time) { "posted" } }.render(),
+        r#"<time datetime="2018-02-14T00:28:07Z">posted</time>"#
+    );
+
+    let after = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+    let before = |secs| SystemTime::UNIX_EPOCH - Duration::from_secs(secs);
+
+    for (time, expected) in [
+        (SystemTime::UNIX_EPOCH, "1970-01-01T00:00:00Z"),
+        (
+            SystemTime::UNIX_EPOCH - Duration::from_millis(1500),
+            "1969-12-31T23:59:58Z",
+        ),
+        (before(2_208_988_800), "1900-01-01T00:00:00Z"),
+        (before(62_135_596_800), "0001-01-01T00:00:00Z"),
+        (before(62_167_219_201), "-0001-12-31T23:59:59Z"),
+        (after(951_782_400), "2000-02-29T00:00:00Z"),
+        (after(253_402_300_799), "9999-12-31T23:59:59Z"),
+        (after(253_402_300_800), "10000-01-01T00:00:00Z"),
+    ] {
+        assert_eq!(time.render(), expected);
+    }
+}
+
+#[test]
+fn reserves_static_len() {
+    use hypertext::{html_elements, Renderable};
+
+    let name = "world";
+    let template = hypertext::maud! {
+        main {
+            h1 { "Hello, " (name) "!" }
+            p { "This paragraph is long enough to need more than one allocation." }
+        }
+    };
+
+    let expected =
+        "<main><h1>Hello, world!</h1><p>This paragraph is long enough to need more than \
+                    one allocation.</p></main>";
+
+    let mut output = String::new();
+    template.render_to(&mut output);
+
+    assert_eq!(output, expected);
+    // at least the static length is reserved up front
+    assert!(output.capacity() >= expected.len() - name.len());
+}
+
+#[test]
+fn components() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    struct Card<H, F, C> {
+        title: &'static str,
+        header: H,
+        footer: F,
+        children: C,
+    }
+
+    impl<H: Renderable, F: Renderable, C: Renderable> Renderable for Card<H, F, C> {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud_move! {
+                article.card title=(self.title) {
+                    header { (self.header) }
+                    (self.children)
+                    footer { (self.footer) }
+                }
+            }
+            .render_to(output);
+        }
+    }
+
+    struct Badge {
+        count: u32,
+    }
+
+    impl Renderable for Badge {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud! { span.badge { (self.count) } }.render_to(output);
+        }
+    }
+
+    let name = "Alice";
+
+    assert_eq!(
+        hypertext::maud! {
+            Card title="Profile" {
+                @slot header { h1 { (name) } }
+                p { "body" }
+                @slot footer { Badge count=3; }
+                p { "more" }
+            }
+        }
+        .render(),
+        "<article class=\"card\" title=\"Profile\"><header><h1>Alice</h1></header><p>body</p><p>more</p><footer><span class=\"badge\">3</span></footer></article>"
+    );
+}
+
+#[test]
+fn component_shorthands() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    struct Card<K, C> {
+        id: &'static str,
+        class: K,
+        children: C,
+    }
+
+    impl<K: Renderable, C: Renderable> Renderable for Card<K, C> {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud_move! {
+                div id=(self.id) class=(self.class) { (self.children) }
+            }
+            .render_to(output);
+        }
+    }
+
+    struct Panel {
+        class: &'static str,
+    }
+
+    impl Renderable for Panel {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud! { aside class=(self.class) {} }.render_to(output);
+        }
+    }
+
+    let theme = "dark";
+    let active = true;
+
+    assert_eq!(
+        hypertext::maud! {
+            Card #main .wide."full-height" { "literal" }
+            Panel .wide.tall;
+        }
+        .render(),
+        "<div id=\"main\" class=\"wide full-height\">literal</div><aside class=\"wide tall\"></aside>"
+    );
+
+    assert_eq!(
+        hypertext::maud! {
+            Card #"side" .card.(theme).active[active].hidden[!active] { "dynamic" }
+        }
+        .render(),
+        "<div id=\"side\" class=\"card dark active\">dynamic</div>"
+    );
+}
+
+#[test]
+fn element_kinds() {
+    use hypertext::{element_kind, ElementKind};
+
+    for void in ["br", "img", "input", "meta", "wbr"] {
+        assert_eq!(element_kind(void), Some(ElementKind::Void), "{void}");
+    }
+
+    for normal in ["div", "html", "script", "textarea", "li"] {
+        assert_eq!(element_kind(normal), Some(ElementKind::Normal), "{normal}");
+    }
+
+    for unknown in ["", "DIV", "my-element", "blink"] {
+        assert_eq!(element_kind(unknown), None, "{unknown}");
+    }
+}
+
+#[test]
+fn keyed_for() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    struct Item {
+        id: &'static str,
+        name: &'static str,
+    }
+
+    let items = [
+        Item {
+            id: "a1",
+            name: "Apple",
+        },
+        Item {
+            id: "\"b&2\"",
+            name: "Banana",
+        },
+    ];
+
+    assert_eq!(
+        hypertext::maud! {
+            ul {
+                @for item in &items keyed by (item.id) {
+                    li.item { (item.name) }
+                } @separated_by { "," }
+            }
+        }
+        .render(),
+        "<ul><li class=\"item\" data-key=\"a1\">Apple</li>,<li class=\"item\" data-key=\"&quot;b&amp;2&quot;\">Banana</li></ul>"
+    );
+}
+
+#[test]
+fn nested_syntaxes() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    let items = ["a", "<b>"];
+
+    assert_eq!(
+        hypertext::maud! {
+            @let title = "List";
+            section {
+                @rsx {
+                    <h1 class="title">{title}</h1>
+                    <ul>
+                        @maud {
+                            @for item in items {
+                                li { (item) }
+                            }
+                        }
+                    </ul>
+                }
+            }
+        }
+        .render(),
+        "<section><h1 class=\"title\">List</h1><ul><li>a</li><li>&lt;b&gt;</li></ul></section>"
+    );
+
+    assert_eq!(
+        hypertext::rsx! {
+            <main>
+                @maud {
+                    @let count = items.len();
+                    p.count {
+                        @rsx { <b>{count}</b> }
+                        " items"
+                    }
+                }
+            </main>
+        }
+        .render(),
+        "<main><p class=\"count\"><b>2</b> items</p></main>"
+    );
+}
+
+#[test]
+fn quoted_names() {
+    use hypertext::{html_elements, Renderable};
+
+    let count = 3;
+
+    assert_eq!(
+        hypertext::maud! {
+            "my-élément" "data-ünïcode"="1" { "Hello" }
+            "x-badge".big "aria-üb"=(count);
+            div "x-€" { "text" }
+        }
+        .render(),
+        "<my-élément data-ünïcode=\"1\">Hello</my-élément><x-badge class=\"big\" aria-üb=\"3\"><div x-€>text</div>"
+    );
+}
+
+#[test]
+fn escape() {
+    use std::borrow::Cow;
+
+    use hypertext::{escape, html_elements, GlobalAttributes, Renderable};
+
+    let value = "a & \"b\" <c> 'd'";
+
+    assert_eq!(
+        hypertext::maud! { p { (value) } }.render().into_inner(),
+        format!("<p>{}</p>", escape::text(value))
+    );
+    assert_eq!(
+        hypertext::maud! { p title=(value) {} }
+            .render()
+            .into_inner(),
+        format!("<p title=\"{}\"></p>", escape::attribute(value))
+    );
+
+    let mut text = String::from("<p>");
+    escape::text_to(value, &mut text);
+    text.push_str("</p>");
+    assert_eq!(
+        hypertext::maud! { p { (value) } }.render().into_inner(),
+        text
+    );
+
+    let mut attribute = String::from("<p title=\"");
+    escape::attribute_to(value, &mut attribute);
+    attribute.push_str("\"></p>");
+    assert_eq!(
+        hypertext::maud! { p title=(value) {} }
+            .render()
+            .into_inner(),
+        attribute
+    );
+
+    assert!(matches!(escape::text("plain"), Cow::Borrowed("plain")));
+}
+
+#[test]
+fn map_rendered() {
+    use hypertext::{html_elements, Raw, Renderable};
+
+    let user = "<Alice>";
+
+    assert_eq!(
+        hypertext::maud! { p { "Hi " (user) ", " (Raw("<!--NONCE-->")) } }
+            .map_rendered(|html| html.replace("<!--NONCE-->", "abc123")),
+        "<p>Hi &lt;Alice&gt;, abc123</p>"
+    );
+}
+
+#[cfg(feature = "experimental-elements")]
+#[test]
+fn experimental_elements() {
+    use hypertext::{html_elements, Renderable};
+
+    assert_eq!(
+        hypertext::maud! {
+            select {
+                button { selectedcontent {} }
+            }
+            fencedframe width=300 {}
+            portal src="/next" {}
+            model src="/teapot.usdz" interactive {}
+        }
+        .render(),
+        "<select><button><selectedcontent></selectedcontent></button></select><fencedframe width=\"300\"></fencedframe><portal src=\"/next\"></portal><model src=\"/teapot.usdz\" interactive></model>"
+    );
+}
+
+#[test]
+fn coalesced_statics() {
+    use hypertext::{html_elements, GlobalAttributes, Renderable};
+
+    let template = hypertext::maud! {
+        header.site {
+            nav { a href="/" { "Home" } " | " a href="/about" { "About" } }
+            { h1 { "Title" } }
+        }
+        hr;
+    };
+
+    let expected = hypertext::maud_static! {
+        header.site {
+            nav { a href="/" { "Home" } " | " a href="/about" { "About" } }
+            { h1 { "Title" } }
+        }
+        hr;
+    };
+
+    let mut output = String::new();
+    template.render_to(&mut output);
+
+    assert_eq!(output, expected.into_inner());
+    // a single static run is reserved up front
+    assert!(output.capacity() >= output.len());
+}
+
+#[test]
+fn cfg() {
+    use hypertext::{html_elements, Renderable};
+
+    let maud_result = hypertext::maud! {
+        "a"
+        @cfg(test) { span { "present" } }
+        @cfg(not(test)) { span { "absent" } }
+        "b"
+    }
+    .render();
+
+    let rsx_result = hypertext::rsx! {
+        a
+        @cfg(test) { <span>present</span> }
+        @cfg(not(test)) { <span>absent</span> }
+        b
+    }
+    .render();
+
+    for result in [maud_result, rsx_result] {
+        assert_eq!(result.into_inner(), "a<span>present</span>b");
+    }
+}
+
+#[test]
+fn attribute_renderable() {
+    use hypertext::{html_elements, AttributeRenderable, GlobalAttributes, Renderable};
+
+    #[derive(AttributeRenderable)]
+    enum Size {
+        #[attribute("sm")]
+        Sm,
+        #[attribute("md")]
+        Md,
+        #[attribute("a \"quoted\" & <odd> value")]
+        Odd,
+        ExtraLarge,
+    }
+
+    let size = Size::Sm;
+
+    assert_eq!(
+        hypertext::maud! {
+            div data-size=(Size::Md) title=(&size) {
+                (Size::Odd) " " (Size::ExtraLarge)
+            }
+        }
+        .render(),
+        "<div data-size=\"md\" title=\"sm\">a &quot;quoted&quot; &amp; &lt;odd&gt; value extra-large</div>"
+    );
+}
+
+#[cfg(feature = "csp")]
+#[test]
+fn csp_nonce() {
+    use hypertext::{
+        csp::{self, CspNonce, Nonce},
+        html_elements, GlobalAttributes, Renderable,
+    };
+
+    let page = || {
+        hypertext::maud! {
+            script nonce=(CspNonce) { "init();" }
+            style nonce=(CspNonce) { "p { color: red; }" }
+        }
+    };
+
+    assert_eq!(
+        csp::with_nonce(Nonce::new("first"), page()).render(),
+        "<script nonce=\"first\">init();</script><style nonce=\"first\">p { color: red; }</style>"
+    );
+    assert_eq!(
+        csp::with_nonce(Nonce::new("\"second\""), page()).render(),
+        "<script nonce=\"&quot;second&quot;\">init();</script><style nonce=\"&quot;second&quot;\">p { color: red; }</style>"
+    );
+    assert_eq!(
+        page().render(),
+        "<script nonce=\"\">init();</script><style nonce=\"\">p { color: red; }</style>"
+    );
+}
+
+#[cfg(feature = "validate")]
+#[test]
+fn validate() {
+    use hypertext::{
+        html_elements,
+        validate::{ValidationError, ValidationErrorKind},
+        GlobalAttributes, Raw, Renderable,
+    };
+
+    struct Field {
+        id: &'static str,
+    }
+
+    impl Renderable for Field {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud! { input id=(self.id) type="text"; }.render_to(output);
+        }
+    }
+
+    let page = hypertext::maud! {
+        !DOCTYPE
+        html {
+            head { title { "a &amp; b" } script { "if (a < b && c) {}" } }
+            body {
+                // a comment
+                p.intro title="Tom & Jerry" { "Tom & Jerry" br; }
+                img src="/a.png" alt="";
+            }
+        }
+    };
+    assert!(page.render_validated().is_ok());
+
+    let unclosed = hypertext::maud! { main { (Raw("<div>")) p { "text" } } };
+    assert_eq!(
+        unclosed.render_validated(),
+        Err(vec![ValidationError {
+            offset: 6,
+            kind: ValidationErrorKind::UnclosedTag { name: "div".into() },
+        }])
+    );
+
+    let duplicate = hypertext::maud! {
+        form { Field id="email"; Field id="email"; }
+    };
+    assert_eq!(
+        duplicate.render_validated(),
+        Err(vec![ValidationError {
+            offset: 36,
+            kind: ValidationErrorKind::DuplicateId {
+                id: "email".into(),
+                first: 6,
+            },
+        }])
+    );
+
+    let malformed = Raw("<p class=x>&nbsp &amp;</b>");
+    assert_eq!(
+        malformed.render_validated().unwrap_err(),
+        [
+            ValidationError {
+                offset: 0,
+                kind: ValidationErrorKind::UnclosedTag { name: "p".into() },
+            },
+            ValidationError {
+                offset: 9,
+                kind: ValidationErrorKind::UnquotedAttribute {
+                    name: "class".into()
+                },
+            },
+            ValidationError {
+                offset: 11,
+                kind: ValidationErrorKind::InvalidEntity,
+            },
+            ValidationError {
+                offset: 22,
+                kind: ValidationErrorKind::UnexpectedClosingTag { name: "b".into() },
+            },
+        ]
+    );
+}
+
+#[cfg(feature = "sri")]
+#[test]
+fn script_sri() {
+    use hypertext::{html_elements, maud, script_sri, Raw, Renderable};
+
+    const SCRIPT: Raw<&str> = script_sri!(src = "tests/fixtures/sri.js");
+
+    assert_eq!(
+        SCRIPT.into_inner(),
+        "<script src=\"tests/fixtures/sri.js\" integrity=\"sha384-DVrf5Cq6csR6XmJZYl32dRZ11oqqgQxf2UhVxezlZfyOd/DtDjefE/bG7kKAsRfN\" crossorigin=\"anonymous\"></script>"
+    );
+
+    assert_eq!(
+        maud! {
+            head { (script_sri!(src = "/static/app.js?v=1&x", file = "tests/fixtures/sri.js")) }
+        }
+        .render(),
+        "<head><script src=\"/static/app.js?v=1&amp;x\" integrity=\"sha384-DVrf5Cq6csR6XmJZYl32dRZ11oqqgQxf2UhVxezlZfyOd/DtDjefE/bG7kKAsRfN\" crossorigin=\"anonymous\"></script></head>"
+    );
+}
+
+#[test]
+fn enumerated_attribute_values() {
+    use hypertext::{html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+    assert_eq!(
+        maud! {
+            div hidden {}
+            div hidden="" {}
+            div hidden="hidden" {}
+            div hidden="until-found" {}
+            div hidden="Until-Found" {}
+        }
+        .render(),
+        r#"<div hidden></div><div hidden=""></div><div hidden="hidden"></div><div hidden="until-found"></div><div hidden="Until-Found"></div>"#
+    );
+
+    assert_eq!(
+        rsx! {
+            <p contenteditable>a</p>
+            <p contenteditable="">b</p>
+            <p contenteditable="true">c</p>
+            <p contenteditable="false">d</p>
+            <p contenteditable="plaintext-only">e</p>
+        }
+        .render(),
+        r#"<p contenteditable>a</p><p contenteditable="">b</p><p contenteditable="true">c</p><p contenteditable="false">d</p><p contenteditable="plaintext-only">e</p>"#
+    );
+
+    for value in ["off", "none", "on", "sentences", "words", "characters"] {
+        assert_eq!(
+            maud! { input autocapitalize=(value); }
+                .render()
+                .into_inner(),
+            format!(r#"<input autocapitalize="{value}">"#)
+        );
+    }
+
+    assert_eq!(
+        maud! { input autocapitalize="words"; }.render(),
+        r#"<input autocapitalize="words">"#
+    );
+
+    // only literals are checked
+    let value = "anything";
+    assert_eq!(
+        maud! { div hidden=(value) {} }.render(),
+        r#"<div hidden="anything"></div>"#
+    );
+}
+
+#[test]
+fn spread_attribute_map() {
+    use std::collections::BTreeMap;
+
+    use hypertext::{html_elements, maud, Attributes, GlobalAttributes, Renderable};
+
+    let attrs = BTreeMap::from([
+        ("aria-label".to_owned(), "Close <dialog>".to_owned()),
+        ("data-id".to_owned(), "1".to_owned()),
+        ("title".to_owned(), r#"say "hi" & bye"#.to_owned()),
+    ]);
+
+    assert_eq!(
+        maud! { div.modal ..(Attributes(&attrs)) { "Hi" } }.render(),
+        r#"<div class="modal" aria-label="Close &lt;dialog&gt;" data-id="1" title="say &quot;hi&quot; &amp; bye">Hi</div>"#
+    );
+
+    let attrs = BTreeMap::from([
+        ("", "empty"),
+        ("a b", "space"),
+        ("c=d", "equals"),
+        ("e\"", "quote"),
+        ("f>", "angle"),
+        ("g/", "slash"),
+        ("h\n", "newline"),
+        ("ok", "kept"),
+    ]);
+
+    assert_eq!(
+        maud! { div ..(Attributes(attrs)) {} }.render(),
+        r#"<div ok="kept"></div>"#
+    );
+}
+
+#[test]
+fn const_raw_len() {
+    use hypertext::{html_elements, maud_static, GlobalAttributes, Raw};
+
+    const NAV: Raw<&str> = Raw(*maud_static! { nav.main { a href="/" { "Home" } } }.as_inner());
+    const EMPTY: Raw<&str> = Raw(*maud_static! {}.as_inner());
+
+    const NAV_LEN: usize = NAV.len();
+    const FIRST_BYTE: u8 = NAV.as_bytes()[0];
+    // const code can pick between fragments based on their contents
+    const FALLBACK: Raw<&str> = if EMPTY.is_empty() { NAV } else { EMPTY };
+
+    assert_eq!(
+        NAV_LEN,
+        r#"<nav class="main"><a href="/">Home</a></nav>"#.len()
+    );
+    const {
+        assert!(!NAV.is_empty());
+        assert!(EMPTY.is_empty());
+    }
+    assert_eq!(EMPTY.len(), 0);
+    assert_eq!(FIRST_BYTE, b'<');
+    assert_eq!(FALLBACK.as_bytes(), NAV.as_bytes());
+}
+
+#[test]
+fn data_attrs() {
+    use std::collections::BTreeMap;
+
+    use hypertext::{html_elements, maud, DataAttrs, GlobalAttributes, Renderable};
+
+    let data = BTreeMap::from([
+        ("action".to_owned(), "click->modal#open".to_owned()),
+        ("controller".to_owned(), "modal".to_owned()),
+        ("modal-title-value".to_owned(), r#"Say "hello""#.to_owned()),
+    ]);
+
+    let expected = r#"<button class="open" data-action="click-&gt;modal#open" data-controller="modal" data-modal-title-value="Say &quot;hello&quot;">Open</button>"#;
+
+    assert_eq!(
+        maud! { button.open ..(DataAttrs(&data)) { "Open" } }.render(),
+        expected
+    );
+    assert_eq!(
+        maud! { button class="open" ..(DataAttrs(data.iter())) { "Open" } }.render(),
+        expected
+    );
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic = "invalid `data-*` attribute key `userId`"]
+fn data_attrs_invalid_key() {
+    use hypertext::{html_elements, maud, DataAttrs, Renderable};
+
+    let _ = maud! { div ..(DataAttrs([("userId", "1")])) {} }.render();
+}
+
+#[test]
+fn maud_const() {
+    use hypertext::{html_elements, maud_const, GlobalAttributes, Raw};
+
+    const COLS: usize = 4;
+    const OFFSET: i32 = -12;
+    const LABEL: &str = r#"<"Grid" & co>"#;
+    const ICON: Raw<&str> = Raw("<i></i>");
+
+    const GRID: Raw<&str> = maud_const! {
+        div class={ "grid grid-cols-" (COLS) } data-offset=(OFFSET) {
+            (ICON) " " (LABEL)
+        }
+    };
+    const NESTED: Raw<&str> = maud_const! { section { (GRID) } };
+
+    assert_eq!(
+        GRID.into_inner(),
+        r#"<div class="grid grid-cols-4" data-offset="-12"><i></i> &lt;&quot;Grid&quot; &amp; co&gt;</div>"#
+    );
+    assert_eq!(
+        NESTED.into_inner(),
+        format!("<section>{}</section>", GRID.into_inner())
+    );
+    assert_eq!(
+        maud_const! { (u128::MAX) " " (i128::MIN) " " (0u8) }.into_inner(),
+        "340282366920938463463374607431768211455 -170141183460469231731687303715884105728 0"
+    );
+    assert_eq!(maud_const! { br; }.into_inner(), "<br>");
+}
+
+#[test]
+fn loop_control() {
+    use hypertext::{html_elements, maud, Renderable};
+
+    let items = ["a", "b", "skip", "c", "d", "e"];
+
+    assert_eq!(
+        maud! {
+            ul {
+                @for (i, item) in items.into_iter().enumerate() {
+                    @if i == 4 {
+                        @break;
+                    }
+                    @if item == "skip" {
+                        @continue;
+                    }
+                    li { (item) }
+                }
+            }
+        }
+        .render(),
+        "<ul><li>a</li><li>b</li><li>c</li></ul>"
+    );
+
+    let rows = [[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+    assert_eq!(
+        maud! {
+            @'rows: for row in rows {
+                @for cell in row {
+                    @if cell == 2 {
+                        @continue 'rows;
+                    }
+                    @if cell == 8 {
+                        @break 'rows;
+                    }
+                    (cell)
+                }
+                ";"
+            }
+        }
+        .render(),
+        "1456;7"
+    );
+
+    let mut n = 0;
+    assert_eq!(
+        maud! {
+            @'count: while n < 10 {
+                @let m = { n += 1; n };
+                @if m > 3 {
+                    @break 'count;
+                }
+                (m)
+            }
+        }
+        .render(),
+        "123"
+    );
+}
+
+#[test]
+fn render_with_capacity() {
+    use hypertext::{html_elements, maud, Renderable};
+
+    let items = ["a", "b", "c"];
+    let page = || maud! { ul { @for item in items { li { (item) } } } };
+
+    let rendered = page().render_with_capacity(1024);
+
+    assert_eq!(rendered, page().render());
+    assert!(rendered.as_inner().capacity() >= 1024);
+
+    // a capacity smaller than the output still renders everything
+    assert_eq!(page().render_with_capacity(1), page().render());
+}
+
+#[test]
+fn forced_elements_and_components() {
+    use hypertext::{maud, GlobalAttributes, Renderable};
+
+    mod html_elements {
+        pub use hypertext::html_elements::*;
+        use hypertext::GlobalAttributes;
+
+        #[allow(dead_code)]
+        pub struct Button;
+
+        impl GlobalAttributes for Button {}
+    }
+
+    struct Button<C> {
+        children: C,
+    }
+
+    impl<C: Renderable> Renderable for Button<C> {
+        fn render_to(self, output: &mut String) {
+            hypertext::maud_move! { button.btn { (self.children) } }.render_to(output);
+        }
+    }
+
+    #[allow(non_camel_case_types)]
+    struct badge {
+        label: &'static str,
+    }
+
+    impl Renderable for badge {
+        fn render_to(self, output: &mut String) {
+            maud! { span.badge { (self.label) } }.render_to(output);
+        }
+    }
+
+    assert_eq!(
+        maud! {
+            Button { "Component" }
+            @element Button id="b" { "Element" }
+            @component badge label="new";
+        }
+        .render(),
+        r#"<button class="btn">Component</button><Button id="b">Element</Button><span class="badge">new</span>"#
+    );
+}
+
+#[test]
+fn copy_children() {
+    use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+    struct Twice<C> {
+        children: C,
+    }
+
+    impl<C: Renderable + Copy> Renderable for Twice<C> {
+        fn render_to(self, output: &mut String) {
+            self.children.render_to(output);
+            self.children.render_to(output);
+        }
+    }
+
+    struct Repeat<C> {
+        times: usize,
+        children: C,
+    }
+
+    impl<C: Renderable + Clone> Renderable for Repeat<C> {
+        fn render_to(self, output: &mut String) {
+            for _ in 0..self.times {
+                self.children.clone().render_to(output);
+            }
+        }
+    }
+
+    let name = String::from("Ferris");
+
+    // only borrows `name`, so the children are `Copy`
+    assert_eq!(
+        maud! { Twice { span.hi { "Hi " (&name) } } }.render(),
+        r#"<span class="hi">Hi Ferris</span><span class="hi">Hi Ferris</span>"#
+    );
+
+    // moves `owned` in, so the children are only `Clone`
+    let owned = name.clone();
+    assert_eq!(
+        maud! { Repeat times=(3) { b { (owned) } } }.render(),
+        "<b>Ferris</b><b>Ferris</b><b>Ferris</b>"
+    );
+
+    // rendering on another thread only needs the borrowed values to be `Sync`
+    let page = maud! { p #greeting { "Hello, " (&name) } };
+    let rendered = std::thread::scope(|scope| {
+        let handle = scope.spawn(move || page.render());
+        (page.render(), handle.join().unwrap())
+    });
+    assert_eq!(rendered.0, rendered.1);
+    assert_eq!(rendered.0, r#"<p id="greeting">Hello, Ferris</p>"#);
+}
+
+#[test]
+fn css() {
+    use hypertext::{css, html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+    let accent = "#ff0000";
+    let width = 3;
+
+    assert_eq!(
+        maud! {
+            div style=(css! {
+                --accent: (accent);
+                border: (width) "px solid var(--accent)";
+                margin-top: 1.5rem;
+                font-family: r#""Fira Sans", sans-serif"#;
+            }) {}
+        }
+        .render(),
+        r#"<div style="--accent: #ff0000; border: 3px solid var(--accent); margin-top: 1.5rem; font-family: &quot;Fira Sans&quot;, sans-serif"></div>"#
+    );
+
+    let evil = r#"red"><script>"#;
+    let style = css! { color: (evil) };
+
+    assert_eq!(
+        rsx! { <p style=style>Hi</p> }.render(),
+        r#"<p style="color: red&quot;&gt;&lt;script&gt;">Hi</p>"#
+    );
+
+    assert_eq!(css! { display: "none"; }.render(), "display: none");
+    assert_eq!(css! {}.render(), "");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn render_parallel() {
+    use hypertext::{html_elements, maud_move, render_parallel, GlobalAttributes, Renderable};
+
+    let section = |i: usize| maud_move! { section #(format!("s{i}")) { h2 { "Section " (i) } } };
+
+    for count in [0, 1, 2, 7, 100] {
+        let parallel = render_parallel((0..count).map(section).collect());
+        let sequential = (0..count).map(|i| section(i).render()).collect::<Vec<_>>();
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(
+            parallel
+                .into_iter()
+                .map(hypertext::Rendered::into_inner)
+                .collect::<String>(),
+            maud_move! { @for i in 0..count { (section(i)) } }
+                .render()
+                .into_inner()
+        );
+    }
+}
+
+#[cfg(all(feature = "csp", feature = "htmx", feature = "theme"))]
+#[test]
+fn render_parallel_scopes() {
+    use std::fmt::Write;
+
+    use hypertext::{
+        csp::{self, CspNonce, Nonce},
+        html_elements,
+        htmx::{fragment, FragmentRegistry},
+        maud_move, render_parallel,
+        theme::{Theme, Var},
+        Renderable,
+    };
+
+    fn parallel<R: Renderable + Send>(items: Vec<R>) -> impl Renderable {
+        move |output: &mut String| {
+            for rendered in render_parallel(items) {
+                output.push_str(&rendered.into_inner());
+            }
+        }
+    }
+
+    let item = |i: usize| maud_move! { (i) ":" (Var::new("fg")) ":" (CspNonce) " " };
+    let expected = (0..100).fold(String::new(), |mut expected, i| {
+        write!(expected, "{i}:black:n ").unwrap();
+        expected
+    });
+
+    let page = csp::with_nonce(Nonce::new("n"), parallel((0..100).map(item).collect()));
+
+    assert_eq!(
+        page.render_with(&Theme::new().with("fg", "black")),
+        expected.as_str()
+    );
+
+    let mut registry = FragmentRegistry::new();
+    let page = registry.render(parallel(
+        (0..100)
+            .map(|i| fragment(format!("f{i}"), maud_move! { p { (i) } }))
+            .collect(),
+    ));
+
+    assert!(page
+        .as_inner()
+        .starts_with(r#"<p id="f0">0</p><p id="f1">1</p>"#));
+    assert!((0..100).all(|i| registry.contains(&format!("f{i}"))));
+}
+
+#[test]
+fn shorthand_values_match_attribute_values() {
+    use hypertext::{html_elements, maud, Renderable};
+
+    for (wide, i) in [(true, 1), (false, 2)] {
+        let shorthand = maud! {
+            div #{ "item-" (i) @if wide { "-wide" } } .{ @if wide { "wide" } @else { "narrow" } } {}
+            p #{ @match i { 1 => "one", _ => { "other-" (i) } } } .{ "n-" (i) true } {}
+        }
+        .render();
+        let attributes = maud! {
+            div id={ "item-" (i) @if wide { "-wide" } } class={ @if wide { "wide" } @else { "narrow" } } {}
+            p id={ @match i { 1 => "one", _ => { "other-" (i) } } } class={ "n-" (i) true } {}
+        }
+        .render();
+
+        assert_eq!(shorthand, attributes);
+    }
+
+    assert_eq!(
+        maud! { div #{ @if true { "a" } @else { "b" } } .{ "c" } {} }.render(),
+        r#"<div id="a" class="c"></div>"#,
+    );
+}
+
+#[test]
+fn wrap_if() {
+    use hypertext::{html_elements, maud, maud_move, Renderable, WrapIf};
+
+    let item = |name: &'static str, href: Option<&'static str>| {
+        maud_move! { span { (name) } }.wrap_if(href.is_some(), |inner| {
+            maud_move! { a href=[href] { (inner) } }
+        })
+    };
+
+    assert!(matches!(item("Home", Some("/")), WrapIf::Wrapped(_)));
+    assert!(matches!(item("Home", None), WrapIf::Bare(_)));
+
+    assert_eq!(
+        maud! { nav { (item("Home", Some("/"))) (item("<Draft>", None)) } }.render(),
+        r#"<nav><a href="/"><span>Home</span></a><span>&lt;Draft&gt;</span></nav>"#,
+    );
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn markdown() {
+    use hypertext::{html_elements, markdown_static, maud, Markdown, Raw, Renderable};
+
+    const CHANGELOG: Raw<&str> = markdown_static!(file = "tests/fixtures/changelog.md");
+    const NOTE: Raw<&str> = markdown_static!("Call `a < b` first[^1].\n\n[^1]: Really.");
+
+    let table = "| Name | Age |\n| --- | --: |\n| Ada & Bob | 30 |";
+
+    assert_eq!(
+        Markdown(table).render(),
+        "<table><thead><tr><th>Name</th><th style=\"text-align: right\">Age</th></tr></thead><tbody>\n\
+         <tr><td>Ada &amp; Bob</td><td style=\"text-align: right\">30</td></tr>\n\
+         </tbody></table>\n",
+    );
+    assert_eq!(
+        maud! { p { (Markdown(String::from("`x < y` & ~~z~~"))) } }.render(),
+        "<p><p><code>x &lt; y</code> &amp; <del>z</del></p>\n</p>",
+    );
+    assert_eq!(
+        CHANGELOG.render(),
+        "<h1>Changelog</h1>\n<ul>\n<li>Added <del>nothing</del> <em>everything</em>.</li>\n</ul>\n",
+    );
+    assert!(NOTE
+        .0
+        .starts_with("<p>Call <code>a &lt; b</code> first<sup"));
+    assert_eq!(
+        Markdown("<b>trusted</b>").render(),
+        "<p><b>trusted</b></p>\n"
+    );
+}
+
+#[test]
+fn void_element_siblings() {
+    use hypertext::{html_elements, maud, rsx, Renderable};
+
+    let expected = r#"<figure><img src="x">caption<br><span>a</span><input type="text">1</figure>"#;
+
+    assert_eq!(
+        maud! { img src="x"; "caption" }.render(),
+        r#"<img src="x">caption"#
+    );
+    assert_eq!(
+        maud! { figure { img src="x"; "caption" br; span { "a" } input type="text"; (1) } }
+            .render(),
+        expected,
+    );
+    assert_eq!(
+        rsx! { <figure><img src="x">caption<br><span>a</span><input type="text" />{1}</figure> }
+            .render(),
+        expected,
+    );
+}
+
+#[test]
+fn render_buffer() {
+    use hypertext::{html_elements, maud, RenderBuffer, Renderable};
+
+    let header = maud! { header { h1 { "Title" } } };
+    let main = maud! { main { p { "a < b" } } }.render();
+
+    let mut page = String::new();
+    page.push_renderable(header)
+        .extend_from(main)
+        .push_escaped("© 2025 & beyond");
+
+    assert_eq!(
+        page,
+        maud! {
+            header { h1 { "Title" } }
+            main { p { "a < b" } }
+            "© 2025 & beyond"
+        }
+        .render()
+        .into_inner(),
+    );
+}
+
+#[test]
+fn attribute_order() {
+    use hypertext::{
+        canonicalize, html_elements, maud, rsx, Attributes, GlobalAttributes, Renderable,
+    };
+
+    let active = true;
+    let extra = [("data-extra", "1")];
+
+    assert_eq!(
+        maud! {
+            input #name .field .wide .active[active] type="text" required
+                data-kind="name" title="Name" ..(Attributes(extra));
+        }
+        .render(),
+        r#"<input id="name" class="field wide active" type="text" required data-kind="name" title="Name" data-extra="1">"#,
+    );
+    assert_eq!(
+        rsx! { <input type="text" class="field" id="name" required title="Name"> }.render(),
+        r#"<input type="text" class="field" id="name" required title="Name">"#,
+    );
+
+    let canonical = r#"<p>a &lt; b<input class="field" id="name" required title="Name" type="text"><br/><script>if (a<b) {}</script></p>"#;
+
+    for html in [
+        canonical,
+        r#"<p>a &lt; b<input type="text" class="field" id="name" required title="Name"><br/><script>if (a<b) {}</script></p>"#,
+        r#"<p>a &lt; b<input  required
+            id="name" title="Name"   type="text" class="field" ><br /><script>if (a<b) {}</script></p>"#,
+    ] {
+        assert_eq!(canonicalize(html), canonical);
+    }
+
+    assert_eq!(
+        canonicalize("a < b <!-- <x b a> -->"),
+        "a < b <!-- <x b a> -->"
+    );
+    assert_eq!(
+        canonicalize(r#"<p title="unterminated>"#),
+        r#"<p title="unterminated>"#
+    );
+}
+
+#[test]
+fn fixed() {
+    use hypertext::{html_elements, maud, Fixed, Renderable};
+
+    assert_eq!(Fixed(1.0_f64, 2).render(), "1.00");
+    assert_eq!(Fixed(2.675_f64, 1).render(), "2.7");
+    assert_eq!(Fixed(0.999_f32, 2).render(), "1.00");
+    assert_eq!(Fixed(1234.5_f64, 0).render(), "1234");
+    assert_eq!(Fixed(-1.23456_f64, 3).render(), "-1.235");
+    assert_eq!(Fixed(-0.001_f64, 2).render(), "-0.00");
+    assert_eq!(Fixed(f64::NAN, 2).render(), "NaN");
+    assert_eq!(
+        maud! { td { (Fixed(12.5_f32, 1)) "%" } }.render(),
+        "<td>12.5%</td>"
+    );
+}
+
+#[test]
+fn while_let() {
+    use hypertext::{html_elements, maud, Renderable};
+
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    let mut stack = vec!["a", "b", "c"];
+
+    assert_eq!(
+        maud! { ul { @while let Some(item) = stack.pop() { li { (item) } } } }.render(),
+        "<ul><li>c</li><li>b</li><li>a</li></ul>",
+    );
+    assert!(stack.is_empty());
+
+    let mut points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+    let mut results = vec![Err::<i32, i32>(2), Ok(1)];
+
+    assert_eq!(
+        maud! {
+            @while let Some(Point { x, y: y @ 1.. }) = points.pop() {
+                p { (x) "," (y) }
+            }
+            @while let Some(Ok(n) | Err(n)) = results.pop() { (n) }
+        }
+        .render(),
+        "<p>3,4</p><p>1,2</p>12",
+    );
+}
+
+#[test]
+fn props_check() {
+    use hypertext::{html_elements, maud, PropsCheck, Renderable};
+
+    struct Progress {
+        value: u32,
+        max: u32,
+        check: PropsCheck,
+    }
+
+    impl Progress {
+        fn check(&self) -> Result<(), &'static str> {
+            if self.value <= self.max {
+                Ok(())
+            } else {
+                Err("value > max")
+            }
+        }
+    }
+
+    impl Renderable for Progress {
+        fn render_to(self, output: &mut String) {
+            self.check.run(&self, Self::check, output);
+
+            maud! { progress value=(self.value) max=(self.max) {} }.render_to(output);
+        }
+    }
+
+    let comment = PropsCheck::new().comment();
+
+    assert_eq!(
+        maud! { Progress value=1 max=2 check=(PropsCheck::new()); }.render(),
+        r#"<progress value="1" max="2"></progress>"#,
+    );
+    assert_eq!(
+        maud! { Progress value=3 max=2 check=(comment.always()); }.render(),
+        r#"<!-- invalid props: value &gt; max --><progress value="3" max="2"></progress>"#,
+    );
+    assert_eq!(
+        maud! { Progress value=3 max=2 check=(comment); }.render(),
+        if cfg!(debug_assertions) {
+            r#"<!-- invalid props: value &gt; max --><progress value="3" max="2"></progress>"#
+        } else {
+            r#"<progress value="3" max="2"></progress>"#
+        },
+    );
+
+    let panicked = std::panic::catch_unwind(|| {
+        maud! { Progress value=3 max=2 check=(PropsCheck::new().always()); }.render()
+    });
+
+    assert_eq!(
+        panicked.unwrap_err().downcast_ref::<String>().unwrap(),
+        "invalid props: value > max",
+    );
+}
+
+#[test]
+fn include() {
+    use hypertext::{html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+    let title = "Home";
+    let links = [("/", "Home"), ("/about", "About")];
+    let year = 2025;
+    let count = "outer";
+
+    let expected = r#"<header><h1 class="title">Home</h1><nav title="2 links"><a href="/">Home</a><a href="/about">About</a></nav></header><main>outer</main><footer><p>© 2025 Example</p></footer>"#;
+
+    assert_eq!(
+        maud! {
+            @include("tests/fixtures/partials/header.maud")
+            main { (count) }
+            @rsx { @include("tests/fixtures/partials/footer.rsx") }
+        }
+        .render(),
+        expected,
+    );
+    assert_eq!(
+        rsx! {
+            @maud { @include("tests/fixtures/partials/header.maud") }
+            <main>{count}</main>
+            @include("tests/fixtures/partials/footer.rsx")
+        }
+        .render(),
+        expected,
+    );
+    assert_eq!(
+        maud! {
+            @include("/tests/fixtures/partials/header.maud")
+            main { (count) }
+            @rsx { @include("/tests/fixtures/partials/footer.rsx") }
+        }
+        .render(),
+        expected,
+    );
+}
+
+#[test]
+fn fragments() {
+    use hypertext::{html_elements, maud, maud_move, Fragments, Renderable};
+
+    let mut items = Fragments::new();
+    assert!(items.is_empty());
+
+    items.push(maud! { li { "first" } });
+    items.extend(["<second>", "third"].map(|name| maud_move! { li { (name) } }));
+
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[1].0, "<li>&lt;second&gt;</li>");
+    assert_eq!(
+        items.iter().map(|item| item.0.len()).collect::<Vec<_>>(),
+        [14, 23, 14],
+    );
+
+    let expected = "<ul><li>first</li><li>&lt;second&gt;</li><li>third</li></ul>";
+
+    assert_eq!(maud! { ul { (&items) } }.render(), expected);
+    assert_eq!(maud! { ul { (items.clone()) } }.render(), expected);
+
+    let collected = (1..=2)
+        .map(|n| maud_move! { li { (n) } })
+        .collect::<Fragments>();
+
+    assert_eq!(
+        maud! {
+            ol {
+                @for item in &collected {
+                    (item.clone())
+                }
+            }
+        }
+        .render(),
+        "<ol><li>1</li><li>2</li></ol>",
+    );
+    assert_eq!(
+        collected.into_iter().map(|item| item.0).collect::<Vec<_>>(),
+        ["<li>1</li>", "<li>2</li>"],
+    );
+}
+
+#[test]
+fn rendered_raw_round_trip() {
+    use hypertext::{html_elements, maud, maud_static, Raw, Renderable, Rendered};
+
+    let card = maud! { article { h2 { "A & B" } } }.render();
+    let raw: Raw<String> = card.clone().into_raw();
+
+    assert_eq!(raw.0, "<article><h2>A &amp; B</h2></article>");
+    assert_eq!(raw.clone().rendered(), card);
+    assert_eq!(Rendered::from(card.clone().into_raw()), card);
+
+    let badge: Raw<&str> = maud_static! { span { "new" } }.into_raw();
+
+    assert_eq!(badge.rendered(), "<span>new</span>");
+    assert_eq!(badge.rendered().into_raw().0, "<span>new</span>");
+
+    assert_eq!(
+        maud! {
+            main {
+                (raw)
+                (badge)
+            }
+        }
+        .render(),
+        "<main><article><h2>A &amp; B</h2></article><span>new</span></main>",
+    );
+}
+
+#[test]
+fn attribute_toggle_else() {
+    use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+    let button = |open: bool, label: &'static str| {
+        maud! {
+            button
+                draggable="true"[open] else "false"
+                title=(label)[open] else { "Show " (label) }
+            { (label) }
+        }
+        .render()
+    };
+
+    assert_eq!(
+        button(true, "Menu"),
+        r#"<button draggable="true" title="Menu">Menu</button>"#,
+    );
+    assert_eq!(
+        button(false, "Menu"),
+        r#"<button draggable="false" title="Show Menu">Menu</button>"#,
+    );
+
+    for count in [0, 3] {
+        assert_eq!(
+            maud! { span data-state="empty"[count == 0] else (count) { "items" } }.render(),
+            if count == 0 {
+                r#"<span data-state="empty">items</span>"#
+            } else {
+                r#"<span data-state="3">items</span>"#
+            },
+        );
+    }
+}
+
+#[test]
+fn render_mut_ref() {
+    use hypertext::{html_elements, maud, Fragments, Renderable};
+
+    let mut name = String::from("<Alice>");
+    let mut items = Fragments::new();
+    let mut greeting = maud! { b { "Hi" } };
+
+    {
+        let name = &mut name;
+        let items = &mut items;
+        let greeting = &mut greeting;
+
+        items.push(maud! { li { "one" } });
+        name.make_ascii_uppercase();
+        assert_eq!(name.as_mut_str().render(), "&lt;ALICE&gt;");
+
+        assert_eq!(
+            maud! { p { (greeting) " " (name) } ul { (items) } }.render(),
+            "<p><b>Hi</b> &lt;ALICE&gt;</p><ul><li>one</li></ul>",
+        );
+    }
+
+    // the referenced values are still usable afterwards
+    assert_eq!(greeting.render(), "<b>Hi</b>");
+    assert_eq!(items.len(), 1);
+}
+
+#[test]
+fn srcdoc() {
+    use hypertext::{html_elements, maud, Renderable, SrcDoc};
+
+    let name = "O'Brien & \"Sons\"";
+    let inner = maud! {
+        !DOCTYPE
+        html {
+            body {
+                h1 title=(name) { (name) }
+                script { (hypertext::Raw("if (1 < 2) alert(\"hi\");")) }
+            }
+        }
+    };
+
+    let inner_html = inner.render().into_inner();
+    let html = maud! { iframe srcdoc=(SrcDoc(inner)) {} }
+        .render()
+        .into_inner();
+
+    assert_eq!(
+        html,
+        concat!(
+            r#"<iframe srcdoc=""#,
+            "&lt;!DOCTYPE html&gt;&lt;html&gt;&lt;body&gt;",
+            "&lt;h1 title=&quot;O'Brien &amp;amp; &amp;quot;Sons&amp;quot;&quot;&gt;",
+            "O'Brien &amp;amp; &amp;quot;Sons&amp;quot;&lt;/h1&gt;",
+            "&lt;script&gt;if (1 &lt; 2) alert(&quot;hi&quot;);&lt;/script&gt;",
+            "&lt;/body&gt;&lt;/html&gt;",
+            r#""></iframe>"#,
+        ),
+    );
+
+    // unescaping the attribute value gives back exactly the inner document
+    let value = html
+        .strip_prefix(r#"<iframe srcdoc=""#)
+        .and_then(|rest| rest.strip_suffix(r#""></iframe>"#))
+        .unwrap()
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&");
+
+    assert_eq!(value, inner_html);
+}
+
+#[test]
+fn captures() {
+    use hypertext::{html_elements, maud, maud_move, Renderable};
+
+    let name = String::from("Alice");
+
+    // splicing a reference only borrows
+    let borrowed = maud! { p { (&name) } };
+    assert_eq!(borrowed.render(), "<p>Alice</p>");
+    assert_eq!(name, "Alice");
+
+    // splicing the value moves it in, so the closure can outlive `name`
+    let moved = {
+        let name = name.clone();
+        maud! { p { (name) } }
+    };
+    assert_eq!(moved.render(), "<p>Alice</p>");
+
+    // `maud_move!` moves even values which are only borrowed inside
+    let count = 3;
+    let moved = {
+        let suffix = String::from("s");
+        maud_move! { p { (count) " item" (&suffix) } }
+    };
+    assert_eq!(moved.render(), "<p>3 items</p>");
+}
+
+#[cfg(feature = "poem")]
+#[test]
+fn poem() {
+    use hypertext::{html_elements, maud, maud_static, Raw, Renderable, Rendered};
+    use poem::{
+        get, handler,
+        http::{header, StatusCode},
+        web::Path,
+        Endpoint, IntoResponse, Request, Route,
+    };
+
+    #[handler]
+    fn hello(Path(name): Path<String>) -> Rendered<String> {
+        maud! { h1 { "Hello, " (name) "!" } }.render()
+    }
+
+    #[handler]
+    fn badge() -> Raw<&'static str> {
+        maud_static! { span { "new" } }.into_raw()
+    }
+
+    #[handler]
+    fn missing() -> impl IntoResponse {
+        maud! { p { "Not found" } }
+            .render()
+            .with_status(StatusCode::NOT_FOUND)
+    }
+
+    let app = Route::new()
+        .at("/hello/:name", get(hello))
+        .at("/badge", get(badge))
+        .at("/missing", get(missing));
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    for (uri, status, body) in [
+        (
+            "/hello/%3CBob%3E",
+            StatusCode::OK,
+            "<h1>Hello, &lt;Bob&gt;!</h1>",
+        ),
+        ("/badge", StatusCode::OK, "<span>new</span>"),
+        ("/missing", StatusCode::NOT_FOUND, "<p>Not found</p>"),
+    ] {
+        let response = runtime.block_on(async {
+            app.get_response(Request::builder().uri(uri.parse().unwrap()).finish())
+                .await
+        });
+
+        assert_eq!(response.status(), status);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/html; charset=utf-8",
+        );
+        assert_eq!(
+            runtime
+                .block_on(response.into_body().into_string())
+                .unwrap(),
+            body,
+        );
+    }
+}
+
+#[test]
+fn class_list() {
+    use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+    for (is_active, enabled) in [(false, false), (true, false), (false, true), (true, true)] {
+        assert_eq!(
+            maud! { button class:[("active", is_active), ("disabled", !enabled)] { "Go" } }
+                .render(),
+            maud! { button ."active"[is_active] ."disabled"[!enabled] { "Go" } }.render(),
+        );
+        assert_eq!(
+            maud! { button.btn title="Go" class:[("active", is_active)] { "Go" } }.render(),
+            maud! { button.btn ."active"[is_active] title="Go" { "Go" } }.render(),
+        );
+    }
+
+    let is_active = true;
+
+    assert_eq!(
+        maud! { li.item class:[("active", is_active), ("first", false),] {} }.render(),
+        r#"<li class="item active"></li>"#,
+    );
+}
+
+#[cfg(feature = "components")]
+#[test]
+fn source_listing() {
+    use hypertext::{components::code::SourceListing, html_elements, maud, Renderable};
+
+    let mut listing = SourceListing {
+        source: "<ul>\n  <li>A & B</li>\n</ul>\n".into(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        (&listing).render(),
+        "<pre><code>&lt;ul&gt;\n  &lt;li&gt;A &amp; B&lt;/li&gt;\n&lt;/ul&gt;\n</code></pre>",
+    );
+
+    listing.language = Some("html".into());
+    listing.line_numbers = true;
+
+    assert_eq!(
+        maud! { figure { (&listing) } }.render(),
+        concat!(
+            r#"<figure><pre><code class="language-html">"#,
+            r#"<span class="line" data-line="1">&lt;ul&gt;</span>"#,
+            "\n",
+            r#"<span class="line" data-line="2">  &lt;li&gt;A &amp; B&lt;/li&gt;</span>"#,
+            "\n",
+            r#"<span class="line" data-line="3">&lt;/ul&gt;</span>"#,
+            "</code></pre></figure>",
+        ),
+    );
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn json_table() {
+    use hypertext::{components::json::JsonTable, html_elements, maud, Renderable};
+
+    let value = serde_json::json!({
+        "<key>": "a & b",
+        "count": 3,
+        "ok": true,
+        "missing": null,
+        "items": [1.5, "x"],
+    });
+
+    assert_eq!(
+        maud! { div { (JsonTable(&value)) } }.render(),
+        concat!(
+            "<div><dl>",
+            "<dt>&lt;key&gt;</dt><dd>a &amp; b</dd>",
+            "<dt>count</dt><dd>3</dd>",
+            "<dt>items</dt><dd><ol><li>1.5</li><li>x</li></ol></dd>",
+            "<dt>missing</dt><dd>—</dd>",
+            "<dt>ok</dt><dd>true</dd>",
+            "</dl></div>",
+        ),
+    );
+
+    let mut deep = serde_json::json!("bottom");
+    for _ in 0..=JsonTable::MAX_DEPTH {
+        deep = serde_json::Value::Array(vec![deep]);
+    }
+
+    let rendered = JsonTable(&deep).render().into_inner();
+
+    assert_eq!(rendered.matches("<ol>").count(), JsonTable::MAX_DEPTH);
+    assert!(rendered.contains("<li>…</li>"));
+    assert!(!rendered.contains("bottom"));
+}
+
+#[test]
+fn included_html_comments() {
+    use hypertext::{html_elements, rsx, GlobalAttributes, Renderable};
+
+    let time = "<noon>";
+
+    assert_eq!(
+        rsx! {
+            <!-- "a --> b" -->
+            @include("tests/fixtures/partials/notice.html")
+        }
+        .render(),
+        concat!(
+            "<!--a --&gt; b-->",
+            "<!-- notice banner, don't edit by hand -->",
+            r#"<aside class="notice">"#,
+            "<!-- <p>the old <em>markup</em>, kept for reference</p> -->",
+            "<p>Maintenance at &lt;noon&gt;.</p>",
+            "<!--already quoted-->",
+            "</aside>",
+        ),
+    );
+    assert_eq!(
+        rsx! { @include("tests/fixtures/partials/raw-strings.html") }.render(),
+        concat!(
+            r#"<p title="a &quot;quote">&lt;!-- kept --&gt;</p>"#,
+            "<!-- don't edit -->",
+        ),
+    );
+}
+
+#[test]
+fn rendered_from_renderable() {
+    use hypertext::{html_elements, maud, Renderable, Rendered};
+
+    let name = "<Alice>";
+
+    assert_eq!(
+        Rendered::from_renderable(maud! { p { (name) } }),
+        maud! { p { (name) } }.render(),
+    );
+
+    let rendered: Rendered<String> = name.into();
+    assert_eq!(rendered, "&lt;Alice&gt;");
+    assert_eq!(Rendered::from(&*String::from(name)), rendered.as_str());
+}
+
+#[test]
+fn repeated_elements() {
+    use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+    let rows = maud! {
+        @for i in 0..100 {
+            div.row title=(i) {
+                div.cell { (i) }
+                div.cell { (i * 2) }
+            }
+        }
+    }
+    .render();
+
+    assert_eq!(rows.as_str().matches("<div").count(), 300);
+    assert!(rows.as_str().starts_with(
+        r#"<div class="row" title="0"><div class="cell">0</div><div class="cell">0</div></div>"#
+    ));
+}
+
+#[test]
+fn raw_block() {
+    use hypertext::{html_elements, maud, maud_static, Renderable};
+
+    let name = "<b>";
+
+    assert_eq!(
+        maud! {
+            p {
+                @raw { "<b>bold</b> " "&amp; <!-- note -->" }
+                (name)
+            }
+        }
+        .render(),
+        "<p><b>bold</b> &amp; <!-- note -->&lt;b&gt;</p>",
+    );
+    assert_eq!(maud_static! { div { @raw { "<br>" } } }, "<div><br></div>",);
+}
+
+#[test]
+fn intersperse() {
+    use hypertext::{html_elements, intersperse, rsx, rsx_move, GlobalAttributes, Renderable};
+
+    let separator = "<sep>";
+    let items = |n: usize| (0..n).map(|i| rsx_move! { <li>{i}</li> });
+    let list = |n| {
+        rsx! {
+            <ul>{intersperse(items(n), rsx! { <li class="sep">{separator}</li> })}</ul>
+        }
+        .render()
+    };
+
+    assert_eq!(list(0), "<ul></ul>");
+    assert_eq!(list(1), "<ul><li>0</li></ul>");
+    assert_eq!(
+        list(3),
+        concat!(
+            "<ul><li>0</li>",
+            r#"<li class="sep">&lt;sep&gt;</li><li>1</li>"#,
+            r#"<li class="sep">&lt;sep&gt;</li><li>2</li></ul>"#,
+        ),
+    );
+    assert_eq!(intersperse(["a", "b"], ", ").render(), "a, b",);
+}
+
+#[cfg(feature = "unstable-context")]
+#[test]
+fn custom_context() {
+    use hypertext::{
+        escape::{AttributeValue, Context, Escaped, Node},
+        html_elements, maud, GlobalAttributes, Renderable,
+    };
+
+    /// Escapes only `<`, for a target where nothing else is special.
+    struct AngleOnly;
+
+    impl Context for AngleOnly {
+        fn escape_to(s: &str, output: &mut String) {
+            output.push_str(&s.replace('<', "&lt;"));
+        }
+    }
+
+    let value = r#"<a & "b">"#;
+
+    assert_eq!(
+        maud! { p title=(Escaped::<AttributeValue, _>::new(value)) { (Escaped::<Node, _>::new(value)) } }
+            .render(),
+        maud! { p title=(value) { (value) } }.render(),
+    );
+    assert_eq!(
+        Escaped::<AngleOnly, _>::new(value).render(),
+        r#"&lt;a & "b">"#,
+    );
+}
+
+#[cfg(feature = "htmx")]
+#[test]
+fn htmx_fragments() {
+    use std::{cell::Cell, rc::Rc};
+
+    use hypertext::{
+        html_elements,
+        htmx::{fragment, FragmentRegistry},
+        maud, maud_move, Renderable,
+    };
+
+    let items = Rc::new(Cell::new(0));
+    let badge = {
+        let items = items.clone();
+        maud_move! { span.badge { (items.get()) " items" } }
+    };
+    let sidebar = maud_move! { nav { "<links>" } };
+
+    let mut registry = FragmentRegistry::new();
+    let page = registry.render(maud! {
+        main {
+            (fragment("sidebar", sidebar))
+            (fragment("cart-badge", badge))
+        }
+    });
+
+    assert_eq!(
+        page,
+        concat!(
+            "<main>",
+            r#"<nav id="sidebar">&lt;links&gt;</nav>"#,
+            r#"<span id="cart-badge" class="badge">0 items</span>"#,
+            "</main>",
+        ),
+    );
+    assert!(registry.contains("sidebar"));
+
+    items.set(3);
+
+    assert_eq!(
+        registry.render_only(["cart-badge", "missing"]),
+        r#"<span id="cart-badge" hx-swap-oob="true" class="badge">3 items</span>"#,
+    );
+
+    // fragments rendered outside of a registry aren't registered
+    let _ = fragment("footer", maud_move! { footer { "…" } }).render();
+    assert!(!registry.contains("footer"));
+}
+
+#[cfg(feature = "htmx")]
+#[test]
+fn htmx_fragment_root() {
+    use hypertext::{
+        html_elements,
+        htmx::{fragment, FragmentRegistry},
+        maud, maud_move, rsx_move,
+    };
+
+    let row = maud_move! { tr { td { "a" } } };
+    let item = rsx_move! { <!-- "item" --> <li><br></li> };
+
+    let mut registry = FragmentRegistry::new();
+
+    assert_eq!(
+        registry.render(maud! {
+            table { (fragment("row", row)) }
+            ul { (fragment("item", item)) }
+        }),
+        concat!(
+            r#"<table><tr id="row"><td>a</td></tr></table>"#,
+            r#"<ul><!--item--><li id="item"><br></li></ul>"#,
+        ),
+    );
+    assert_eq!(
+        registry.render_only(["row"]),
+        r#"<tr id="row" hx-swap-oob="true"><td>a</td></tr>"#,
+    );
+}
+
+#[cfg(all(feature = "htmx", debug_assertions))]
+#[test]
+#[should_panic = "htmx fragment `text` has no root element"]
+fn htmx_fragment_without_root() {
+    use hypertext::{htmx::fragment, Renderable};
+
+    let _ = fragment("text", "<text>").render();
+}
+
+#[cfg(all(feature = "htmx", not(debug_assertions)))]
+#[test]
+fn htmx_fragment_without_root() {
+    use hypertext::{htmx::fragment, Renderable};
+
+    assert_eq!(fragment("text", "<text>").render(), "&lt;text&gt;");
+}
+
+#[test]
+fn render_fragment_list() {
+    use hypertext::{html_elements, maud, render_fragment_list};
+
+    let count = 2;
+    let fragments = [
+        (
+            "main",
+            Box::new(maud! { p { "Items: " (count) } }) as Box<dyn FnOnce(&mut String)>,
+        ),
+        ("sidebar", Box::new(maud! { nav { "<links>" } })),
+    ];
+
+    let rendered = render_fragment_list(fragments);
+
+    assert_eq!(rendered.len(), 2);
+    assert_eq!(rendered[0].0, "main");
+    assert_eq!(rendered[0].1, "<p>Items: 2</p>");
+    assert_eq!(rendered[1].0, "sidebar");
+    assert_eq!(rendered[1].1, "<nav>&lt;links&gt;</nav>");
+}
+
+#[cfg(feature = "htmx")]
+#[test]
+fn htmx_render_oob() {
+    use hypertext::{html_elements, htmx::render_oob, maud};
+
+    let count = 2;
+    let fragments = [
+        (
+            "main",
+            Box::new(maud! { p { "Items: " (count) } }) as Box<dyn FnOnce(&mut String)>,
+        ),
+        ("side\"bar", Box::new(maud! { nav { "<links>" } })),
+    ];
+
+    assert_eq!(
+        render_oob(fragments),
+        concat!(
+            r#"<p id="main" hx-swap-oob="true">Items: 2</p>"#,
+            r#"<nav id="side&quot;bar" hx-swap-oob="true">&lt;links&gt;</nav>"#,
+        ),
+    );
+}
+
+#[test]
+fn template_fn_registry() {
+    use std::collections::{HashMap, HashSet};
+
+    use hypertext::{html_elements, maud, Renderable, TemplateFn};
+
+    fn header(output: &mut String) {
+        maud! { header { "<Site>" } }.render_to(output);
+    }
+
+    fn footer(output: &mut String) {
+        maud! { footer { "© 2024" } }.render_to(output);
+    }
+
+    let registry: HashMap<&str, TemplateFn> =
+        HashMap::from([("header", header as TemplateFn), ("footer", footer)]);
+
+    assert_eq!(registry["header"].render(), "<header>&lt;Site&gt;</header>");
+    assert_eq!(
+        maud! { body { (registry["header"]) main {} (registry["footer"]) } }.render(),
+        "<body><header>&lt;Site&gt;</header><main></main><footer>© 2024</footer></body>",
+    );
+
+    let unique = [header as TemplateFn, footer, header]
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(unique.len(), 2);
+}
+
+#[test]
+fn empty_attributes() {
+    use hypertext::{html_elements, maud, Renderable};
+
+    for locked in [true, false] {
+        let expected = if locked {
+            r#"<input disabled><input disabled><input disabled><input disabled type="text">"#
+        } else {
+            r#"<input disabled><input disabled><input><input type="text">"#
+        };
+
+        assert_eq!(
+            maud! {
+                input disabled;
+                input disabled?;
+                input disabled[locked];
+                input disabled ? [locked] type="text";
+            }
+            .render(),
+            expected,
+        );
+    }
+}
+
+#[test]
+fn literals_are_not_unindented() {
+    use hypertext::{html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+    let expected = concat!(
+        r#"<p title="  indented">  leading</p>"#,
+        "<pre>\n  /\\_/\\\n ( o.o )\n  &gt; ^ &lt;\n</pre>",
+    );
+
+    assert_eq!(
+        maud! {
+            p title="  indented" { "  leading" }
+            pre { "\n  /\\_/\\\n ( o.o )\n  > ^ <\n" }
+        }
+        .render(),
+        expected,
+    );
+    assert_eq!(
+        rsx! {
+            <p title="  indented">"  leading"</p>
+            <pre>"\n  /\\_/\\\n ( o.o )\n  > ^ <\n"</pre>
+        }
+        .render(),
+        expected,
+    );
+}
+
+#[test]
+fn tap_output() {
+    use hypertext::{html_elements, maud, Renderable};
+
+    let mut lengths = Vec::new();
+    let mut seen = String::new();
+
+    let page = maud! {
+        ul {
+            @for item in ["a", "<b>"] {
+                (maud! { li { (item) } }.tap_output(|output| lengths.push(output.len())))
+            }
+        }
+        ("tail".tap_output(|output| seen = output.to_owned()))
+    }
+    .render();
+
+    assert_eq!(page, "<ul><li>a</li><li>&lt;b&gt;</li></ul>tail");
+    assert_eq!(
+        lengths,
+        [
+            "<ul><li>a</li>".len(),
+            "<ul><li>a</li><li>&lt;b&gt;</li>".len()
+        ]
+    );
+    assert_eq!(seen, page.as_str());
+}
+
+#[test]
+fn attribute_value_types() {
+    use hypertext::{
+        html_elements, maud,
+        values::{Decoding, Loading, Rel, Target},
+        Renderable,
+    };
+
+    for (target, expected) in [
+        (Target::Blank, "_blank"),
+        (Target::Self_, "_self"),
+        (Target::Parent, "_parent"),
+        (Target::Top, "_top"),
+    ] {
+        assert_eq!(
+            maud! { a target=(target) {} }.render(),
+            format!(r#"<a target="{expected}"></a>"#).as_str(),
+        );
+    }
+
+    assert_eq!(
+        maud! {
+            img loading=(Loading::Lazy) decoding=(Decoding::Async);
+            img loading=(Loading::Eager) decoding=(Decoding::Sync);
+            img decoding=(Decoding::Auto);
+        }
+        .render(),
+        concat!(
+            r#"<img loading="lazy" decoding="async">"#,
+            r#"<img loading="eager" decoding="sync">"#,
+            r#"<img decoding="auto">"#,
+        ),
+    );
+
+    let mut rel = Rel::NOFOLLOW | Rel::NOREFERRER;
+    rel |= Rel::NOOPENER;
+
+    assert!(rel.contains(Rel::NOOPENER | Rel::NOFOLLOW));
+    assert!(!rel.contains(Rel::UGC));
+    assert_eq!(
+        maud! { a rel=(rel) {} link rel=(Rel::empty()); }.render(),
+        r#"<a rel="noopener noreferrer nofollow"></a><link rel="">"#,
+    );
+}
+
+#[test]
+fn escaped_lengths() {
+    use hypertext::escape::{self, escaped_len_attribute, escaped_len_text, fits_within};
+
+    for s in [
+        "",
+        "plain",
+        "Tom & Jerry",
+        r#"<a href="x">'</a>"#,
+        "héllo → wörld",
+        "日本語 & <絵文字 🎉>",
+    ] {
+        assert_eq!(escaped_len_text(s), escape::text(s).len(), "{s}");
+        assert_eq!(escaped_len_attribute(s), escape::attribute(s).len(), "{s}");
+    }
+
+    assert!(fits_within("a < b", 8, escaped_len_text));
+    assert!(!fits_within("a < b", 7, escaped_len_text));
+    assert!(fits_within("\"é\"", 14, escaped_len_attribute));
+    assert!(!fits_within("\"é\"", 13, escaped_len_attribute));
+}
+
+#[test]
+fn render_ascii() {
+    use hypertext::{escape, html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+    let name = "Zoë 🦀";
+
+    assert_eq!(
+        maud! { p title=(name) { "Ça va? " (name) " <&>" } }.render(),
+        r#"<p title="Zoë 🦀">Ça va? Zoë 🦀 &lt;&amp;&gt;</p>"#,
+    );
+    assert_eq!(
+        maud! { p title=(name) { "Ça va? " (name) " <&>" } }.render_ascii(),
+        r#"<p title="Zo&#xEB; &#x1F980;">&#xC7;a va? Zo&#xEB; &#x1F980; &lt;&amp;&gt;</p>"#,
+    );
+    assert_eq!(rsx! { <p>"plain"</p> }.render_ascii(), "<p>plain</p>",);
+
+    // supplementary-plane characters are one reference, not a surrogate pair
+    assert_eq!(escape::ascii("\u{10FFFF}😀"), "&#x10FFFF;&#x1F600;");
+}
+
+#[test]
+fn attribute_set_transforms() {
+    use std::{net::Ipv4Addr, time::Duration};
+
+    use hypertext::{html_elements, maud, AttributeSet, Renderable};
+
+    fn yes_no(value: bool) -> &'static str {
+        if value {
+            "yes"
+        } else {
+            "no"
+        }
+    }
+
+    #[derive(AttributeSet)]
+    struct Attrs {
+        #[attr(debug)]
+        data_delay: Duration,
+        #[attr(debug)]
+        data_tags: Option<Vec<&'static str>>,
+        #[attr(display)]
+        data_ip: Ipv4Addr,
+        #[attr(with = yes_no, rename = "data-open")]
+        open: bool,
+    }
+
+    assert_eq!(
+        maud! {
+            div ..(Attrs {
+                data_delay: Duration::from_millis(1500),
+                data_tags: Some(vec!["<a>", "b"]),
+                data_ip: Ipv4Addr::LOCALHOST,
+                open: true,
+            }) {}
+        }
+        .render(),
+        concat!(
+            r#"<div data-delay="1.5s" data-tags="[&quot;&lt;a&gt;&quot;, &quot;b&quot;]""#,
+            r#" data-ip="127.0.0.1" data-open="yes"></div>"#,
+        ),
+    );
+}
+
+#[test]
+fn rsx_splices_with_angle_brackets() {
+    use hypertext::{html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+    let x = 5;
+    let limit = 2;
+    let expected = concat!(
+        r#"<div class="big" title="true" data-n="0" data-max="5">"#,
+        "true|false|0|3",
+        "</div>",
+    );
+
+    assert_eq!(
+        rsx! {
+            <div class=(if x > 3 { "big" } else { "small" }) title={Some(x).is_some_and(|a| a > limit)}
+                data-n={Vec::<u8>::new().len()} data-max=([1, x].into_iter().max().unwrap())>
+                {x > 3} "|" {Some(x).is_some_and(|a| a < limit)} "|" {Vec::<u8>::new().len()} "|"
+                {[1, 2, 3].iter().filter(|&&n| n >= 1).count()}
+            </div>
+        }
+        .render(),
+        expected,
+    );
+    assert_eq!(
+        maud! {
+            div class=(if x > 3 { "big" } else { "small" }) title=(Some(x).is_some_and(|a| a > limit))
+                data-n=(Vec::<u8>::new().len()) data-max=([1, x].into_iter().max().unwrap()) {
+                (x > 3) "|" (Some(x).is_some_and(|a| a < limit)) "|" (Vec::<u8>::new().len()) "|"
+                ([1, 2, 3].iter().filter(|&&n| n >= 1).count())
+            }
+        }
+        .render(),
+        expected,
+    );
+}
+
+#[test]
+fn component_library_prelude() {
+    use ui_kit::prelude::*;
+
+    let expected = concat!(
+        r#"<section ui-variant="card"><h2>Inbox</h2>"#,
+        r#"<ui-badge tone="info"><ui-icon name="mail">3 new</ui-badge>"#,
+        r#"<p ui-variant="muted">Nothing else</p><br></section>"#,
+    );
+
+    assert_eq!(
+        maud! {
+            Card title="Inbox" {
+                Badge tone="info" label="3 new" icon=(Some("mail"));
+                p ui-variant="muted" { "Nothing else" }
+                br;
+            }
+        }
+        .render(),
+        expected,
+    );
+    assert_eq!(
+        rsx! {
+            <section ui-variant="card">
+                <h2>Inbox</h2>
+                {Badge { tone: "info", label: "3 new", icon: Some("mail") }}
+                <p ui-variant="muted">Nothing else</p>
+                <br>
+            </section>
+        }
+        .render(),
+        expected,
+    );
+}
+
+#[test]
+fn component_library_reexported_elements() {
+    use hypertext::{maud, Renderable};
+    use ui_kit::html_elements;
+
+    assert_eq!(
+        maud! { ui-badge tone="warning" { ui-icon name="alert"; "Late" } }.render(),
+        r#"<ui-badge tone="warning"><ui-icon name="alert">Late</ui-badge>"#,
+    );
+}
+
+#[test]
+fn separated_by_loop_control() {
+    use hypertext::{maud, Renderable};
+
+    let items = ["skip", "a", "skip", "b", "stop", "c"];
+
+    assert_eq!(
+        maud! {
+            @for item in items {
+                @if item == "skip" { @continue; }
+                @if item == "stop" { @break; }
+                (item)
+            } @separated_by { "," }
+        }
+        .render(),
+        "a,b"
+    );
+
+    assert_eq!(
+        maud! {
+            @for n in 1..=6 {
+                @if n % 2 == 1 { @continue; }
+                (n)
+            } @separated_by { ", " }
+        }
+        .render(),
+        "2, 4, 6"
+    );
+
+    // iterations which render nothing without being skipped are separated
+    assert_eq!(
+        maud! {
+            @for item in ["a", "", "b"] {
+                (item)
+            } @separated_by { ", " }
+        }
+        .render(),
+        "a, , b"
+    );
+
+    // iterations cut short after rendering something keep their separator
+    assert_eq!(
+        maud! {
+            @for n in 1..=3 {
+                (n)
+                @if n == 2 { @continue; }
+                "."
+            } @separated_by { " " }
+        }
+        .render(),
+        "1. 2 3."
+    );
+
+    let rows = [[1, 2, 0], [3, 0, 4], [0, 5, 6]];
+
+    // leaving inner loops removes their pending separators too
+    assert_eq!(
+        maud! {
+            @'rows: for row in rows {
+                @for n in row {
+                    @if n == 0 { @continue 'rows; }
+                    (n)
+                } @separated_by { "+" }
+            } @separated_by { " | " }
+        }
+        .render(),
+        "1+2 | 3"
+    );
+}
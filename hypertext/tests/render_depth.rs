@@ -0,0 +1,50 @@
+//! Tests for `set_max_render_depth`, in their own binary so that changing the
+//! limit can't affect any other test running at the same time.
+#![cfg(feature = "std")]
+
+use std::panic;
+
+use hypertext::{html_elements, maud_move, rsx_move, set_max_render_depth, Renderable};
+
+/// Sets the maximum render depth until dropped, then restores the default.
+struct MaxRenderDepth;
+
+impl MaxRenderDepth {
+    fn set(depth: usize) -> Self {
+        set_max_render_depth(depth);
+        Self
+    }
+}
+
+impl Drop for MaxRenderDepth {
+    fn drop(&mut self) {
+        set_max_render_depth(512);
+    }
+}
+
+#[test]
+fn render_depth() {
+    fn outer(depth: usize) -> impl Renderable {
+        maud_move! { div { (inner(depth)) } }
+    }
+
+    fn inner(depth: usize) -> impl Renderable {
+        rsx_move! { <span>{ depth.checked_sub(1).map(outer) }</span> }
+    }
+
+    let _guard = MaxRenderDepth::set(64);
+
+    // 31 levels of each component, which is under the limit
+    let html = outer(30).render().into_inner();
+    assert_eq!(html.matches("<div>").count(), 31);
+    assert_eq!(html.matches("<span>").count(), 31);
+
+    let err = panic::catch_unwind(|| outer(usize::MAX).render()).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<String>().map(String::as_str),
+        Some("hypertext: render depth exceeded 64 — possible component cycle"),
+    );
+
+    // the depth is unwound with the panic
+    assert_eq!(outer(30).render().into_inner(), html);
+}
@@ -0,0 +1,39 @@
+//! Tests for `HYPERTEXT_STATS`, in their own binary so that setting the
+//! variable can't leak into the builds of any other test.
+
+use std::{env, fs, path::Path};
+
+#[test]
+fn stats() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("stats");
+    let source = dir.join("record.rs");
+    let path = dir.join("stats.jsonl");
+
+    // cargo doesn't track the variable, so write a fresh copy of the source to
+    // force it to be recompiled
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy("tests/fixtures/stats.rs", &source).unwrap();
+    let _ = fs::remove_file(&path);
+
+    env::set_var("HYPERTEXT_STATS", &path);
+    trybuild::TestCases::new().pass(&source);
+
+    let stats = fs::read_to_string(&path).unwrap();
+
+    let record = stats
+        .lines()
+        .find(|line| line.contains("stats/record.rs"))
+        .unwrap_or_else(|| panic!("no record for the invocation in {stats:?}"));
+
+    for field in [
+        "\"macro\":\"maud\"",
+        "\"line\":6",
+        "\"nodes\":",
+        "\"elements\":3",
+        "\"attributes\":2",
+        "\"dynamic_parts\":1",
+        "\"tokens\":",
+    ] {
+        assert!(record.contains(field), "{field} not in {record}");
+    }
+}
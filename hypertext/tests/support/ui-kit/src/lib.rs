@@ -0,0 +1,88 @@
+//! A small component library, built the way a downstream crate would build
+//! one, so the tests can use it from a separate crate through its prelude.
+
+use hypertext::{attributes, maud, Renderable};
+
+/// The standard elements, plus the kit's custom elements.
+pub mod html_elements {
+    pub use hypertext::html_elements::*;
+
+    hypertext::elements! {
+        /// A status badge.
+        ui_badge {
+            /// The colour of the badge.
+            tone
+        }
+
+        /// An icon from the kit's icon set.
+        ui_icon {
+            /// The name of the icon.
+            name
+        }
+    }
+
+    impl hypertext::VoidElement for ui_icon {}
+}
+
+attributes! {
+    /// Attributes understood by every element of the kit.
+    pub trait UiAttributes {
+        /// The visual variant of an element.
+        ui_variant
+    }
+}
+
+/// A status badge with an optional icon.
+#[derive(Debug, Clone, Copy)]
+pub struct Badge<'a> {
+    /// The colour of the badge.
+    pub tone: &'a str,
+    /// The text of the badge.
+    pub label: &'a str,
+    /// The name of an icon to show before the label.
+    pub icon: Option<&'a str>,
+}
+
+impl Renderable for Badge<'_> {
+    fn render_to(self, output: &mut String) {
+        maud! {
+            ui-badge tone=(self.tone) {
+                @if let Some(icon) = self.icon {
+                    ui-icon name=(icon);
+                }
+                (self.label)
+            }
+        }
+        .render_to(output);
+    }
+}
+
+/// A card with a title and arbitrary contents.
+#[derive(Debug, Clone, Copy)]
+pub struct Card<'a, R> {
+    /// The title of the card.
+    pub title: &'a str,
+    /// The contents of the card.
+    pub children: R,
+}
+
+impl<R: Renderable> Renderable for Card<'_, R> {
+    fn render_to(self, output: &mut String) {
+        maud! {
+            section ui-variant="card" {
+                h2 { (self.title) }
+                (self.children)
+            }
+        }
+        .render_to(output);
+    }
+}
+
+hypertext::component_prelude! {
+    /// Everything needed to use the kit's components and elements.
+    pub mod prelude {
+        elements = crate::html_elements;
+
+        pub use crate::{Badge, Card, UiAttributes};
+    }
+}
@@ -0,0 +1,111 @@
+//! Compile-fail tests for the `hypertext` macros.
+
+#[cfg(feature = "strict-data-attributes")]
+#[test]
+fn strict_data_attributes() {
+    trybuild::TestCases::new().compile_fail("tests/ui/strict-data-attributes/*.rs");
+}
+
+#[test]
+fn attribute_collision() {
+    trybuild::TestCases::new().compile_fail("tests/ui/attribute-collision/*.rs");
+}
+
+#[test]
+fn attribute_set() {
+    trybuild::TestCases::new().compile_fail("tests/ui/attribute-set/*.rs");
+}
+
+#[test]
+fn attribute_syntax() {
+    trybuild::TestCases::new().compile_fail("tests/ui/attribute-syntax/*.rs");
+}
+
+#[test]
+fn attribute_values() {
+    trybuild::TestCases::new().compile_fail("tests/ui/attribute-values/*.rs");
+}
+
+#[test]
+fn id_references() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/id-references/resolved.rs");
+    t.compile_fail("tests/ui/id-references/unresolved-*.rs");
+}
+
+#[test]
+fn blank_target() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/blank-target/safe.rs");
+    t.compile_fail("tests/ui/blank-target/unsafe.rs");
+}
+
+#[test]
+fn component_slots() {
+    trybuild::TestCases::new().compile_fail("tests/ui/component-slots/*.rs");
+}
+
+#[test]
+fn component_shorthands() {
+    trybuild::TestCases::new().compile_fail("tests/ui/component-shorthands/*.rs");
+}
+
+#[test]
+fn component_element() {
+    trybuild::TestCases::new().compile_fail("tests/ui/component-element/*.rs");
+}
+
+#[test]
+fn include() {
+    trybuild::TestCases::new().compile_fail("tests/ui/include/*.rs");
+}
+
+#[test]
+fn keyed_for() {
+    trybuild::TestCases::new().compile_fail("tests/ui/keyed-for/*.rs");
+}
+
+#[test]
+fn raw_block() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/raw-block/*.rs");
+    t.pass("tests/ui/raw-block/pass/*.rs");
+}
+
+#[test]
+fn repeated_checks() {
+    trybuild::TestCases::new().compile_fail("tests/ui/repeated-checks/*.rs");
+}
+
+#[test]
+fn assert_no_html() {
+    trybuild::TestCases::new().compile_fail("tests/ui/assert-no-html/*.rs");
+}
+
+#[test]
+fn loop_control() {
+    trybuild::TestCases::new().compile_fail("tests/ui/loop-control/*.rs");
+}
+
+#[test]
+fn maud_const() {
+    trybuild::TestCases::new().compile_fail("tests/ui/maud-const/*.rs");
+}
+
+#[test]
+fn quoted_names() {
+    trybuild::TestCases::new().compile_fail("tests/ui/quoted-names/*.rs");
+}
+
+#[test]
+fn moved_captures() {
+    trybuild::TestCases::new().compile_fail("tests/ui/moved-captures/*.rs");
+}
+
+#[test]
+fn r#await() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/await/nested-async.rs");
+    t.compile_fail("tests/ui/await/splice.rs");
+    t.compile_fail("tests/ui/await/control-flow.rs");
+}
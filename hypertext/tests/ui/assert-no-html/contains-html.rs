@@ -0,0 +1,7 @@
+use hypertext::{assert_no_html, Raw};
+
+const TITLE: Raw<&str> = assert_no_html("<b>My Site</b>");
+
+fn main() {
+    let _ = TITLE;
+}
@@ -0,0 +1,7 @@
+use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+fn main() {
+    let active = true;
+
+    let _ = maud! { div class="card" class:[("active", active)] {} }.render();
+}
@@ -0,0 +1,12 @@
+use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+trait CustomAttributes: GlobalAttributes {
+    #[allow(non_upper_case_globals)]
+    const foo_bar: hypertext::Attribute = hypertext::Attribute;
+}
+
+impl<T: GlobalAttributes> CustomAttributes for T {}
+
+fn main() {
+    let _ = maud! { div foo-bar="1" foo_bar="2" {} }.render();
+}
@@ -0,0 +1,15 @@
+use hypertext::AttributeSet;
+
+#[derive(AttributeSet)]
+struct Attrs {
+    #[attr(debug, display)]
+    data_value: u32,
+}
+
+#[derive(AttributeSet)]
+struct Other {
+    #[attr(json)]
+    data_value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+use hypertext::{html_elements, rsx, GlobalAttributes, Renderable};
+
+fn main() {
+    let _ = rsx! { <div contenteditable="yes"></div> }.render();
+}
@@ -0,0 +1,22 @@
+use hypertext::{maud, Renderable};
+
+async fn fetch_flag() -> bool {
+    true
+}
+
+async fn fetch_items() -> Vec<u8> {
+    Vec::new()
+}
+
+async fn handler() {
+    let _ = maud! { @if fetch_flag().await { p {} } }.render();
+    let _ = maud! { @if false {} @else if fetch_flag().await { p {} } }.render();
+    let _ = maud! { @match fetch_flag().await { _ => {} } }.render();
+    let _ = maud! { @for item in fetch_items().await { (item) } }.render();
+    let _ = maud! { @let items = fetch_items().await; (items.len()) }.render();
+    let _ = maud! { @while fetch_flag().await {} }.render();
+}
+
+fn main() {
+    let _ = handler();
+}
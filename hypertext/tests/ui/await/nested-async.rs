@@ -0,0 +1,7 @@
+use hypertext::{html_elements, maud, rsx, Renderable};
+
+fn main() {
+    // an `.await` in a nested `async` block doesn't make the template async
+    let _ = maud! { p { ({ let _future = async { async {}.await }; "ok" }) } }.render();
+    let _ = rsx! { <p>{ let _future = async move { async {}.await }; "ok" }</p> }.render();
+}
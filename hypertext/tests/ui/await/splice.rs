@@ -0,0 +1,16 @@
+use hypertext::{html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+async fn fetch_user() -> String {
+    String::from("Alice")
+}
+
+async fn handler() {
+    let _ = maud! { p { (fetch_user().await) } }.render();
+    let _ = maud! { p title=(fetch_user().await) {} }.render();
+    let _ = rsx! { <p>{fetch_user().await}</p> }.render();
+    let _ = rsx! { <p title={fetch_user().await}></p> }.render();
+}
+
+fn main() {
+    let _ = handler();
+}
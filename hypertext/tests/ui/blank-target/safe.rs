@@ -0,0 +1,24 @@
+#![deny(deprecated)]
+
+use hypertext::{html_elements, maud, rsx, Renderable};
+
+fn main() {
+    let target = "_blank";
+    let rel = "noopener";
+
+    let _ = maud! {
+        a href="https://example.com" target="_blank" rel="noopener" { "Example" }
+        a href="https://example.com" target="_blank" rel="external noreferrer" { "Example" }
+        a href="https://example.com" target=(target) { "Example" }
+        a href="https://example.com" target="_blank" rel=(rel) { "Example" }
+        a href="https://example.com" target="_self" { "Example" }
+        form action="/search" target="_blank" {}
+    }
+    .render();
+
+    let _ = rsx! {
+        <a href="https://example.com" target="_blank" rel="noopener">Example</a>
+        <a href="https://example.com" target={target}>Example</a>
+    }
+    .render();
+}
@@ -0,0 +1,16 @@
+#![deny(deprecated)]
+
+use hypertext::{html_elements, maud, rsx, Renderable};
+
+fn main() {
+    let _ = maud! {
+        a href="https://example.com" target="_blank" { "Example" }
+        a href="https://example.com" target="_blank" rel="external" { "Example" }
+    }
+    .render();
+
+    let _ = rsx! {
+        <a href="https://example.com" target="_blank">Example</a>
+    }
+    .render();
+}
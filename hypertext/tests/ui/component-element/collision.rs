@@ -0,0 +1,20 @@
+use hypertext::{maud, Renderable};
+
+mod design_system {
+    use hypertext::{GlobalAttributes, Renderable};
+
+    pub struct Button;
+
+    impl GlobalAttributes for Button {}
+
+    // rendering is unrelated to the collision, so keep it out of the output
+    impl Renderable for Button {
+        fn render_to(self, _: &mut String) {}
+    }
+}
+
+use design_system::Button;
+
+fn main() {
+    let _ = maud! { Button { "Save" } }.render();
+}
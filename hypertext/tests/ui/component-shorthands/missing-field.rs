@@ -0,0 +1,18 @@
+use hypertext::{maud, Renderable};
+
+struct Card {
+    id: &'static str,
+}
+
+impl Renderable for Card {
+    fn render_to(self, output: &mut String) {
+        output.push_str(self.id);
+    }
+}
+
+fn main() {
+    let _ = maud! {
+        Card #main .wide;
+    }
+    .render();
+}
@@ -0,0 +1,21 @@
+use hypertext::{maud, Renderable};
+
+struct Card<C: Renderable> {
+    children: C,
+}
+
+impl<C: Renderable> Renderable for Card<C> {
+    fn render_to(self, output: &mut String) {
+        self.children.render_to(output);
+    }
+}
+
+fn main() {
+    let _ = maud! {
+        Card {
+            @slot children { p { "slot" } }
+            p { "default" }
+        }
+    }
+    .render();
+}
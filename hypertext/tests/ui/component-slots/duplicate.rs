@@ -0,0 +1,21 @@
+use hypertext::{maud, Renderable};
+
+struct Card<H: Renderable> {
+    header: H,
+}
+
+impl<H: Renderable> Renderable for Card<H> {
+    fn render_to(self, output: &mut String) {
+        self.header.render_to(output);
+    }
+}
+
+fn main() {
+    let _ = maud! {
+        Card {
+            @slot header { h1 { "One" } }
+            @slot header { h1 { "Two" } }
+        }
+    }
+    .render();
+}
@@ -0,0 +1,25 @@
+#![deny(deprecated)]
+
+use hypertext::{html_elements, maud, rsx, GlobalAttributes, Renderable};
+
+fn main() {
+    let show_input = true;
+    let dynamic = "dynamic";
+
+    let _ = maud! {
+        label for="name" { "Name" }
+        @if show_input {
+            input #name;
+        }
+        label for=(dynamic) { "Dynamic" }
+        table { tr { td headers="name other" {} } }
+        span id="other" {}
+    }
+    .render();
+
+    let _ = rsx! {
+        <label for="name">Name</label>
+        <input id="name">
+    }
+    .render();
+}
@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+fn main() {
+    let _ = maud! {
+        label for="name" { "Name" }
+        input #username;
+        table {
+            tr { th #heading { "Heading" } }
+            tr { td headers="heading missing" { "Cell" } }
+        }
+    }
+    .render();
+}
@@ -0,0 +1,11 @@
+#![deny(deprecated)]
+
+use hypertext::{html_elements, rsx, GlobalAttributes, Renderable};
+
+fn main() {
+    let _ = rsx! {
+        <label for="name">Name</label>
+        <input id="username">
+    }
+    .render();
+}
@@ -0,0 +1,7 @@
+use hypertext::{html_elements, maud, rsx, Renderable};
+
+// trybuild compiles this from `target/tests/trybuild/hypertext`
+fn main() {
+    let _ = maud! { @include("../../../../hypertext/tests/fixtures/broken/cycle-a.maud") }.render();
+    let _ = rsx! { @include("../../../../hypertext/tests/fixtures/broken/cycle.rsx") }.render();
+}
@@ -0,0 +1,8 @@
+use hypertext::{html_elements, maud, rsx, Renderable};
+
+// trybuild compiles this from `target/tests/trybuild/hypertext`
+fn main() {
+    let _ = maud! { @include("../../../../hypertext/tests/fixtures/broken/parse.maud") }.render();
+    let _ = rsx! { @include("../../../../hypertext/tests/fixtures/broken/parse.rsx") }.render();
+    let _ = rsx! { @include("../../../../hypertext/tests/fixtures/broken/tokenize.rsx") }.render();
+}
@@ -0,0 +1,6 @@
+use hypertext::{maud, rsx, Renderable};
+
+fn main() {
+    let _ = maud! { @include("tests/fixtures/partials/missing.maud") }.render();
+    let _ = rsx! { <div>@include("tests/fixtures/partials/missing.rsx")</div> }.render();
+}
@@ -0,0 +1,11 @@
+use hypertext::{maud, Renderable};
+
+fn main() {
+    let _ = maud! {
+        @for i in 0..3 keyed by (i) {
+            dt { (i) }
+            dd { (i * 2) }
+        }
+    }
+    .render();
+}
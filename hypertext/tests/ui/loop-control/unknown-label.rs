@@ -0,0 +1,12 @@
+use hypertext::{maud, Renderable};
+
+fn main() {
+    let _ = maud! {
+        @'outer: for _ in 0..3 {
+            @for _ in 0..3 {
+                @continue 'inner;
+            }
+        }
+    }
+    .render();
+}
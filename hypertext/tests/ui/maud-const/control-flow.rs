@@ -0,0 +1,7 @@
+use hypertext::{maud_const, Raw};
+
+const SHOW: bool = true;
+
+const HTML: Raw<&str> = maud_const! { @if SHOW { br; } };
+
+fn main() {}
@@ -0,0 +1,11 @@
+use hypertext::{html_elements, maud, Renderable};
+
+fn main() {
+    let name = String::from("Alice");
+
+    let greeting = maud! { p { "Hello, " (name) } };
+
+    println!("{name}");
+
+    let _ = greeting.render();
+}
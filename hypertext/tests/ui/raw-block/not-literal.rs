@@ -0,0 +1,6 @@
+use hypertext::{maud, Renderable};
+
+fn main() {
+    let html = "<b>bold</b>";
+    let _ = maud! { div { @raw { (html) } } }.render();
+}
@@ -0,0 +1,5 @@
+use hypertext::{html_elements, maud, Renderable};
+
+fn main() {
+    let _ = maud! { div { @raw { "<!-- <script> -->" } } }.render();
+}
@@ -0,0 +1,5 @@
+use hypertext::{maud, Renderable};
+
+fn main() {
+    let _ = maud! { div { @raw { "<!-- a --> <!-- b" } } }.render();
+}
@@ -0,0 +1,11 @@
+use hypertext::{html_elements, maud, Renderable};
+
+fn main() {
+    let _ = maud! {
+        @cfg(any()) {
+            div colour="red" {}
+        }
+        div colour="red" {}
+    }
+    .render();
+}
@@ -0,0 +1,12 @@
+use hypertext::{html_elements, maud, GlobalAttributes, Renderable};
+
+fn main() {
+    let _ = maud! {
+        div title="1" {}
+        div title="2" {}
+        div title="3" {}
+        div title="4" {}
+        div title="5" colour="red" {}
+    }
+    .render();
+}
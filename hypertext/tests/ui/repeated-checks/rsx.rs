@@ -0,0 +1,12 @@
+use hypertext::{html_elements, rsx, GlobalAttributes, Renderable};
+
+fn main() {
+    let _ = rsx! {
+        <div title="1"></div>
+        <div title="2"></div>
+        <div title="3"></div>
+        <div title="4"></div>
+        <div title="5" colour="red"></div>
+    }
+    .render();
+}
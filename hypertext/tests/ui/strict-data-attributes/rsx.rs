@@ -0,0 +1,5 @@
+use hypertext::{html_elements, rsx, Renderable};
+
+fn main() {
+    let _ = rsx! { <div data-userId="1"></div> }.render();
+}